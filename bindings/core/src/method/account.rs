@@ -242,6 +242,39 @@ pub enum AccountMethod {
         addresses_and_nft_ids: Vec<AddressAndNftId>,
         options: Option<TransactionOptionsDto>,
     },
+    /// Update the state metadata of an alias output with a state transition.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    UpdateAliasState {
+        alias_id: AliasIdDto,
+        /// Hex encoded bytes
+        state_metadata: String,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Set the state controller address of an alias output with a governance transition.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    SetAliasStateController {
+        alias_id: AliasIdDto,
+        address: String,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Set the governor address of an alias output with a governance transition.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    SetAliasGovernor {
+        alias_id: AliasIdDto,
+        address: String,
+        options: Option<TransactionOptionsDto>,
+    },
+    /// Transfer an alias output to another address, which becomes its state controller and governor.
+    /// Expected response: [`SentTransaction`](crate::Response::SentTransaction)
+    #[serde(rename_all = "camelCase")]
+    TransferAlias {
+        alias_id: AliasIdDto,
+        address: String,
+        options: Option<TransactionOptionsDto>,
+    },
     /// Set the alias of the account.
     /// Expected response: [`Ok`](crate::Response::Ok)
     SetAlias { alias: String },
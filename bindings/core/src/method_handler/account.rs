@@ -104,7 +104,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             Response::OutputIds(output_ids)
         }
         AccountMethod::GetOutput { output_id } => {
-            let output_data = account.get_output(&output_id).await;
+            let output_data = account.get_output(&output_id).await?;
             Response::OutputData(output_data.as_ref().map(OutputDataDto::from).map(Box::new))
         }
         AccountMethod::GetFoundryOutput { token_id } => {
@@ -113,7 +113,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             Response::Output(OutputDto::from(&output))
         }
         AccountMethod::GetTransaction { transaction_id } => {
-            let transaction = account.get_transaction(&transaction_id).await;
+            let transaction = account.get_transaction(&transaction_id).await?;
             Response::Transaction(transaction.as_ref().map(TransactionDto::from).map(Box::new))
         }
         AccountMethod::GetIncomingTransactionData { transaction_id } => {
@@ -217,7 +217,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             Response::MinimumRequiredStorageDeposit(minimum_storage_deposit.to_string())
         }
         AccountMethod::MintNfts { nfts_options, options } => {
-            let transaction = account
+            let transactions = account
                 .mint_nfts(
                     nfts_options
                         .iter()
@@ -226,7 +226,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                     options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
                 )
                 .await?;
-            Response::SentTransaction(TransactionDto::from(&transaction))
+            Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
         }
         AccountMethod::GetBalance => Response::Balance(AccountBalanceDto::from(&account.balance().await?)),
         AccountMethod::PrepareOutput {
@@ -287,7 +287,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
             addresses_with_amount,
             options,
         } => {
-            let transaction = account
+            let transactions = account
                 .send_amount(
                     addresses_with_amount
                         .iter()
@@ -296,7 +296,7 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                     options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
                 )
                 .await?;
-            Response::SentTransaction(TransactionDto::from(&transaction))
+            Response::Transactions(transactions.iter().map(TransactionDto::from).collect())
         }
         AccountMethod::SendNativeTokens {
             addresses_and_native_tokens,
@@ -322,6 +322,62 @@ pub(crate) async fn call_account_method_internal(account: &Account, method: Acco
                 .await?;
             Response::SentTransaction(TransactionDto::from(&transaction))
         }
+        AccountMethod::UpdateAliasState {
+            alias_id,
+            state_metadata,
+            options,
+        } => {
+            let transaction = account
+                .update_alias_state(
+                    AliasId::try_from(&alias_id)?,
+                    prefix_hex::decode(state_metadata)?,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
+        AccountMethod::SetAliasStateController {
+            alias_id,
+            address,
+            options,
+        } => {
+            let transaction = account
+                .set_alias_state_controller(
+                    AliasId::try_from(&alias_id)?,
+                    &address,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
+        AccountMethod::SetAliasGovernor {
+            alias_id,
+            address,
+            options,
+        } => {
+            let transaction = account
+                .set_alias_governor(
+                    AliasId::try_from(&alias_id)?,
+                    &address,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
+        AccountMethod::TransferAlias {
+            alias_id,
+            address,
+            options,
+        } => {
+            let transaction = account
+                .transfer_alias(
+                    AliasId::try_from(&alias_id)?,
+                    &address,
+                    options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                )
+                .await?;
+            Response::SentTransaction(TransactionDto::from(&transaction))
+        }
         AccountMethod::SetAlias { alias } => {
             account.set_alias(&alias).await?;
             Response::Ok
@@ -25,17 +25,13 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             }
 
             match builder.finish().await {
-                Ok(account) => {
-                    let account = account.read().await;
-                    Response::Account(AccountDetailsDto::from(&*account))
-                }
+                Ok(account) => Response::Account(AccountDetailsDto::from_account(&account).await?),
                 Err(e) => return Err(e.into()),
             }
         }
         WalletMethod::GetAccount { account_id } => {
             let account = wallet.get_account(account_id.clone()).await?;
-            let account = account.read().await;
-            Response::Account(AccountDetailsDto::from(&*account))
+            Response::Account(AccountDetailsDto::from_account(&account).await?)
         }
         WalletMethod::GetAccountIndexes => {
             let accounts = wallet.get_accounts().await?;
@@ -49,8 +45,7 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
             let accounts = wallet.get_accounts().await?;
             let mut account_dtos = Vec::new();
             for account in accounts {
-                let account = account.read().await;
-                account_dtos.push(AccountDetailsDto::from(&*account));
+                account_dtos.push(AccountDetailsDto::from_account(&account).await?);
             }
             Response::Accounts(account_dtos)
         }
@@ -96,8 +91,7 @@ pub(crate) async fn call_wallet_method_internal(wallet: &Wallet, method: WalletM
                 .await?;
             let mut account_dtos = Vec::new();
             for account in accounts {
-                let account = account.read().await;
-                account_dtos.push(AccountDetailsDto::from(&*account));
+                account_dtos.push(AccountDetailsDto::from_account(&account).await?);
             }
             Response::Accounts(account_dtos)
         }
@@ -317,7 +317,9 @@ pub enum Response {
     Transaction(Option<Box<TransactionDto>>),
     /// Response for
     /// - [`Transactions`](crate::method::AccountMethod::Transactions),
-    /// - [`PendingTransactions`](crate::method::AccountMethod::PendingTransactions)
+    /// - [`PendingTransactions`](crate::method::AccountMethod::PendingTransactions),
+    /// - [`MintNfts`](crate::method::AccountMethod::MintNfts),
+    /// - [`SendAmount`](crate::method::AccountMethod::SendAmount)
     Transactions(Vec<TransactionDto>),
     /// Response for
     /// - [`SignTransactionEssence`](crate::method::AccountMethod::SignTransactionEssence)
@@ -340,9 +342,6 @@ pub enum Response {
     /// - [`ConsolidateOutputs`](crate::method::AccountMethod::ConsolidateOutputs)
     /// - [`ClaimOutputs`](crate::method::AccountMethod::ClaimOutputs)
     /// - [`CreateAliasOutput`](crate::method::AccountMethod::CreateAliasOutput)
-    /// - [`SendAmount`](crate::method::AccountMethod::SendAmount),
-    /// - [`MintNfts`](crate::method::AccountMethod::MintNfts),
-    /// - [`SendAmount`](crate::method::AccountMethod::SendAmount),
     /// - [`SendNativeTokens`](crate::method::AccountMethod::SendNativeTokens),
     /// - [`SendNft`](crate::method::AccountMethod::SendNft),
     /// - [`SendOutputs`](crate::method::AccountMethod::SendOutputs)
@@ -33,16 +33,18 @@ async function run() {
             'rms1qrrv7flg6lz5cssvzv2lsdt8c673khad060l4quev6q09tkm9mgtupgf0h0';
         const amount = '1000000';
 
-        const response = await account.sendAmount([
+        const transactions = await account.sendAmount([
             {
                 address,
                 amount,
             },
         ]);
 
-        console.log(
-            `Block sent: ${process.env.EXPLORER_URL}/block/${response.blockId}`,
-        );
+        for (const transaction of transactions) {
+            console.log(
+                `Block sent: ${process.env.EXPLORER_URL}/block/${transaction.blockId}`,
+            );
+        }
     } catch (error) {
         console.error('Error: ', error);
     }
@@ -42,6 +42,7 @@ import type {
 } from '../../types/wallet/buildOutputData';
 import type {
     HexEncodedAmount,
+    HexEncodedString,
     IAliasOutput,
     IBasicOutput,
     IFoundryOutput,
@@ -744,12 +745,13 @@ export class Account {
      * @param nftsOptions The options for minting nfts.
      * @param transactionOptions The options to define a `RemainderValueStrategy`
      * or custom inputs.
-     * @returns The minting transaction.
+     * @returns The minting transactions, NFTs that don't fit into a single
+     * transaction are minted in multiple ones.
      */
     async mintNfts(
         nftsOptions: NftOptions[],
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction[]> {
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
             {
@@ -885,12 +887,13 @@ export class Account {
      * @param addressesWithAmount Addresses with amounts.
      * @param transactionOptions The options to define a `RemainderValueStrategy`
      * or custom inputs.
-     * @returns The sent transaction.
+     * @returns The sent transactions, outputs that don't fit into a single
+     * transaction are sent in multiple ones.
      */
     async sendAmount(
         addressesWithAmount: AddressWithAmount[],
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction[]> {
         const response = await this.methodHandler.callAccountMethod(
             this.meta.index,
             {
@@ -993,6 +996,118 @@ export class Account {
         });
     }
 
+    /**
+     * Set the governor address of an alias output with a governance transition.
+     * @param aliasId The AliasId.
+     * @param address The bech32 address of the new governor.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The sent transaction.
+     */
+    async setAliasGovernor(
+        aliasId: string,
+        address: string,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'setAliasGovernor',
+                data: {
+                    aliasId,
+                    address,
+                    options: transactionOptions,
+                },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Set the state controller address of an alias output with a governance transition.
+     * @param aliasId The AliasId.
+     * @param address The bech32 address of the new state controller.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The sent transaction.
+     */
+    async setAliasStateController(
+        aliasId: string,
+        address: string,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'setAliasStateController',
+                data: {
+                    aliasId,
+                    address,
+                    options: transactionOptions,
+                },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Transfer an alias output to another address, which becomes its state controller and governor.
+     * @param aliasId The AliasId.
+     * @param address The bech32 address to transfer the alias to.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The sent transaction.
+     */
+    async transferAlias(
+        aliasId: string,
+        address: string,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'transferAlias',
+                data: {
+                    aliasId,
+                    address,
+                    options: transactionOptions,
+                },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
+    /**
+     * Update the state metadata of an alias output with a state transition.
+     * @param aliasId The AliasId.
+     * @param stateMetadata The new hex encoded state metadata.
+     * @param transactionOptions The options to define a `RemainderValueStrategy`
+     * or custom inputs.
+     * @returns The sent transaction.
+     */
+    async updateAliasState(
+        aliasId: string,
+        stateMetadata: HexEncodedString,
+        transactionOptions?: TransactionOptions,
+    ): Promise<Transaction> {
+        const response = await this.methodHandler.callAccountMethod(
+            this.meta.index,
+            {
+                name: 'updateAliasState',
+                data: {
+                    aliasId,
+                    stateMetadata,
+                    options: transactionOptions,
+                },
+            },
+        );
+
+        return JSON.parse(response).payload;
+    }
+
     /**
      * Set the fallback SyncOptions for account syncing.
      * If storage is enabled, will persist during restarts.
@@ -17,6 +17,19 @@ export interface SeedSecretManager {
 /** Placeholder secret manager that can't do anything. */
 export type PlaceholderSecretManager = 'placeholder';
 
+/** Secret manager that forwards address generation and signing to a remote signing service. */
+export interface RemoteSecretManager {
+    remote: {
+        /** The URL of the signing service. */
+        url: string;
+        /**
+         * The token that is sent as bearer token with every request. It isn't stored, so a wallet with a bearer
+         * token needs to be created with the secret manager again when it's restored from the storage.
+         */
+        bearerToken?: string;
+    };
+}
+
 /** Secret manager that uses Stronghold. */
 export interface StrongholdSecretManager {
     stronghold: {
@@ -30,4 +43,5 @@ export type SecretManagerType =
     | LedgerNanoSecretManager
     | MnemonicSecretManager
     | StrongholdSecretManager
+    | RemoteSecretManager
     | PlaceholderSecretManager;
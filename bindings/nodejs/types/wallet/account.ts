@@ -99,6 +99,8 @@ export interface SyncOptions {
     syncOnlyMostBasicOutputs?: boolean;
     /** Sync native token foundries, so their metadata can be returned in the balance. Default: false. */
     syncNativeTokenFoundries?: boolean;
+    /** Send the queued payments after syncing if the payment queue waited for its max delay. Needed on wasm, where there is no timer to flush the queue. Default: false. */
+    flushPaymentQueue?: boolean;
 }
 
 /** Specifies what outputs should be synced for the ed25519 addresses from the account. */
@@ -1,4 +1,8 @@
-import type { OutputTypes, HexEncodedAmount } from '@iota/types';
+import type {
+    OutputTypes,
+    HexEncodedAmount,
+    HexEncodedString,
+} from '@iota/types';
 import type { SyncOptions, FilterOptions } from '../account';
 import type {
     AddressWithAmount,
@@ -315,6 +319,24 @@ export type __SetAliasMethod__ = {
     };
 };
 
+export type __SetAliasGovernorMethod__ = {
+    name: 'setAliasGovernor';
+    data: {
+        aliasId: string;
+        address: string;
+        options?: TransactionOptions;
+    };
+};
+
+export type __SetAliasStateControllerMethod__ = {
+    name: 'setAliasStateController';
+    data: {
+        aliasId: string;
+        address: string;
+        options?: TransactionOptions;
+    };
+};
+
 export type __SetDefaultSyncOptionsMethod__ = {
     name: 'setDefaultSyncOptions';
     data: {
@@ -343,6 +365,24 @@ export type __SyncAccountMethod__ = {
     };
 };
 
+export type __TransferAliasMethod__ = {
+    name: 'transferAlias';
+    data: {
+        aliasId: string;
+        address: string;
+        options?: TransactionOptions;
+    };
+};
+
+export type __UpdateAliasStateMethod__ = {
+    name: 'updateAliasState';
+    data: {
+        aliasId: string;
+        stateMetadata: HexEncodedString;
+        options?: TransactionOptions;
+    };
+};
+
 export type __VoteMethod__ = {
     name: 'vote';
     data: {
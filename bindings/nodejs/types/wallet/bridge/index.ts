@@ -40,10 +40,14 @@ import type {
     __SendNftMethod__,
     __SendOutputsMethod__,
     __SetAliasMethod__,
+    __SetAliasGovernorMethod__,
+    __SetAliasStateControllerMethod__,
     __SetDefaultSyncOptionsMethod__,
     __SignTransactionEssenceMethod__,
     __SubmitAndStoreTransactionMethod__,
     __SyncAccountMethod__,
+    __TransferAliasMethod__,
+    __UpdateAliasStateMethod__,
     __GetIncomingTransactionDataMethod__,
     __VoteMethod__,
     __GetParticipationOverviewMethod__,
@@ -127,10 +131,14 @@ export type __AccountMethod__ =
     | __SendNftMethod__
     | __SendOutputsMethod__
     | __SetAliasMethod__
+    | __SetAliasGovernorMethod__
+    | __SetAliasStateControllerMethod__
     | __SetDefaultSyncOptionsMethod__
     | __SignTransactionEssenceMethod__
     | __SubmitAndStoreTransactionMethod__
     | __SyncAccountMethod__
+    | __TransferAliasMethod__
+    | __UpdateAliasStateMethod__
     | __VoteMethod__
     | __StopParticipatingMethod__
     | __GetParticipationOverviewMethod__
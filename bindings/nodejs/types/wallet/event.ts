@@ -1,28 +1,57 @@
 import type { IOutputResponse, ITransactionPayload } from '@iota/types';
+import type { AccountBalance } from './account';
 import type { OutputData } from './output';
+import type { Transaction } from './transaction';
 
 /** Wallet event types */
 export type EventType =
     | '*'
+    | 'BalanceChanged'
     | 'ConsolidationRequired'
     | 'LedgerAddressGeneration'
     | 'NewOutput'
+    | 'OutputClaimable'
+    | 'OutputExpiring'
     | 'SpentOutput'
+    | 'TransactionConflicting'
     | 'TransactionInclusion'
     | 'TransactionProgress';
 
+export type BalanceChangedEvent = {
+    previousBalance: AccountBalance;
+    balance: AccountBalance;
+};
+
 export type NewOutputEvent = {
     output: OutputData;
     transaction?: ITransactionPayload;
     transactionInputs?: IOutputResponse;
 };
 
+export type OutputClaimableEvent = {
+    output: OutputData;
+};
+
+export type OutputExpiringEvent = {
+    output: OutputData;
+    /** Unix timestamp in seconds after which only the expiration return address can unlock the output */
+    expirationTime: number;
+};
+
+export type TransactionConflictingEvent = {
+    transaction: Transaction;
+};
+
 /** Wallet events */
 export enum WalletEvent {
+    BalanceChanged = 'BalanceChanged',
     ConsolidationRequired = 'ConsolidationRequired',
     LedgerAddressGeneration = 'LedgerAddressGeneration',
     NewOutput = 'NewOutput',
+    OutputClaimable = 'OutputClaimable',
+    OutputExpiring = 'OutputExpiring',
     SpentOutput = 'SpentOutput',
+    TransactionConflicting = 'TransactionConflicting',
     TransactionInclusion = 'TransactionInclusion',
     TransactionProgress = 'TransactionProgress',
 }
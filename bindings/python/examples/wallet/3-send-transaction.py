@@ -20,7 +20,8 @@ outputs = [{
     "amount": "1000000",
 }]
 
-transaction = account.send_amount(outputs)
+transactions = account.send_amount(outputs)
 
-print(f'Transaction: {transaction["transactionId"]}')
-print(f'Block sent: {EXPLORER}/block/" + {transaction["blockId"]}');
\ No newline at end of file
+for transaction in transactions:
+    print(f'Transaction: {transaction["transactionId"]}')
+    print(f'Block sent: {EXPLORER}/block/" + {transaction["blockId"]}');
\ No newline at end of file
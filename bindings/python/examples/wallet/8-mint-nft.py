@@ -19,7 +19,8 @@ outputs = [{
     "immutableMetadata": utf8_to_hex("some immutable nft metadata"),
 }]
 
-transaction = account.mint_nfts(outputs)
+transactions = account.mint_nfts(outputs)
 
-print(f'Transaction: {transaction["transactionId"]}')
-print(f'Block sent: {EXPLORER}/block/" + {transaction["blockId"]}');
+for transaction in transactions:
+    print(f'Transaction: {transaction["transactionId"]}')
+    print(f'Block sent: {EXPLORER}/block/" + {transaction["blockId"]}');
@@ -36,6 +36,25 @@ class SeedSecretManager(dict):
         dict.__init__(self, hexSeed=seed)
 
 
+class RemoteSecretManager(dict):
+    """Secret manager that forwards address generation and signing to a remote signing service.
+    """
+
+    def __init__(self, url, bearer_token=None):
+        """Initialize a remote secret manager. The bearer token isn't stored, so a wallet with a bearer token needs
+        to be created with the secret manager again when it's restored from the storage.
+        """
+
+        dict.__init__(self, remote=RemoteSecretManager.Inner(
+            url, bearer_token))
+
+    class Inner(dict):
+        def __init__(self, url, bearer_token):
+            dict.__init__(self, url=url)
+            if bearer_token is not None:
+                self['bearerToken'] = bearer_token
+
+
 class StrongholdSecretManager(dict):
     """Secret manager that uses Stronghold.
     """
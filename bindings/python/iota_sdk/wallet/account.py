@@ -186,6 +186,63 @@ class Account:
             }
         )
 
+    def update_alias_state(self,
+                           alias_id,
+                           state_metadata,
+                           options=None):
+        """Update the state metadata of an alias output with a state transition.
+        The state metadata is a hex encoded string.
+        """
+        return self._call_account_method(
+            'updateAliasState', {
+                'aliasId': alias_id,
+                'stateMetadata': state_metadata,
+                'options': options
+            }
+        )
+
+    def set_alias_state_controller(self,
+                                   alias_id,
+                                   address,
+                                   options=None):
+        """Set the state controller address of an alias output with a governance transition.
+        """
+        return self._call_account_method(
+            'setAliasStateController', {
+                'aliasId': alias_id,
+                'address': address,
+                'options': options
+            }
+        )
+
+    def set_alias_governor(self,
+                           alias_id,
+                           address,
+                           options=None):
+        """Set the governor address of an alias output with a governance transition.
+        """
+        return self._call_account_method(
+            'setAliasGovernor', {
+                'aliasId': alias_id,
+                'address': address,
+                'options': options
+            }
+        )
+
+    def transfer_alias(self,
+                       alias_id,
+                       address,
+                       options=None):
+        """Transfer an alias output to another address, which becomes its state controller and governor.
+        """
+        return self._call_account_method(
+            'transferAlias', {
+                'aliasId': alias_id,
+                'address': address,
+                'options': options
+            }
+        )
+
     def destroy_foundry(self,
                         foundry_id,
                         options=None):
@@ -336,7 +393,7 @@ class Account:
         )
 
     def mint_nfts(self, nfts_options, options=None):
-        """Mint nfts.
+        """Mint nfts, returns the transactions that were needed to mint them.
         """
         return self._call_account_method(
             'mintNfts', {
@@ -411,7 +468,7 @@ class Account:
         )
 
     def send_amount(self, addresses_with_amount, options=None):
-        """Send amount.
+        """Send amount, returns the transactions that were needed to send it.
         """
         return self._call_account_method(
             'sendAmount', {
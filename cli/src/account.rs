@@ -12,12 +12,12 @@ use crate::{
     command::account::{
         addresses_command, balance_command, burn_native_token_command, burn_nft_command, claim_command,
         claimable_outputs_command, consolidate_command, create_alias_outputs_command, decrease_native_token_command,
-        decrease_voting_power_command, destroy_alias_command, destroy_foundry_command, faucet_command,
-        increase_native_token_command, increase_voting_power_command, mint_native_token_command, mint_nft_command,
-        new_address_command, output_command, outputs_command, participation_overview_command, send_command,
-        send_native_token_command, send_nft_command, stop_participating_command, sync_command, transaction_command,
-        transactions_command, unspent_outputs_command, vote_command, voting_output_command, voting_power_command,
-        AccountCli, AccountCommand,
+        decrease_voting_power_command, destroy_alias_command, destroy_foundry_command, export_send_command,
+        faucet_command, import_bundle_command, increase_native_token_command, increase_voting_power_command,
+        mint_native_token_command, mint_nft_command, new_address_command, output_command, outputs_command,
+        participation_overview_command, send_command, send_native_token_command, send_nft_command, sign_bundle_command,
+        stop_participating_command, sync_command, transaction_command, transactions_command, unspent_outputs_command,
+        vote_command, voting_output_command, voting_power_command, AccountCli, AccountCommand,
     },
     error::Error,
     helper::{bytes_from_hex_or_file, print_account_help},
@@ -86,7 +86,11 @@ pub async fn account_prompt_internal(account: Account, history: &mut AccountHist
                 AccountCommand::Exit => {
                     return Ok(true);
                 }
+                AccountCommand::ExportSend { address, amount, path } => {
+                    export_send_command(&account, address, amount, path).await
+                }
                 AccountCommand::Faucet { address, url } => faucet_command(&account, address, url).await,
+                AccountCommand::ImportBundle { path } => import_bundle_command(&account, path).await,
                 AccountCommand::IncreaseNativeTokenSupply { token_id, amount } => {
                     increase_native_token_command(&account, token_id, amount).await
                 }
@@ -157,6 +161,7 @@ pub async fn account_prompt_internal(account: Account, history: &mut AccountHist
                     gift_storage_deposit,
                 } => send_native_token_command(&account, address, token_id, amount, gift_storage_deposit).await,
                 AccountCommand::SendNft { address, nft_id } => send_nft_command(&account, address, nft_id).await,
+                AccountCommand::SignBundle { path } => sign_bundle_command(&account, path).await,
                 AccountCommand::Sync => sync_command(&account).await,
                 AccountCommand::Transaction { transaction_id } => transaction_command(&account, &transaction_id).await,
                 AccountCommand::Transactions { show_details } => transactions_command(&account, show_details).await,
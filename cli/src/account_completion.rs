@@ -4,7 +4,7 @@
 use dialoguer::Completion;
 
 pub(crate) struct AccountCompletion<'a> {
-    options: [&'a str; 36],
+    options: [&'a str; 39],
 }
 
 pub(crate) const ACCOUNT_COMPLETION: AccountCompletion = AccountCompletion {
@@ -21,7 +21,9 @@ pub(crate) const ACCOUNT_COMPLETION: AccountCompletion = AccountCompletion {
         "destroy-alias",
         "destroy-foundry",
         "exit",
+        "export-send",
         "faucet",
+        "import-bundle",
         "increase-native-token-supply",
         "mint-native-token",
         "mint-nft",
@@ -31,6 +33,7 @@ pub(crate) const ACCOUNT_COMPLETION: AccountCompletion = AccountCompletion {
         "send",
         "send-native-token",
         "send-nft",
+        "sign-bundle",
         "sync",
         "transaction",
         "transactions",
@@ -5,7 +5,10 @@ use std::str::FromStr;
 
 use clap::{Parser, Subcommand};
 use iota_sdk::{
-    client::request_funds_from_faucet,
+    client::{
+        api::{TransactionBundle, TransactionSummary},
+        request_funds_from_faucet,
+    },
     types::{
         api::plugins::participation::types::ParticipationEventId,
         block::{
@@ -81,6 +84,16 @@ pub enum AccountCommand {
     },
     /// Exit the CLI wallet.
     Exit,
+    /// Prepare sending an amount and export the unsigned transaction bundle to a file, to sign it offline with
+    /// `sign-bundle`. Files with a `.json` extension are written as JSON, others in the binary format.
+    ExportSend {
+        /// Address to send funds to, e.g. rms1qztwng6cty8cfm42nzvq099ev7udhrnk0rw8jt8vttf9kpqnxhpsx869vr3.
+        address: String,
+        /// Amount to send, e.g. 1000000.
+        amount: u64,
+        /// Path of the transaction bundle file, e.g. ./transaction.bundle.
+        path: String,
+    },
     /// Request funds from the faucet.
     Faucet {
         /// Address the faucet sends the funds to, defaults to the latest address.
@@ -88,6 +101,11 @@ pub enum AccountCommand {
         /// URL of the faucet, default to <https://faucet.testnet.shimmer.network/api/enqueue>.
         url: Option<String>,
     },
+    /// Import a transaction bundle signed with `sign-bundle` and submit the transaction.
+    ImportBundle {
+        /// Path of the signed transaction bundle file, e.g. ./transaction.bundle.
+        path: String,
+    },
     /// Mint more of a native token.
     IncreaseNativeTokenSupply {
         /// Token ID to be minted, e.g. 0x087d205988b733d97fb145ae340e27a8b19554d1ceee64574d7e5ff66c45f69e7a0100000000.
@@ -184,6 +202,11 @@ pub enum AccountCommand {
         /// NFT ID to be sent, e.g. 0xecadf10e6545aa82da4df2dfd2a496b457c8850d2cab49b7464cb273d3dffb07.
         nft_id: String,
     },
+    /// Validate a transaction bundle exported with `export-send` and sign it, the signed bundle replaces the file.
+    SignBundle {
+        /// Path of the transaction bundle file, e.g. ./transaction.bundle.
+        path: String,
+    },
     /// Synchronize the account.
     Sync,
     /// Show the details of the transaction.
@@ -458,6 +481,25 @@ pub async fn destroy_foundry_command(account: &Account, foundry_id: String) -> R
     Ok(())
 }
 
+// `export-send` command
+pub async fn export_send_command(account: &Account, address: String, amount: u64, path: String) -> Result<(), Error> {
+    let prepared_transaction = account
+        .prepare_send_amount(vec![AddressWithAmount::new(address, amount)], None)
+        .await?;
+    let bundle = account.export_prepared_transaction(prepared_transaction).await?;
+
+    write_transaction_bundle(&path, &bundle).await?;
+
+    println_log_info!(
+        "Transaction bundle exported to {path}, essence hash: {}\n{}",
+        prefix_hex::encode(bundle.prepared_transaction_data().essence.hash()),
+        TransactionSummary::new(bundle.prepared_transaction_data(), None, None)?
+            .display(&account.client().get_bech32_hrp().await?)
+    );
+
+    Ok(())
+}
+
 // `faucet` command
 pub async fn faucet_command(account: &Account, address: Option<String>, url: Option<String>) -> Result<(), Error> {
     let address = if let Some(address) = address {
@@ -477,6 +519,20 @@ pub async fn faucet_command(account: &Account, address: Option<String>, url: Opt
     Ok(())
 }
 
+// `import-bundle` command
+pub async fn import_bundle_command(account: &Account, path: String) -> Result<(), Error> {
+    let bundle = read_transaction_bundle(&path).await?;
+    let transaction = account.import_signed_transaction(bundle).await?;
+
+    println_log_info!(
+        "Transaction sent:\n{:?}\n{:?}",
+        transaction.transaction_id,
+        transaction.block_id
+    );
+
+    Ok(())
+}
+
 // `increase-native-token-supply` command
 pub async fn increase_native_token_command(account: &Account, token_id: String, amount: String) -> Result<(), Error> {
     let mint_transaction = account
@@ -560,13 +616,15 @@ pub async fn mint_nft_command(
         immutable_metadata,
         metadata,
     }];
-    let transaction = account.mint_nfts(nft_options, None).await?;
+    let transactions = account.mint_nfts(nft_options, None).await?;
 
-    println_log_info!(
-        "NFT minting transaction sent:\n{:?}\n{:?}",
-        transaction.transaction_id,
-        transaction.block_id
-    );
+    for transaction in transactions {
+        println_log_info!(
+            "NFT minting transaction sent:\n{:?}\n{:?}",
+            transaction.transaction_id,
+            transaction.block_id
+        );
+    }
 
     Ok(())
 }
@@ -621,7 +679,7 @@ pub async fn send_command(
             .with_return_address(return_address)
             .with_expiration(expiration),
     ];
-    let transaction = account
+    let transactions = account
         .send_amount(
             outputs,
             TransactionOptions {
@@ -631,11 +689,13 @@ pub async fn send_command(
         )
         .await?;
 
-    println_log_info!(
-        "Transaction sent:\n{:?}\n{:?}",
-        transaction.transaction_id,
-        transaction.block_id
-    );
+    for transaction in transactions {
+        println_log_info!(
+            "Transaction sent:\n{:?}\n{:?}",
+            transaction.transaction_id,
+            transaction.block_id
+        );
+    }
 
     Ok(())
 }
@@ -706,6 +766,29 @@ pub async fn send_nft_command(account: &Account, address: String, nft_id: String
     Ok(())
 }
 
+// `sign-bundle` command
+pub async fn sign_bundle_command(account: &Account, path: String) -> Result<(), Error> {
+    let bundle = read_transaction_bundle(&path).await?;
+
+    // The signer can be offline, so the HRP is taken from the addresses of the account
+    let bech32_hrp = match account.addresses().await?.first() {
+        Some(address) => address.address().hrp().to_string(),
+        None => account.client().get_bech32_hrp().await?,
+    };
+    println_log_info!(
+        "Signing transaction:\n{}",
+        TransactionSummary::new(bundle.prepared_transaction_data(), None, None)?.display(&bech32_hrp)
+    );
+
+    let bundle = account.sign_transaction_bundle(bundle).await?;
+
+    write_transaction_bundle(&path, &bundle).await?;
+
+    println_log_info!("Transaction bundle signed and written to {path}.");
+
+    Ok(())
+}
+
 // `sync` command
 pub async fn sync_command(account: &Account) -> Result<(), Error> {
     let balance = account.sync(None).await?;
@@ -893,3 +976,21 @@ async fn print_address(account: &Account, address: &AccountAddress) -> Result<()
 
     Ok(())
 }
+
+async fn read_transaction_bundle(path: &str) -> Result<TransactionBundle, Error> {
+    if path.ends_with(".json") {
+        Ok(TransactionBundle::from_json(&tokio::fs::read_to_string(path).await?)?)
+    } else {
+        Ok(TransactionBundle::from_bytes(&tokio::fs::read(path).await?)?)
+    }
+}
+
+async fn write_transaction_bundle(path: &str, bundle: &TransactionBundle) -> Result<(), Error> {
+    if path.ends_with(".json") {
+        tokio::fs::write(path, bundle.to_json()?).await?;
+    } else {
+        tokio::fs::write(path, bundle.to_bytes()?).await?;
+    }
+
+    Ok(())
+}
@@ -32,19 +32,21 @@ async fn main() -> Result<()> {
             "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
             1_000_000,
         )];
-        let transaction = account.send_amount(outputs, None).await?;
-        println!("Transaction sent: {}", transaction.transaction_id);
-
-        // Wait for transaction to get included
-        let block_id = account
-            .retry_transaction_until_included(&transaction.transaction_id, None, None)
-            .await?;
-
-        println!(
-            "Block included: {}/block/{}",
-            std::env::var("EXPLORER_URL").unwrap(),
-            block_id
-        );
+        let transactions = account.send_amount(outputs, None).await?;
+        for transaction in transactions {
+            println!("Transaction sent: {}", transaction.transaction_id);
+
+            // Wait for transaction to get included
+            let block_id = account
+                .retry_transaction_until_included(&transaction.transaction_id, None, None)
+                .await?;
+
+            println!(
+                "Block included: {}/block/{}",
+                std::env::var("EXPLORER_URL").unwrap(),
+                block_id
+            );
+        }
     }
 
     Ok(())
@@ -32,7 +32,7 @@ async fn main() -> Result<()> {
         1,
     )];
 
-    let transaction = account
+    let transactions = account
         .send_amount(
             outputs,
             TransactionOptions {
@@ -41,18 +41,20 @@ async fn main() -> Result<()> {
             },
         )
         .await?;
-    println!("Transaction sent: {}", transaction.transaction_id);
-
-    // Wait for transaction to get included
-    let block_id = account
-        .retry_transaction_until_included(&transaction.transaction_id, None, None)
-        .await?;
-
-    println!(
-        "Block included: {}/block/{}",
-        std::env::var("EXPLORER_URL").unwrap(),
-        block_id
-    );
+    for transaction in transactions {
+        println!("Transaction sent: {}", transaction.transaction_id);
+
+        // Wait for transaction to get included
+        let block_id = account
+            .retry_transaction_until_included(&transaction.transaction_id, None, None)
+            .await?;
+
+        println!(
+            "Block included: {}/block/{}",
+            std::env::var("EXPLORER_URL").unwrap(),
+            block_id
+        );
+    }
 
     Ok(())
 }
@@ -40,19 +40,21 @@ async fn main() -> Result<()> {
         immutable_metadata: Some(b"some NFT immutable metadata".to_vec()),
     }];
 
-    let transaction = account.mint_nfts(nft_options, None).await?;
-    println!("Transaction sent: {}", transaction.transaction_id);
-
-    // Wait for transaction to get included
-    let block_id = account
-        .retry_transaction_until_included(&transaction.transaction_id, None, None)
-        .await?;
-
-    println!(
-        "Block included: {}/block/{}",
-        std::env::var("EXPLORER_URL").unwrap(),
-        block_id
-    );
+    let transactions = account.mint_nfts(nft_options, None).await?;
+    for transaction in transactions {
+        println!("Transaction sent: {}", transaction.transaction_id);
+
+        // Wait for transaction to get included
+        let block_id = account
+            .retry_transaction_until_included(&transaction.transaction_id, None, None)
+            .await?;
+
+        println!(
+            "Block included: {}/block/{}",
+            std::env::var("EXPLORER_URL").unwrap(),
+            block_id
+        );
+    }
 
     // Build nft output manually
     let sender_address = account.addresses().await?[0].address().clone();
@@ -41,26 +41,28 @@ async fn main() -> Result<()> {
         tag: None,
     }];
 
-    let transaction = account.mint_nfts(nft_options, None).await?;
-    println!("Transaction sent: {}", transaction.transaction_id);
+    let transactions = account.mint_nfts(nft_options, None).await?;
+    for transaction in transactions {
+        println!("Transaction sent: {}", transaction.transaction_id);
 
-    let block_id = account
-        .retry_transaction_until_included(&transaction.transaction_id, None, None)
-        .await?;
-    println!(
-        "Block with NFTs mint included: {}/block/{}",
-        std::env::var("EXPLORER_URL").unwrap(),
-        block_id
-    );
+        let block_id = account
+            .retry_transaction_until_included(&transaction.transaction_id, None, None)
+            .await?;
+        println!(
+            "Block with NFTs mint included: {}/block/{}",
+            std::env::var("EXPLORER_URL").unwrap(),
+            block_id
+        );
 
-    let TransactionEssence::Regular(essence) = transaction.payload.essence();
-    for (output_index, output) in essence.outputs().iter().enumerate() {
-        if let Output::Nft(nft_output) = output {
-            // New minted nft id is empty in the output
-            if nft_output.nft_id().is_null() {
-                let output_id = OutputId::new(transaction.transaction_id, output_index as u16)?;
-                let nft_id = NftId::from(&output_id);
-                println!("New minted NFT id: {nft_id}");
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        for (output_index, output) in essence.outputs().iter().enumerate() {
+            if let Output::Nft(nft_output) = output {
+                // New minted nft id is empty in the output
+                if nft_output.nft_id().is_null() {
+                    let output_id = OutputId::new(transaction.transaction_id, output_index as u16)?;
+                    let nft_id = NftId::from(&output_id);
+                    println!("New minted NFT id: {nft_id}");
+                }
             }
         }
     }
@@ -9,10 +9,7 @@
 use std::str::FromStr;
 
 use iota_sdk::{
-    types::block::{
-        address::{Address, NftAddress},
-        output::NftId,
-    },
+    types::block::output::NftId,
     wallet::{NftOptions, Result, Wallet},
 };
 
@@ -38,7 +35,6 @@ async fn main() -> Result<()> {
         .set_stronghold_password(&std::env::var("STRONGHOLD_PASSWORD").unwrap())
         .await?;
 
-    let bech32_hrp = account.client().get_bech32_hrp().await?;
     let mut nft_options = Vec::new();
 
     // Create the metadata with another index for each
@@ -46,23 +42,25 @@ async fn main() -> Result<()> {
         nft_options.push(NftOptions {
             address: None,
             immutable_metadata: Some(format!("{{\"standard\":\"IRC27\",\"version\":\"v1.0\",\"type\":\"video/mp4\",\"uri\":\"ipfs://wrongcVm9fx47YXNTkhpMEYSxCD3Bqh7PJYr7eo5Ywrong\",\"name\":\"Shimmer OG NFT #{index}\",\"description\":\"The Shimmer OG NFT was handed out 1337 times by the IOTA Foundation to celebrate the official launch of the Shimmer Network.\",\"issuerName\":\"IOTA Foundation\",\"collectionId\":\"{issuer_nft_id}\",\"collectionName\":\"Shimmer OG\" }}").as_bytes().to_vec()),
-            // The NFT address from the NFT we minted in mint_issuer_nft example
-            issuer: Some(Address::Nft(NftAddress::new(issuer_nft_id)).to_bech32(bech32_hrp.clone())),
+            // The issuer is set to the NFT address from the NFT we minted in mint_issuer_nft example
+            issuer: None,
             metadata: None,
             sender: None,
             tag: None,
         });
     }
 
-    // Mint nfts in chunks, since the transaction size is limited
-    for nfts in nft_options.chunks(50) {
-        let transaction = account.mint_nfts(nfts.to_vec(), None).await?;
+    // Mint the nfts in as few transactions as possible, since the transaction size is limited
+    let transactions = account.mint_collection_nfts(issuer_nft_id, nft_options, None).await?;
 
+    for transaction in &transactions {
         println!(
             "Transaction with chunk of NFTs mint sent: {}/transaction/{}",
             std::env::var("EXPLORER_URL").unwrap(),
             transaction.transaction_id
         );
+    }
+    if let Some(transaction) = transactions.last() {
         // Try to get the transaction confirmed
         account
             .retry_transaction_until_included(&transaction.transaction_id, None, None)
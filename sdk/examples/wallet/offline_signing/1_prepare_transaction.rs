@@ -13,7 +13,7 @@ use std::{
 
 use iota_sdk::{
     client::{
-        api::{PreparedTransactionData, PreparedTransactionDataDto},
+        api::TransactionBundle,
         constants::SHIMMER_COIN_TYPE,
         secret::{placeholder::PlaceholderSecretManager, SecretManager},
     },
@@ -21,7 +21,7 @@ use iota_sdk::{
 };
 
 const ADDRESS_FILE_NAME: &str = "examples/wallet/offline_signing/addresses.json";
-const PREPARED_TRANSACTION_FILE_NAME: &str = "examples/wallet/offline_signing/prepared_transaction.json";
+const TRANSACTION_BUNDLE_FILE_NAME: &str = "examples/wallet/offline_signing/transaction_bundle.json";
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -64,7 +64,10 @@ async fn main() -> Result<()> {
 
     println!("Prepared transaction sending {outputs:?}");
 
-    write_transaction_to_file(PREPARED_TRANSACTION_FILE_NAME, prepared_transaction)
+    // The bundle contains the protocol parameters, so the offline signer can validate the transaction
+    let bundle = account.export_prepared_transaction(prepared_transaction).await?;
+
+    write_bundle_to_file(TRANSACTION_BUNDLE_FILE_NAME, &bundle)
 }
 
 fn read_addresses_from_file<P: AsRef<Path>>(path: P) -> Result<Vec<AccountAddress>> {
@@ -75,8 +78,8 @@ fn read_addresses_from_file<P: AsRef<Path>>(path: P) -> Result<Vec<AccountAddres
     Ok(serde_json::from_str(&json)?)
 }
 
-fn write_transaction_to_file<P: AsRef<Path>>(path: P, prepared_transaction: PreparedTransactionData) -> Result<()> {
-    let json = serde_json::to_string_pretty(&PreparedTransactionDataDto::from(&prepared_transaction))?;
+fn write_bundle_to_file<P: AsRef<Path>>(path: P, bundle: &TransactionBundle) -> Result<()> {
+    let json = bundle.to_json()?;
     let mut file = BufWriter::new(File::create(path)?);
 
     println!("{json}");
@@ -13,18 +13,13 @@ use std::{
 
 use iota_sdk::{
     client::{
-        api::{
-            transaction::validate_transaction_payload_length, PreparedTransactionData, PreparedTransactionDataDto,
-            SignedTransactionData, SignedTransactionDataDto,
-        },
-        secret::{stronghold::StrongholdSecretManager, SecretManageExt, SecretManager},
+        api::TransactionBundle,
+        secret::{stronghold::StrongholdSecretManager, SecretManager},
     },
-    types::block::{output::RentStructureBuilder, payload::TransactionPayload, protocol::ProtocolParameters},
     wallet::Result,
 };
 
-const PREPARED_TRANSACTION_FILE_NAME: &str = "examples/wallet/offline_signing/prepared_transaction.json";
-const SIGNED_TRANSACTION_FILE_NAME: &str = "examples/wallet/offline_signing/signed_transaction.json";
+const TRANSACTION_BUNDLE_FILE_NAME: &str = "examples/wallet/offline_signing/transaction_bundle.json";
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -39,66 +34,29 @@ async fn main() -> Result<()> {
     // Load snapshot file
     secret_manager.read_stronghold_snapshot().await?;
 
-    // TODO: read from file, similar to https://github.com/iotaledger/iota.rs/issues/1267
-    // Make sure that these values match the network you use.
-    let protocol_parameters = ProtocolParameters::new(
-        2,
-        String::from("testnet"),
-        String::from("smr"),
-        1500,
-        15,
-        RentStructureBuilder::new()
-            .byte_cost(100)
-            .byte_factor_key(1)
-            .byte_factor_data(10)
-            .finish(),
-        1813620509061365,
-    )
-    .unwrap();
-
-    let prepared_transaction_data =
-        read_prepared_transaction_from_file(PREPARED_TRANSACTION_FILE_NAME, &protocol_parameters)?;
+    // The bundle is validated against the protocol parameters it contains when it's read.
+    let mut bundle = read_bundle_from_file(TRANSACTION_BUNDLE_FILE_NAME)?;
 
     // Signs prepared transaction offline.
-    let unlocks = SecretManager::Stronghold(secret_manager)
-        .sign_transaction_essence(&prepared_transaction_data, None)
-        .await?;
-    let signed_transaction = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?;
-
-    validate_transaction_payload_length(&signed_transaction)?;
-
-    let signed_transaction_data = SignedTransactionData {
-        transaction_payload: signed_transaction,
-        inputs_data: prepared_transaction_data.inputs_data,
-    };
+    bundle.sign(&SecretManager::Stronghold(secret_manager), None).await?;
 
     println!("Signed transaction.");
 
-    write_signed_transaction_to_file(SIGNED_TRANSACTION_FILE_NAME, &signed_transaction_data)?;
+    write_bundle_to_file(TRANSACTION_BUNDLE_FILE_NAME, &bundle)?;
 
     Ok(())
 }
 
-fn read_prepared_transaction_from_file<P: AsRef<Path>>(
-    path: P,
-    protocol_parameters: &ProtocolParameters,
-) -> Result<PreparedTransactionData> {
+fn read_bundle_from_file<P: AsRef<Path>>(path: P) -> Result<TransactionBundle> {
     let mut file = File::open(&path)?;
     let mut json = String::new();
     file.read_to_string(&mut json)?;
 
-    Ok(PreparedTransactionData::try_from_dto(
-        &serde_json::from_str::<PreparedTransactionDataDto>(&json)?,
-        protocol_parameters,
-    )?)
+    Ok(TransactionBundle::from_json(&json)?)
 }
 
-fn write_signed_transaction_to_file<P: AsRef<Path>>(
-    path: P,
-    signed_transaction_data: &SignedTransactionData,
-) -> Result<()> {
-    let dto = SignedTransactionDataDto::from(signed_transaction_data);
-    let json = serde_json::to_string_pretty(&dto)?;
+fn write_bundle_to_file<P: AsRef<Path>>(path: P, bundle: &TransactionBundle) -> Result<()> {
+    let json = bundle.to_json()?;
     let mut file = BufWriter::new(File::create(path)?);
 
     println!("{json}");
@@ -8,14 +8,11 @@
 use std::{fs::File, io::prelude::*, path::Path};
 
 use iota_sdk::{
-    client::{
-        api::{SignedTransactionData, SignedTransactionDataDto},
-        Client,
-    },
+    client::api::TransactionBundle,
     wallet::{Result, Wallet},
 };
 
-const SIGNED_TRANSACTION_FILE_NAME: &str = "examples/wallet/offline_signing/signed_transaction.json";
+const TRANSACTION_BUNDLE_FILE_NAME: &str = "examples/wallet/offline_signing/transaction_bundle.json";
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -28,11 +25,10 @@ async fn main() -> Result<()> {
     // Create a new account
     let account = wallet.get_account("Alice").await?;
 
-    let signed_transaction_data =
-        read_signed_transaction_from_file(account.client(), SIGNED_TRANSACTION_FILE_NAME).await?;
+    let bundle = read_bundle_from_file(TRANSACTION_BUNDLE_FILE_NAME)?;
 
     // Sends offline signed transaction online.
-    let transaction = account.submit_and_store_transaction(signed_transaction_data).await?;
+    let transaction = account.import_signed_transaction(bundle).await?;
     println!("Transaction sent: {}", transaction.transaction_id);
 
     let block_id = account
@@ -47,18 +43,10 @@ async fn main() -> Result<()> {
     Ok(())
 }
 
-async fn read_signed_transaction_from_file<P: AsRef<Path> + Send>(
-    client: &Client,
-    path: P,
-) -> Result<SignedTransactionData> {
+fn read_bundle_from_file<P: AsRef<Path>>(path: P) -> Result<TransactionBundle> {
     let mut file = File::open(&path)?;
     let mut json = String::new();
     file.read_to_string(&mut json)?;
 
-    let dto = serde_json::from_str::<SignedTransactionDataDto>(&json)?;
-
-    Ok(SignedTransactionData::try_from_dto(
-        &dto,
-        &client.get_protocol_parameters().await?,
-    )?)
+    Ok(TransactionBundle::from_json(&json)?)
 }
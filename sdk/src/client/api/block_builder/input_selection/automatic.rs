@@ -113,7 +113,8 @@ impl<'a> ClientBlockBuilder<'a> {
             protocol_parameters.clone(),
         )
         .required_inputs(required_inputs_for_sender_or_issuer_ids.clone())
-        .timestamp(current_time);
+        .timestamp(current_time)
+        .coin_selection_strategy(self.coin_selection_strategy);
 
         if let Some(address) = self.custom_remainder_address {
             input_selection = input_selection.remainder_address(address);
@@ -217,7 +218,8 @@ impl<'a> ClientBlockBuilder<'a> {
                         protocol_parameters.clone(),
                     )
                     .required_inputs(required_inputs_for_sender_or_issuer_ids.clone())
-                    .timestamp(current_time);
+                    .timestamp(current_time)
+                    .coin_selection_strategy(self.coin_selection_strategy);
 
                     if let Some(address) = self.custom_remainder_address {
                         input_selection = input_selection.remainder_address(address);
@@ -0,0 +1,32 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+/// The strategy used to select the inputs that provide the base coin amount of a transaction.
+///
+/// The strategy only applies to the amount requirement, inputs that are required by the outputs, e.g. chains or native
+/// tokens, are always selected.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "strategy", content = "value")]
+pub enum CoinSelectionStrategy {
+    /// Selects inputs from the lowest to the highest amount, or from the highest to the lowest amount if too many
+    /// inputs would be needed otherwise.
+    #[default]
+    SmallestFirst,
+    /// Selects inputs from the highest to the lowest amount, which keeps the number of inputs low.
+    LargestFirst,
+    /// Searches the combination of inputs with the smallest remainder, ideally without any remainder output, with a
+    /// branch and bound search. Falls back to [`CoinSelectionStrategy::SmallestFirst`] if no combination is found.
+    MinimizeRemainder,
+    /// Selects inputs of a single address, preferring addresses that are already used by the transaction, so
+    /// addresses aren't linked together on the ledger. Falls back to [`CoinSelectionStrategy::SmallestFirst`] if no
+    /// single address has enough funds.
+    AvoidAddressLinking,
+    /// Selects inputs like [`CoinSelectionStrategy::SmallestFirst`] and additionally consumes basic outputs holding at
+    /// most `threshold` base coins, as long as the input count limit allows it.
+    ConsolidateDust {
+        /// The highest amount of an output that is considered dust
+        threshold: u64,
+    },
+}
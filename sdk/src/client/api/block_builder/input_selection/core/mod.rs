@@ -2,6 +2,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod burn;
+pub(crate) mod coin_selection;
 pub(crate) mod error;
 pub(crate) mod remainder;
 pub(crate) mod requirement;
@@ -14,6 +15,7 @@ pub(crate) use requirement::is_alias_transition;
 
 pub use self::{
     burn::{Burn, BurnDto},
+    coin_selection::CoinSelectionStrategy,
     error::Error,
     requirement::Requirement,
 };
@@ -41,6 +43,7 @@ pub struct InputSelection {
     addresses: HashSet<Address>,
     burn: Option<Burn>,
     remainder_address: Option<Address>,
+    coin_selection_strategy: CoinSelectionStrategy,
     protocol_parameters: ProtocolParameters,
     timestamp: u32,
     requirements: Vec<Requirement>,
@@ -186,6 +189,7 @@ impl InputSelection {
             addresses,
             burn: None,
             remainder_address: None,
+            coin_selection_strategy: CoinSelectionStrategy::default(),
             protocol_parameters,
             timestamp: unix_timestamp_now().as_secs() as u32,
             requirements: Vec::new(),
@@ -217,6 +221,12 @@ impl InputSelection {
         self
     }
 
+    /// Sets the coin selection strategy of an [`InputSelection`].
+    pub fn coin_selection_strategy(mut self, strategy: CoinSelectionStrategy) -> Self {
+        self.coin_selection_strategy = strategy;
+        self
+    }
+
     /// Sets the timestamp of an [`InputSelection`].
     pub fn timestamp(mut self, timestamp: u32) -> Self {
         self.timestamp = timestamp;
@@ -1,11 +1,11 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
-use super::{Error, InputSelection, Requirement};
+use super::{is_alias_transition, Error, InputSelection, Requirement};
 use crate::{
-    client::secret::types::InputSigningData,
+    client::{api::input_selection::CoinSelectionStrategy, secret::types::InputSigningData},
     types::block::{
         address::Address,
         input::INPUT_COUNT_MAX,
@@ -18,6 +18,9 @@ use crate::{
 
 // TODO checked operations ?
 
+// Maximum number of branches explored by the branch and bound search of `CoinSelectionStrategy::MinimizeRemainder`.
+const BRANCH_AND_BOUND_MAX_TRIES: usize = 100_000;
+
 /// Get the `StorageDepositReturnUnlockCondition`, if not expired.
 pub(crate) fn sdruc_not_expired(output: &Output, current_time: u32) -> Option<&StorageDepositReturnUnlockCondition> {
     // PANIC: safe to unwrap as outputs without unlock conditions have been filtered out already.
@@ -107,17 +110,25 @@ impl AmountSelection {
     }
 
     fn missing_amount(&self) -> u64 {
+        self.missing_amount_with(0)
+    }
+
+    // The missing amount if inputs with an additional amount, without native tokens and storage deposit returns, were
+    // selected.
+    fn missing_amount_with(&self, additional_amount: u64) -> u64 {
+        let inputs_sum = self.inputs_sum + additional_amount;
+
         // If there is already a remainder, make sure it's enough to cover the storage deposit.
-        if self.inputs_sum > self.outputs_sum {
-            let diff = self.inputs_sum - self.outputs_sum;
+        if inputs_sum > self.outputs_sum {
+            let diff = inputs_sum - self.outputs_sum;
 
             if self.remainder_amount > diff {
                 self.remainder_amount - diff
             } else {
                 0
             }
-        } else if self.inputs_sum < self.outputs_sum {
-            self.outputs_sum - self.inputs_sum
+        } else if inputs_sum < self.outputs_sum {
+            self.outputs_sum - inputs_sum
         } else if self.native_tokens_remainder {
             self.remainder_amount
         } else {
@@ -278,6 +289,173 @@ impl InputSelection {
         })
     }
 
+    // Basic inputs without native tokens and storage deposit returns that can be unlocked by an Ed25519 address, their
+    // whole amount is available to fulfill the amount requirement.
+    fn simple_amount_inputs<'a>(
+        &'a self,
+        amount_selection: &'a AmountSelection,
+    ) -> impl Iterator<Item = (&'a InputSigningData, Address)> + 'a {
+        self.available_inputs.iter().filter_map(|input| {
+            if amount_selection.newly_selected_inputs.contains_key(input.output_id()) {
+                return None;
+            }
+
+            if let Output::Basic(output) = &input.output {
+                let address = output
+                    .unlock_conditions()
+                    .locked_address(output.address(), self.timestamp);
+
+                if address.is_ed25519()
+                    && output.native_tokens().is_empty()
+                    && sdruc_not_expired(&input.output, self.timestamp).is_none()
+                {
+                    return Some((input, *address));
+                }
+            }
+
+            None
+        })
+    }
+
+    fn remaining_input_count(&self, amount_selection: &AmountSelection) -> usize {
+        usize::from(INPUT_COUNT_MAX)
+            .saturating_sub(self.selected_inputs.len() + amount_selection.newly_selected_inputs.len())
+    }
+
+    // Searches the combination of simple inputs with the smallest remainder.
+    fn minimize_remainder_inputs(&self, amount_selection: &AmountSelection) -> Option<Vec<InputSigningData>> {
+        let mut candidates = self
+            .simple_amount_inputs(amount_selection)
+            .map(|(input, _)| input)
+            .collect::<Vec<_>>();
+        candidates.sort_by(|left, right| right.output.amount().cmp(&left.output.amount()));
+
+        let mut search = BranchAndBound {
+            amount_selection,
+            amounts: candidates.iter().map(|input| input.output.amount()).collect(),
+            remaining: vec![0; candidates.len() + 1],
+            max_inputs: self.remaining_input_count(amount_selection),
+            lower_bound: amount_selection.missing_amount(),
+            tries: 0,
+            selection: Vec::new(),
+            best: None,
+        };
+        for index in (0..candidates.len()).rev() {
+            search.remaining[index] = search.remaining[index + 1] + search.amounts[index];
+        }
+        search.search(0, 0);
+
+        search
+            .best
+            .map(|(_, indexes)| indexes.into_iter().map(|index| candidates[index].clone()).collect())
+    }
+
+    // Selects simple inputs of a single address, preferring addresses that are already used by the transaction.
+    fn single_address_inputs(&self, amount_selection: &AmountSelection) -> Option<Vec<InputSigningData>> {
+        let used_addresses = self
+            .selected_inputs
+            .iter()
+            .chain(amount_selection.newly_selected_inputs.values().map(|(input, _)| input))
+            .filter_map(|input| {
+                let alias_transition = is_alias_transition(input, &self.outputs).map(|t| t.0);
+                input
+                    .output
+                    .required_and_unlocked_address(self.timestamp, input.output_id(), alias_transition)
+                    .ok()
+                    .map(|(address, _)| address)
+            })
+            .collect::<HashSet<_>>();
+
+        let mut inputs_per_address = BTreeMap::<Address, Vec<&InputSigningData>>::new();
+        for (input, address) in self.simple_amount_inputs(amount_selection) {
+            inputs_per_address.entry(address).or_default().push(input);
+        }
+
+        let max_inputs = self.remaining_input_count(amount_selection);
+        // Addresses that aren't used yet, then fewer inputs, then a smaller sum are preferred.
+        let mut best_key = None;
+        let mut best_inputs = Vec::new();
+
+        for (address, mut inputs) in inputs_per_address {
+            inputs.sort_by(|left, right| right.output.amount().cmp(&left.output.amount()));
+
+            let mut sum = 0;
+            for (count, input) in inputs.iter().enumerate().take(max_inputs) {
+                sum += input.output.amount();
+
+                if amount_selection.missing_amount_with(sum) == 0 {
+                    let key = (!used_addresses.contains(&address), count + 1, sum);
+                    if best_key.is_none_or(|best_key| key < best_key) {
+                        best_key = Some(key);
+                        best_inputs = inputs[..=count].to_vec();
+                    }
+                    break;
+                }
+            }
+        }
+
+        best_key.map(|_| best_inputs.into_iter().cloned().collect())
+    }
+
+    // Adds dust inputs to the amount selection, as long as the input count limit allows it.
+    fn consolidate_dust(&self, amount_selection: &mut AmountSelection, threshold: u64) {
+        let mut dust = self
+            .available_inputs
+            .iter()
+            .filter(|input| {
+                if amount_selection.newly_selected_inputs.contains_key(input.output_id()) {
+                    return false;
+                }
+
+                match &input.output {
+                    Output::Basic(output) => {
+                        output.amount() <= threshold
+                            && output
+                                .simple_deposit_address()
+                                .is_some_and(|address| address.is_ed25519() && self.addresses.contains(address))
+                    }
+                    _ => false,
+                }
+            })
+            .collect::<Vec<_>>();
+        dust.sort_by(|left, right| left.output.amount().cmp(&right.output.amount()));
+
+        let mut consolidated = amount_selection.clone();
+        for input in dust.into_iter().take(self.remaining_input_count(amount_selection)) {
+            consolidated.inputs_sum += input.output.amount();
+            consolidated
+                .newly_selected_inputs
+                .insert(*input.output_id(), (input.clone(), None));
+        }
+
+        if consolidated.missing_amount() == 0 {
+            log::debug!(
+                "Consolidating {} dust inputs",
+                consolidated.newly_selected_inputs.len() - amount_selection.newly_selected_inputs.len()
+            );
+            *amount_selection = consolidated;
+        }
+    }
+
+    fn finish_amount_selection(
+        &mut self,
+        mut amount_selection: AmountSelection,
+    ) -> Vec<(InputSigningData, Option<AliasTransition>)> {
+        if let CoinSelectionStrategy::ConsolidateDust { threshold } = self.coin_selection_strategy {
+            self.consolidate_dust(&mut amount_selection, threshold);
+        }
+
+        log::debug!(
+            "Outputs {:?} selected to fulfill the amount requirement",
+            amount_selection.newly_selected_inputs
+        );
+
+        self.available_inputs
+            .retain(|input| !amount_selection.newly_selected_inputs.contains_key(input.output_id()));
+
+        amount_selection.into_newly_selected_inputs()
+    }
+
     pub(crate) fn fulfill_amount_requirement(
         &mut self,
     ) -> Result<Vec<(InputSigningData, Option<AliasTransition>)>, Error> {
@@ -285,7 +463,7 @@ impl InputSelection {
 
         if amount_selection.missing_amount() == 0 {
             log::debug!("Amount requirement already fulfilled");
-            return Ok(amount_selection.into_newly_selected_inputs());
+            return Ok(self.finish_amount_selection(amount_selection));
         } else {
             log::debug!(
                 "Fulfilling amount requirement with input {}, output {}, input sdrs {:?} and output sdrs {:?}",
@@ -296,21 +474,43 @@ impl InputSelection {
             );
         }
 
-        // TODO if consolidate strategy: sum all the lowest amount until diff is covered.
-        // TODO this would be lowest amount of input strategy.
+        let strategy_inputs = match self.coin_selection_strategy {
+            CoinSelectionStrategy::MinimizeRemainder => self.minimize_remainder_inputs(&amount_selection),
+            CoinSelectionStrategy::AvoidAddressLinking => self.single_address_inputs(&amount_selection),
+            _ => None,
+        };
 
-        // Try to select outputs first with ordering from low to high amount, if that fails, try reversed.
+        if let Some(inputs) = strategy_inputs {
+            if amount_selection.fulfil(inputs.iter()) {
+                log::debug!("Amount requirement fulfilled with {:?}", self.coin_selection_strategy);
+                return Ok(self.finish_amount_selection(amount_selection));
+            }
 
-        log::debug!("Ordering inputs from low to high amount");
-        // Sort inputs per amount, low to high.
-        self.available_inputs
-            .sort_by(|left, right| left.output.amount().cmp(&right.output.amount()));
+            log::debug!("Clearing amount selection");
+            amount_selection = AmountSelection::new(self)?;
+        }
+
+        let largest_first = self.coin_selection_strategy == CoinSelectionStrategy::LargestFirst;
+
+        if largest_first {
+            log::debug!("Ordering inputs from high to low amount");
+            // Sort inputs per amount, high to low.
+            self.available_inputs
+                .sort_by(|left, right| right.output.amount().cmp(&left.output.amount()));
+        } else {
+            log::debug!("Ordering inputs from low to high amount");
+            // Sort inputs per amount, low to high.
+            self.available_inputs
+                .sort_by(|left, right| left.output.amount().cmp(&right.output.amount()));
+        }
 
         if let Some(r) = self.fulfill_amount_requirement_inner(&mut amount_selection) {
             return Ok(r);
         }
 
-        if self.selected_inputs.len() + amount_selection.newly_selected_inputs.len() > INPUT_COUNT_MAX.into() {
+        if !largest_first
+            && self.selected_inputs.len() + amount_selection.newly_selected_inputs.len() > INPUT_COUNT_MAX.into()
+        {
             // Clear before trying with reversed ordering.
             log::debug!("Clearing amount selection");
             amount_selection = AmountSelection::new(self)?;
@@ -335,15 +535,7 @@ impl InputSelection {
             self.reduce_funds_of_chains(&mut amount_selection)?;
         }
 
-        log::debug!(
-            "Outputs {:?} selected to fulfill the amount requirement",
-            amount_selection.newly_selected_inputs
-        );
-
-        self.available_inputs
-            .retain(|input| !amount_selection.newly_selected_inputs.contains_key(input.output_id()));
-
-        Ok(amount_selection.into_newly_selected_inputs())
+        Ok(self.finish_amount_selection(amount_selection))
     }
 
     fn fulfill_amount_requirement_inner(
@@ -420,3 +612,65 @@ impl InputSelection {
         }
     }
 }
+
+// Depth first search for the combination of inputs with the smallest sum that fulfills the amount requirement. The
+// amounts are sorted from high to low.
+struct BranchAndBound<'a> {
+    amount_selection: &'a AmountSelection,
+    amounts: Vec<u64>,
+    // Sum of the amounts from an index to the end
+    remaining: Vec<u64>,
+    max_inputs: usize,
+    // No combination with a lower sum can fulfill the amount requirement
+    lower_bound: u64,
+    tries: usize,
+    selection: Vec<usize>,
+    best: Option<(u64, Vec<usize>)>,
+}
+
+impl BranchAndBound<'_> {
+    fn search(&mut self, start: usize, sum: u64) {
+        self.tries += 1;
+        if self.tries > BRANCH_AND_BOUND_MAX_TRIES {
+            return;
+        }
+
+        if self.amount_selection.missing_amount_with(sum) == 0 {
+            // Adding more inputs would only increase the remainder
+            if self.best.as_ref().is_none_or(|(best_sum, _)| sum < *best_sum) {
+                self.best = Some((sum, self.selection.clone()));
+            }
+            // There is no better combination than one without any remainder
+            if sum == self.lower_bound {
+                self.tries = BRANCH_AND_BOUND_MAX_TRIES;
+            }
+            return;
+        }
+
+        if self.selection.len() == self.max_inputs {
+            return;
+        }
+
+        for index in start..self.amounts.len() {
+            if sum + self.remaining[index] < self.lower_bound {
+                break;
+            }
+            let amount = self.amounts[index];
+            if self
+                .best
+                .as_ref()
+                .is_some_and(|(best_sum, _)| sum + amount >= *best_sum)
+            {
+                continue;
+            }
+
+            self.selection.push(index);
+            self.search(index + 1, sum + amount);
+            self.selection.pop();
+
+            if self.tries >= BRANCH_AND_BOUND_MAX_TRIES {
+                return;
+            }
+        }
+    }
+}
@@ -125,7 +125,8 @@ impl<'a> ClientBlockBuilder<'a> {
             protocol_parameters.clone(),
         )
         .required_inputs(required_inputs)
-        .timestamp(current_time);
+        .timestamp(current_time)
+        .coin_selection_strategy(self.coin_selection_strategy);
 
         if let Some(address) = self.custom_remainder_address {
             input_selection = input_selection.remainder_address(address);
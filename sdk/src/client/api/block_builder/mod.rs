@@ -9,11 +9,13 @@ use std::ops::Range;
 
 use packable::bounded::TryIntoBoundedU16Error;
 
-pub use self::transaction::verify_semantic;
+pub use self::transaction::{simulate_semantic, verify_semantic};
 use crate::{
     client::{
-        api::block_builder::input_selection::Burn, constants::SHIMMER_COIN_TYPE, secret::SecretManager, Client, Error,
-        Result,
+        api::block_builder::input_selection::{Burn, CoinSelectionStrategy},
+        constants::SHIMMER_COIN_TYPE,
+        secret::SecretManager,
+        Client, Error, Result,
     },
     types::block::{
         address::{Address, Ed25519Address},
@@ -43,6 +45,7 @@ pub struct ClientBlockBuilder<'a> {
     data: Option<Vec<u8>>,
     parents: Option<Parents>,
     burn: Option<Burn>,
+    coin_selection_strategy: CoinSelectionStrategy,
 }
 
 /// Block output address
@@ -86,6 +89,8 @@ pub struct ClientBlockBuilderOptions {
     pub parents: Option<Vec<BlockId>>,
     /// Explicit burning of aliases, nfts, foundries and native tokens
     pub burn: Option<Burn>,
+    /// Strategy used to select the inputs that provide the base coin amount
+    pub coin_selection_strategy: Option<CoinSelectionStrategy>,
 }
 
 impl<'a> ClientBlockBuilder<'a> {
@@ -105,6 +110,7 @@ impl<'a> ClientBlockBuilder<'a> {
             data: None,
             parents: None,
             burn: None,
+            coin_selection_strategy: CoinSelectionStrategy::default(),
         }
     }
 
@@ -114,6 +120,12 @@ impl<'a> ClientBlockBuilder<'a> {
         self
     }
 
+    /// Sets the strategy used to select the inputs that provide the base coin amount.
+    pub fn with_coin_selection_strategy(mut self, coin_selection_strategy: CoinSelectionStrategy) -> Self {
+        self.coin_selection_strategy = coin_selection_strategy;
+        self
+    }
+
     /// Sets the seed.
     pub fn with_secret_manager(mut self, manager: &'a SecretManager) -> Self {
         self.secret_manager.replace(manager);
@@ -305,6 +317,9 @@ impl<'a> ClientBlockBuilder<'a> {
         if let Some(burn) = options.burn {
             self = self.with_burn(burn);
         }
+        if let Some(coin_selection_strategy) = options.coin_selection_strategy {
+            self = self.with_coin_selection_strategy(coin_selection_strategy);
+        }
 
         Ok(self)
     }
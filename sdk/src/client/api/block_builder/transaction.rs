@@ -3,15 +3,22 @@
 
 //! Transaction preparation and signing
 
+use std::collections::{HashMap, HashSet};
+
 use packable::PackableExt;
 
 use crate::{
     client::{
-        api::{types::PreparedTransactionData, ClientBlockBuilder},
+        api::{
+            input_selection::{is_alias_transition, Error as InputSelectionError},
+            types::PreparedTransactionData,
+            ClientBlockBuilder,
+        },
         secret::{types::InputSigningData, SecretManageExt},
         Error, Result,
     },
     types::block::{
+        address::{Address, Ed25519Address},
         input::{Input, UtxoInput},
         output::{InputsCommitment, Output, OutputId},
         payload::{
@@ -19,7 +26,8 @@ use crate::{
             Payload, TaggedDataPayload,
         },
         semantic::{semantic_validation, ConflictReason, ValidationContext},
-        signature::Ed25519Signature,
+        signature::{Ed25519Signature, Signature},
+        unlock::{AliasUnlock, NftUnlock, ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
         Block, BlockId,
     },
 };
@@ -141,6 +149,114 @@ pub fn verify_semantic(
     Ok(semantic_validation(context, inputs.as_slice(), transaction.unlocks())?)
 }
 
+/// Verifies the semantic of a prepared transaction without signing it.
+///
+/// The unlocks are created with placeholder signatures that aren't verified, so the result predicts whether the
+/// transaction is accepted once it's signed.
+pub fn simulate_semantic(
+    prepared_transaction_data: &PreparedTransactionData,
+    current_time: u32,
+) -> crate::client::Result<ConflictReason> {
+    let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+    let unlocks = place_unlocks(
+        prepared_transaction_data,
+        current_time,
+        |_, ed25519_address| {
+            // The address is used as public key to keep the placeholder signatures unique
+            Ok(Some(Unlock::Signature(SignatureUnlock::new(Signature::Ed25519(
+                Ed25519Signature::new(**ed25519_address, [0; Ed25519Signature::SIGNATURE_LENGTH]),
+            )))))
+        },
+        &mut Vec::new(),
+    )?;
+
+    let transaction = TransactionPayload::new(prepared_transaction_data.essence.clone(), Unlocks::new(unlocks)?)?;
+    validate_transaction_payload_length(&transaction)?;
+
+    let transaction_id = transaction.id();
+    let inputs = prepared_transaction_data
+        .inputs_data
+        .iter()
+        .map(|input| (*input.output_id(), &input.output))
+        .collect::<Vec<(OutputId, &Output)>>();
+
+    let mut context = ValidationContext::new(
+        &transaction_id,
+        essence,
+        inputs.iter().map(|(id, input)| (id, *input)),
+        transaction.unlocks(),
+        current_time,
+    );
+    context.verify_signatures = false;
+
+    Ok(semantic_validation(context, inputs.as_slice(), transaction.unlocks())?)
+}
+
+/// Places the unlocks for the inputs of a prepared transaction.
+///
+/// `signature_unlock` is called for the first input of every ed25519 address, the following inputs with the same
+/// address reference it and inputs owned by an alias or nft address reference the input of that alias or nft. If
+/// `signature_unlock` returns `None`, the input is added to `missing_unlocks` together with its required address,
+/// as well as the inputs that would be unlocked through it.
+pub(crate) fn place_unlocks(
+    prepared_transaction_data: &PreparedTransactionData,
+    current_time: u32,
+    mut signature_unlock: impl FnMut(&InputSigningData, &Ed25519Address) -> Result<Option<Unlock>>,
+    missing_unlocks: &mut Vec<(OutputId, Address)>,
+) -> Result<Vec<Unlock>> {
+    let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+    let mut unlocks = Vec::new();
+    let mut unlock_indexes = HashMap::<Address, usize>::new();
+    // Alias and nft addresses of inputs that are missing their unlock
+    let mut missing_addresses = HashSet::<Address>::new();
+
+    // Assuming inputs_data is ordered by address type
+    for (current_unlock_index, input) in prepared_transaction_data.inputs_data.iter().enumerate() {
+        let alias_transition = is_alias_transition(input, essence.outputs()).map(|t| t.0);
+        let (input_address, unlocked_address) =
+            input
+                .output
+                .required_and_unlocked_address(current_time, input.output_id(), alias_transition)?;
+
+        let unlock = match unlock_indexes.get(&input_address) {
+            // If we already have an [Unlock] for this address, add a [Unlock] based on the address type
+            Some(unlock_index) => match input_address {
+                Address::Alias(_) => Some(Unlock::Alias(AliasUnlock::new(*unlock_index as u16)?)),
+                Address::Ed25519(_) => Some(Unlock::Reference(ReferenceUnlock::new(*unlock_index as u16)?)),
+                Address::Nft(_) => Some(Unlock::Nft(NftUnlock::new(*unlock_index as u16)?)),
+            },
+            None => match input_address {
+                Address::Ed25519(ed25519_address) => signature_unlock(input, &ed25519_address)?,
+                _ if missing_addresses.contains(&input_address) => None,
+                // We can only sign ed25519 addresses and unlock_indexes needs to contain the alias or nft address
+                // already at this point, because the reference index needs to be lower than the current unlock index
+                _ => return Err(InputSelectionError::MissingInputWithEd25519Address)?,
+            },
+        };
+
+        let Some(unlock) = unlock else {
+            missing_unlocks.push((*input.output_id(), input_address));
+            missing_addresses.extend(unlocked_address);
+            continue;
+        };
+
+        // Add the ed25519 address to the unlock_indexes, so it gets referenced if further inputs have the same
+        // address in their unlock condition
+        if let Unlock::Signature(_) = unlock {
+            unlock_indexes.insert(input_address, current_unlock_index);
+        }
+        unlocks.push(unlock);
+
+        // Alias and nft addresses unlocked by this input can be used to unlock further inputs via [Unlock::Alias]
+        // or [Unlock::Nft]
+        if let Some(unlocked_address) = unlocked_address {
+            unlock_indexes.insert(unlocked_address, current_unlock_index);
+        }
+    }
+
+    Ok(unlocks)
+}
+
 /// Verifies that the transaction payload doesn't exceed the block size limit with 8 parents.
 pub fn validate_transaction_payload_length(transaction_payload: &TransactionPayload) -> Result<()> {
     let transaction_payload_bytes = transaction_payload.pack_to_vec();
@@ -7,8 +7,13 @@ mod address;
 mod block_builder;
 mod consolidation;
 mod high_level;
+mod partially_signed;
+mod transaction_bundle;
+mod transaction_summary;
 mod types;
 
-pub use self::{address::*, block_builder::*, types::*};
+pub use self::{
+    address::*, block_builder::*, partially_signed::*, transaction_bundle::*, transaction_summary::*, types::*,
+};
 
 const ADDRESS_GAP_RANGE: u32 = 20;
@@ -0,0 +1,237 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::slip10::Chain,
+};
+use serde::{de, Deserialize, Deserializer, Serialize};
+
+use crate::{
+    client::{
+        api::{
+            transaction::{place_unlocks, validate_transaction_payload_length},
+            PreparedTransactionData, PreparedTransactionDataDto, SignedTransactionData,
+        },
+        secret::SecretManage,
+        Error, Result,
+    },
+    types::block::{
+        address::{Address, Ed25519Address},
+        output::OutputId,
+        payload::TransactionPayload,
+        protocol::ProtocolParameters,
+        signature::{dto::Ed25519SignatureDto, Ed25519Signature, Signature},
+        unlock::{SignatureUnlock, Unlock, Unlocks},
+    },
+    utils::unix_timestamp_now,
+};
+
+/// A prepared transaction together with the signatures collected so far, for transactions with inputs that are
+/// controlled by different co-signers, each of them using their own secret manager.
+///
+/// Every co-signer adds the signatures for the addresses they control with
+/// [`PartiallySignedTransactionData::sign()`], the containers of the co-signers are then combined with
+/// [`PartiallySignedTransactionData::merge()`]. As long as [`PartiallySignedTransactionData::missing_unlocks()`] isn't
+/// empty, more signatures are required.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartiallySignedTransactionData {
+    /// The transaction to sign
+    pub prepared_transaction_data: PreparedTransactionData,
+    /// The signatures of the essence hash, at most one for each address
+    signatures: Vec<Ed25519Signature>,
+}
+
+impl PartiallySignedTransactionData {
+    /// Creates a new [`PartiallySignedTransactionData`] without any signatures.
+    pub fn new(prepared_transaction_data: PreparedTransactionData) -> Self {
+        Self {
+            prepared_transaction_data,
+            signatures: Vec::new(),
+        }
+    }
+
+    /// Returns the collected signatures.
+    pub fn signatures(&self) -> &[Ed25519Signature] {
+        &self.signatures
+    }
+
+    /// Adds a signature of the essence hash, a signature for an address that already signed replaces the previous one.
+    pub fn add_signature(&mut self, signature: Ed25519Signature) -> Result<()> {
+        let address = signature_address(&signature);
+        signature.is_valid(&self.prepared_transaction_data.essence.hash(), &address)?;
+
+        self.signatures
+            .retain(|existing| existing.public_key() != signature.public_key());
+        self.signatures.push(signature);
+
+        Ok(())
+    }
+
+    /// Signs the essence hash with the key of the given chain.
+    pub async fn sign<S: SecretManage>(&mut self, secret_manager: &S, chain: &Chain) -> Result<()>
+    where
+        Error: From<S::Error>,
+    {
+        let signature = secret_manager
+            .sign_ed25519(&self.prepared_transaction_data.essence.hash(), chain)
+            .await?;
+
+        self.add_signature(signature)
+    }
+
+    /// Signs the essence hash for every input that has a chain set.
+    pub async fn sign_inputs<S: SecretManage>(&mut self, secret_manager: &S) -> Result<()>
+    where
+        Error: From<S::Error>,
+    {
+        let chains = self
+            .prepared_transaction_data
+            .inputs_data
+            .iter()
+            .filter_map(|input| input.chain.clone())
+            .collect::<HashSet<_>>();
+
+        for chain in chains {
+            self.sign(secret_manager, &chain).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Adds the signatures of another [`PartiallySignedTransactionData`] for the same transaction essence, they are
+    /// verified like with [`PartiallySignedTransactionData::add_signature()`].
+    pub fn merge(&mut self, other: Self) -> Result<()> {
+        if self.prepared_transaction_data.essence != other.prepared_transaction_data.essence {
+            return Err(Error::TransactionEssenceMismatch);
+        }
+
+        for signature in other.signatures {
+            self.add_signature(signature)?;
+        }
+
+        Ok(())
+    }
+
+    /// Returns the ids of the inputs that can't be unlocked with the collected signatures, together with the address
+    /// that is required to unlock them.
+    pub fn missing_unlocks(&self, time: Option<u32>) -> Result<Vec<(OutputId, Address)>> {
+        let mut missing_unlocks = Vec::new();
+        self.unlocks(time, &mut missing_unlocks)?;
+        Ok(missing_unlocks)
+    }
+
+    /// Builds the unlocks from the collected signatures, fails if any input is still missing an unlock.
+    pub fn finish(self, time: Option<u32>) -> Result<SignedTransactionData> {
+        let mut missing_unlocks = Vec::new();
+        let unlocks = self.unlocks(time, &mut missing_unlocks)?;
+
+        if !missing_unlocks.is_empty() {
+            return Err(Error::MissingUnlocks(
+                missing_unlocks.into_iter().map(|(output_id, _)| output_id).collect(),
+            ));
+        }
+
+        let transaction_payload =
+            TransactionPayload::new(self.prepared_transaction_data.essence, Unlocks::new(unlocks)?)?;
+
+        validate_transaction_payload_length(&transaction_payload)?;
+
+        Ok(SignedTransactionData {
+            transaction_payload,
+            inputs_data: self.prepared_transaction_data.inputs_data,
+        })
+    }
+
+    // Places the unlocks the same way as `SecretManager::default_sign_transaction_essence()`, inputs without an
+    // available signature are added to `missing_unlocks` instead.
+    fn unlocks(&self, time: Option<u32>, missing_unlocks: &mut Vec<(OutputId, Address)>) -> Result<Vec<Unlock>> {
+        let signatures = self
+            .signatures
+            .iter()
+            .map(|signature| (signature_address(signature), signature))
+            .collect::<HashMap<_, _>>();
+
+        place_unlocks(
+            &self.prepared_transaction_data,
+            time.unwrap_or_else(|| unix_timestamp_now().as_secs() as u32),
+            |_, ed25519_address| {
+                Ok(signatures
+                    .get(ed25519_address)
+                    .map(|signature| Unlock::Signature(SignatureUnlock::new(Signature::Ed25519((*signature).clone())))))
+            },
+            missing_unlocks,
+        )
+    }
+}
+
+// The signatures are verified when deserializing, so a container can't hold invalid signatures
+impl<'de> Deserialize<'de> for PartiallySignedTransactionData {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(rename_all = "camelCase")]
+        struct UncheckedPartiallySignedTransactionData {
+            prepared_transaction_data: PreparedTransactionData,
+            signatures: Vec<Ed25519Signature>,
+        }
+
+        let unchecked = UncheckedPartiallySignedTransactionData::deserialize(deserializer)?;
+        let mut partially_signed_transaction_data = Self::new(unchecked.prepared_transaction_data);
+        for signature in unchecked.signatures {
+            partially_signed_transaction_data
+                .add_signature(signature)
+                .map_err(de::Error::custom)?;
+        }
+
+        Ok(partially_signed_transaction_data)
+    }
+}
+
+fn signature_address(signature: &Ed25519Signature) -> Ed25519Address {
+    Ed25519Address::new(Blake2b256::digest(signature.public_key()).into())
+}
+
+/// Dto for [`PartiallySignedTransactionData`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PartiallySignedTransactionDataDto {
+    /// The transaction to sign
+    pub prepared_transaction_data: PreparedTransactionDataDto,
+    /// The signatures of the essence hash
+    pub signatures: Vec<Ed25519SignatureDto>,
+}
+
+impl From<&PartiallySignedTransactionData> for PartiallySignedTransactionDataDto {
+    fn from(value: &PartiallySignedTransactionData) -> Self {
+        Self {
+            prepared_transaction_data: PreparedTransactionDataDto::from(&value.prepared_transaction_data),
+            signatures: value.signatures.iter().map(Ed25519SignatureDto::from).collect(),
+        }
+    }
+}
+
+impl PartiallySignedTransactionData {
+    /// Conversion from [`PartiallySignedTransactionDataDto`] to [`PartiallySignedTransactionData`], the signatures
+    /// are verified against the essence hash.
+    pub fn try_from_dto(
+        value: &PartiallySignedTransactionDataDto,
+        protocol_parameters: &ProtocolParameters,
+    ) -> Result<Self> {
+        let mut partially_signed_transaction_data = Self::new(PreparedTransactionData::try_from_dto(
+            &value.prepared_transaction_data,
+            protocol_parameters,
+        )?);
+
+        for signature in &value.signatures {
+            partially_signed_transaction_data.add_signature(Ed25519Signature::try_from(signature)?)?;
+        }
+
+        Ok(partially_signed_transaction_data)
+    }
+}
@@ -0,0 +1,340 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::slip10::Chain,
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        api::{
+            transaction::validate_transaction_payload_length, PreparedTransactionData, PreparedTransactionDataDto,
+            SignedTransactionData,
+        },
+        secret::SecretManageExt,
+        Error, Result,
+    },
+    types::block::{
+        input::{Input, UtxoInput},
+        output::InputsCommitment,
+        payload::{transaction::TransactionEssence, TransactionPayload},
+        protocol::{dto::ProtocolParametersDto, ProtocolParameters},
+        unlock::{dto::UnlockDto, Unlock, Unlocks},
+    },
+};
+
+/// The current version of the [`TransactionBundle`] format.
+pub const TRANSACTION_BUNDLE_VERSION: u8 = 1;
+
+/// The magic bytes at the start of a binary [`TransactionBundle`].
+const TRANSACTION_BUNDLE_MAGIC: &[u8; 4] = b"IOTB";
+
+/// A prepared transaction together with everything an offline signer needs to validate it before signing it, and the
+/// unlocks once it's signed.
+///
+/// A bundle is exchanged either as JSON, see [`TransactionBundle::to_json()`], or in the binary format of
+/// [`TransactionBundle::to_bytes()`], which is made of the magic bytes `IOTB`, the version byte, the length of the
+/// JSON as little endian `u32` and the JSON itself. The JSON contains a Blake2b-256 checksum of its content, so both
+/// formats are checked for corruption when they are read.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionBundle {
+    protocol_parameters: ProtocolParameters,
+    prepared_transaction_data: PreparedTransactionData,
+    unlocks: Option<Unlocks>,
+}
+
+impl TransactionBundle {
+    /// Creates a new unsigned [`TransactionBundle`], the prepared transaction has to be valid for the protocol
+    /// parameters.
+    pub fn new(
+        prepared_transaction_data: PreparedTransactionData,
+        protocol_parameters: ProtocolParameters,
+    ) -> Result<Self> {
+        let bundle = Self {
+            protocol_parameters,
+            prepared_transaction_data,
+            unlocks: None,
+        };
+        bundle.validate()?;
+
+        Ok(bundle)
+    }
+
+    /// Returns the protocol parameters of the network the transaction is issued in.
+    pub fn protocol_parameters(&self) -> &ProtocolParameters {
+        &self.protocol_parameters
+    }
+
+    /// Returns the network id of the transaction.
+    pub fn network_id(&self) -> u64 {
+        self.protocol_parameters.network_id()
+    }
+
+    /// Returns the prepared transaction.
+    pub fn prepared_transaction_data(&self) -> &PreparedTransactionData {
+        &self.prepared_transaction_data
+    }
+
+    /// Sets the BIP32 chain with which the input at the index is signed, for inputs prepared without one, like the
+    /// inputs of watch-only accounts. The chain isn't part of the transaction, so it can be set by the signer.
+    pub fn set_input_chain(&mut self, input_index: usize, chain: Chain) -> Result<()> {
+        let input = self
+            .prepared_transaction_data
+            .inputs_data
+            .get_mut(input_index)
+            .ok_or_else(|| Error::InvalidTransactionBundle(format!("no input with index {input_index}")))?;
+        input.chain.replace(chain);
+
+        Ok(())
+    }
+
+    /// Returns the unlocks of the transaction, if it's signed already.
+    pub fn unlocks(&self) -> Option<&Unlocks> {
+        self.unlocks.as_ref()
+    }
+
+    /// Returns whether the transaction is signed.
+    pub fn is_signed(&self) -> bool {
+        self.unlocks.is_some()
+    }
+
+    /// Checks that the prepared transaction belongs to the network of the protocol parameters, that the inputs data
+    /// matches the inputs and the inputs commitment of the essence and that there is an unlock for each input.
+    pub fn validate(&self) -> Result<()> {
+        let TransactionEssence::Regular(essence) = &self.prepared_transaction_data.essence;
+        let inputs_data = &self.prepared_transaction_data.inputs_data;
+
+        if essence.network_id() != self.network_id() {
+            return Err(Error::InvalidTransactionBundle(format!(
+                "network id {} of the essence doesn't match the network id {}",
+                essence.network_id(),
+                self.network_id()
+            )));
+        }
+
+        if essence.inputs().len() != inputs_data.len()
+            || essence
+                .inputs()
+                .iter()
+                .zip(inputs_data)
+                .any(|(input, input_data)| *input != Input::Utxo(UtxoInput::from(*input_data.output_id())))
+        {
+            return Err(Error::InvalidTransactionBundle(
+                "inputs data doesn't match the inputs of the essence".to_string(),
+            ));
+        }
+
+        if *essence.inputs_commitment() != InputsCommitment::new(inputs_data.iter().map(|input| &input.output)) {
+            return Err(Error::InvalidTransactionBundle(
+                "inputs data doesn't match the inputs commitment of the essence".to_string(),
+            ));
+        }
+
+        if let Some(unlocks) = &self.unlocks {
+            if unlocks.len() != inputs_data.len() {
+                return Err(Error::InvalidTransactionBundle(format!(
+                    "{} unlocks for {} inputs",
+                    unlocks.len(),
+                    inputs_data.len()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates the transaction and signs it with the secret manager.
+    pub async fn sign<S: SecretManageExt>(&mut self, secret_manager: &S, time: Option<u32>) -> Result<()>
+    where
+        Error: From<S::Error>,
+    {
+        self.validate()?;
+
+        let unlocks = secret_manager
+            .sign_transaction_essence(&self.prepared_transaction_data, time)
+            .await?;
+        self.unlocks.replace(unlocks);
+
+        self.validate()
+    }
+
+    /// Returns the signed transaction, fails if the transaction isn't signed yet.
+    pub fn signed_transaction_data(&self) -> Result<SignedTransactionData> {
+        let unlocks = self
+            .unlocks
+            .clone()
+            .ok_or_else(|| Error::InvalidTransactionBundle("the transaction isn't signed".to_string()))?;
+        let transaction_payload = TransactionPayload::new(self.prepared_transaction_data.essence.clone(), unlocks)?;
+
+        validate_transaction_payload_length(&transaction_payload)?;
+
+        Ok(SignedTransactionData {
+            transaction_payload,
+            inputs_data: self.prepared_transaction_data.inputs_data.clone(),
+        })
+    }
+
+    /// Serializes the bundle to JSON.
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(&TransactionBundleDto::try_from(self)?)?)
+    }
+
+    /// Deserializes and validates a bundle from JSON.
+    pub fn from_json(json: &str) -> Result<Self> {
+        Self::try_from_dto(&serde_json::from_str(json)?)
+    }
+
+    /// Serializes the bundle to the binary format.
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let json = serde_json::to_vec(&TransactionBundleDto::try_from(self)?)?;
+        let length = u32::try_from(json.len())
+            .map_err(|_| Error::InvalidTransactionBundle(format!("bundle too large: {} bytes", json.len())))?;
+
+        let mut bytes = Vec::with_capacity(TRANSACTION_BUNDLE_MAGIC.len() + 5 + json.len());
+        bytes.extend_from_slice(TRANSACTION_BUNDLE_MAGIC);
+        bytes.push(TRANSACTION_BUNDLE_VERSION);
+        bytes.extend_from_slice(&length.to_le_bytes());
+        bytes.extend_from_slice(&json);
+
+        Ok(bytes)
+    }
+
+    /// Deserializes and validates a bundle from the binary format.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let header_length = TRANSACTION_BUNDLE_MAGIC.len() + 5;
+        if bytes.len() < header_length || !bytes.starts_with(TRANSACTION_BUNDLE_MAGIC) {
+            return Err(Error::InvalidTransactionBundle("not a transaction bundle".to_string()));
+        }
+
+        let version = bytes[TRANSACTION_BUNDLE_MAGIC.len()];
+        if version != TRANSACTION_BUNDLE_VERSION {
+            return Err(Error::InvalidTransactionBundle(format!(
+                "unsupported version {version}"
+            )));
+        }
+
+        let mut length = [0u8; 4];
+        length.copy_from_slice(&bytes[TRANSACTION_BUNDLE_MAGIC.len() + 1..header_length]);
+        let json = &bytes[header_length..];
+        if json.len() != u32::from_le_bytes(length) as usize {
+            return Err(Error::InvalidTransactionBundle("truncated bundle".to_string()));
+        }
+
+        Self::try_from_dto(&serde_json::from_slice(json)?)
+    }
+}
+
+/// Dto for [`TransactionBundle`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransactionBundleDto {
+    /// The version of the bundle format
+    pub version: u8,
+    /// The network id of the transaction
+    pub network_id: String,
+    /// The protocol parameters of the network
+    pub protocol_parameters: ProtocolParametersDto,
+    /// The transaction to sign
+    pub prepared_transaction_data: PreparedTransactionDataDto,
+    /// The unlocks of the signed transaction
+    pub unlocks: Option<Vec<UnlockDto>>,
+    /// The hex encoded Blake2b-256 hash of the other fields, serialized as compact JSON in this order
+    pub checksum: String,
+}
+
+impl TransactionBundleDto {
+    fn checksum(&self) -> Result<String> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Content<'a> {
+            version: u8,
+            network_id: &'a str,
+            protocol_parameters: &'a ProtocolParametersDto,
+            prepared_transaction_data: &'a PreparedTransactionDataDto,
+            unlocks: &'a Option<Vec<UnlockDto>>,
+        }
+
+        let content = serde_json::to_vec(&Content {
+            version: self.version,
+            network_id: &self.network_id,
+            protocol_parameters: &self.protocol_parameters,
+            prepared_transaction_data: &self.prepared_transaction_data,
+            unlocks: &self.unlocks,
+        })?;
+
+        Ok(prefix_hex::encode(Blake2b256::digest(content).as_slice()))
+    }
+}
+
+impl TryFrom<&TransactionBundle> for TransactionBundleDto {
+    type Error = Error;
+
+    fn try_from(value: &TransactionBundle) -> Result<Self> {
+        let mut dto = Self {
+            version: TRANSACTION_BUNDLE_VERSION,
+            network_id: value.network_id().to_string(),
+            protocol_parameters: ProtocolParametersDto::from(&value.protocol_parameters),
+            prepared_transaction_data: PreparedTransactionDataDto::from(&value.prepared_transaction_data),
+            unlocks: value
+                .unlocks
+                .as_ref()
+                .map(|unlocks| unlocks.iter().map(UnlockDto::from).collect()),
+            checksum: String::new(),
+        };
+        dto.checksum = dto.checksum()?;
+
+        Ok(dto)
+    }
+}
+
+impl TransactionBundle {
+    /// Conversion from [`TransactionBundleDto`] to [`TransactionBundle`], the version, the checksum and the content
+    /// of the bundle are validated.
+    pub fn try_from_dto(value: &TransactionBundleDto) -> Result<Self> {
+        if value.version != TRANSACTION_BUNDLE_VERSION {
+            return Err(Error::InvalidTransactionBundle(format!(
+                "unsupported version {}",
+                value.version
+            )));
+        }
+
+        if value.checksum()? != value.checksum {
+            return Err(Error::InvalidTransactionBundle("checksum mismatch".to_string()));
+        }
+
+        let protocol_parameters = ProtocolParameters::try_from(value.protocol_parameters.clone())?;
+
+        if value.network_id != protocol_parameters.network_id().to_string() {
+            return Err(Error::InvalidTransactionBundle(format!(
+                "network id {} doesn't match the network name {}",
+                value.network_id,
+                protocol_parameters.network_name()
+            )));
+        }
+
+        let bundle = Self {
+            prepared_transaction_data: PreparedTransactionData::try_from_dto(
+                &value.prepared_transaction_data,
+                &protocol_parameters,
+            )?,
+            protocol_parameters,
+            unlocks: value
+                .unlocks
+                .as_ref()
+                .map(|unlocks| {
+                    Ok::<_, Error>(Unlocks::new(
+                        unlocks
+                            .iter()
+                            .map(Unlock::try_from)
+                            .collect::<std::result::Result<Vec<_>, _>>()?,
+                    )?)
+                })
+                .transpose()?,
+        };
+        bundle.validate()?;
+
+        Ok(bundle)
+    }
+}
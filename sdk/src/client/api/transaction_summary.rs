@@ -0,0 +1,475 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::BTreeMap, fmt};
+
+use primitive_types::U256;
+
+use crate::{
+    client::{
+        api::{
+            input_selection::{is_alias_transition, Burn},
+            PreparedTransactionData,
+        },
+        Result,
+    },
+    types::block::{
+        address::Address,
+        output::{unlock_condition::UnlockCondition, ChainId, NativeTokens, Output, TokenId, TokenScheme},
+        payload::transaction::TransactionEssence,
+        Error as BlockError,
+    },
+    utils::unix_timestamp_now,
+};
+
+/// A human readable summary of what a [`PreparedTransactionData`] does, to show it to users before the transaction is
+/// signed, e.g. when blind-signing with a Ledger Nano.
+///
+/// Amounts that are locked by a storage deposit return unlock condition aren't counted as received by the owner of the
+/// output, they are listed in [`TransactionSummary::storage_deposits_locked`] and
+/// [`TransactionSummary::storage_deposits_returned`] instead.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionSummary {
+    /// The balance changes of all addresses that own inputs or outputs of the transaction
+    pub balance_changes: Vec<BalanceChange>,
+    /// The aliases, foundries and NFTs that are created, moved or destroyed
+    pub chain_transitions: Vec<ChainTransition>,
+    /// The outputs created by the transaction
+    pub outputs: Vec<OutputSummary>,
+    /// The address the remainder is sent to, if there is a remainder
+    pub remainder_address: Option<Address>,
+    /// The storage deposits of created outputs that have to be returned to the given addresses
+    pub storage_deposits_locked: Vec<(Address, u64)>,
+    /// The storage deposits of spent outputs that are returned to the given addresses
+    pub storage_deposits_returned: Vec<(Address, u64)>,
+    /// The native tokens minted by foundry transitions
+    pub native_tokens_minted: BTreeMap<TokenId, U256>,
+    /// The native tokens melted by foundry transitions
+    pub native_tokens_melted: BTreeMap<TokenId, U256>,
+    /// The native tokens that are burned, i.e. neither melted nor transferred to an output, either explicitly with a
+    /// [`Burn`] or implicitly
+    pub native_tokens_burned: BTreeMap<TokenId, U256>,
+}
+
+/// The base coins and native tokens an address spends and receives in a transaction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BalanceChange {
+    /// The address
+    pub address: Address,
+    /// The base coins of the spent inputs
+    pub base_coin_spent: u64,
+    /// The base coins of the created outputs
+    pub base_coin_received: u64,
+    /// The native tokens of the spent inputs
+    pub native_tokens_spent: BTreeMap<TokenId, U256>,
+    /// The native tokens of the created outputs
+    pub native_tokens_received: BTreeMap<TokenId, U256>,
+}
+
+impl BalanceChange {
+    fn new(address: Address) -> Self {
+        Self {
+            address,
+            base_coin_spent: 0,
+            base_coin_received: 0,
+            native_tokens_spent: BTreeMap::new(),
+            native_tokens_received: BTreeMap::new(),
+        }
+    }
+
+    /// Returns the net change of base coins, negative if the address spends more than it receives.
+    pub fn base_coin(&self) -> i128 {
+        self.base_coin_received as i128 - self.base_coin_spent as i128
+    }
+}
+
+/// A chain output that is created, moved or destroyed.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ChainTransition {
+    /// The id of the chain, null for aliases and NFTs that are created by the transaction
+    pub chain_id: ChainId,
+    /// The owner of the spent chain output, `None` if the chain is created
+    pub from: Option<Address>,
+    /// The owner of the created chain output, `None` if the chain is destroyed
+    pub to: Option<Address>,
+}
+
+impl ChainTransition {
+    /// Returns whether the chain is created by the transaction.
+    pub fn is_created(&self) -> bool {
+        self.from.is_none()
+    }
+
+    /// Returns whether the chain is destroyed by the transaction.
+    pub fn is_destroyed(&self) -> bool {
+        self.to.is_none()
+    }
+}
+
+/// An output created by a transaction.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OutputSummary {
+    /// The index of the output in the transaction
+    pub index: u16,
+    /// The owner of the output, the state controller for aliases and the alias for foundries
+    pub owner: Option<Address>,
+    /// The base coins of the output
+    pub amount: u64,
+    /// The native tokens of the output
+    pub native_tokens: Option<NativeTokens>,
+    /// The unlock conditions of the output
+    pub unlock_conditions: Vec<UnlockCondition>,
+    /// Whether the output is the remainder
+    pub is_remainder: bool,
+}
+
+impl TransactionSummary {
+    /// Summarizes a prepared transaction, `burn` are the burn options it was prepared with. `time` is used to
+    /// determine who can unlock inputs with an expiration and defaults to the current time.
+    pub fn new(
+        prepared_transaction_data: &PreparedTransactionData,
+        burn: Option<&Burn>,
+        time: Option<u32>,
+    ) -> Result<Self> {
+        let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+        let outputs = essence.outputs();
+        let time = time.unwrap_or_else(|| unix_timestamp_now().as_secs() as u32);
+
+        let mut balance_changes = BTreeMap::<Address, BalanceChange>::new();
+        let mut chain_transitions = Vec::new();
+        let mut storage_deposits_locked = Vec::new();
+        let mut storage_deposits_returned = Vec::new();
+        let mut native_tokens_in = BTreeMap::new();
+        let mut native_tokens_out = BTreeMap::new();
+        let mut native_tokens_minted = BTreeMap::new();
+        let mut native_tokens_melted = BTreeMap::new();
+
+        for input in &prepared_transaction_data.inputs_data {
+            let alias_transition = is_alias_transition(input, outputs).map(|t| t.0);
+            let (owner, _) = input
+                .output
+                .required_and_unlocked_address(time, input.output_id(), alias_transition)?;
+            // The storage deposit only has to be returned if the output isn't unlocked by the expiration return address
+            let storage_deposit_return = input.output.unlock_conditions().and_then(|unlock_conditions| {
+                if unlock_conditions.is_expired(time) {
+                    None
+                } else {
+                    unlock_conditions.storage_deposit_return()
+                }
+            });
+
+            // The outputs of a bundle aren't necessarily valid, so the storage deposit return amount can exceed the
+            // amount of the output
+            let amount = input.output.amount().saturating_sub(
+                storage_deposit_return.map_or(0, |storage_deposit_return| storage_deposit_return.amount()),
+            );
+            let balance_change = balance_changes
+                .entry(owner)
+                .or_insert_with(|| BalanceChange::new(owner));
+            balance_change.base_coin_spent = balance_change.base_coin_spent.saturating_add(amount);
+            if let Some(native_tokens) = input.output.native_tokens() {
+                add_native_tokens(&mut balance_change.native_tokens_spent, native_tokens);
+                add_native_tokens(&mut native_tokens_in, native_tokens);
+            }
+
+            if let Some(storage_deposit_return) = storage_deposit_return {
+                storage_deposits_returned.push((
+                    *storage_deposit_return.return_address(),
+                    storage_deposit_return.amount(),
+                ));
+            }
+
+            if let Some(chain_id) = input.output.chain_id() {
+                let chain_id = chain_id.or_from_output_id(input.output_id());
+                let to = outputs
+                    .iter()
+                    .find(|output| output.chain_id() == Some(chain_id))
+                    .and_then(output_owner);
+                chain_transitions.push(ChainTransition {
+                    chain_id,
+                    from: Some(owner),
+                    to,
+                });
+            }
+        }
+
+        let remainder_output = prepared_transaction_data
+            .remainder
+            .as_ref()
+            .map(|remainder| &remainder.output);
+        let mut remainder_index = None;
+        let mut output_summaries = Vec::with_capacity(outputs.len());
+
+        for (index, output) in outputs.iter().enumerate() {
+            let owner = output_owner(output);
+            let unlock_conditions = output.unlock_conditions();
+            let storage_deposit_return =
+                unlock_conditions.and_then(|unlock_conditions| unlock_conditions.storage_deposit_return());
+
+            if let Some(owner) = owner {
+                let amount = output.amount().saturating_sub(
+                    storage_deposit_return.map_or(0, |storage_deposit_return| storage_deposit_return.amount()),
+                );
+                let balance_change = balance_changes
+                    .entry(owner)
+                    .or_insert_with(|| BalanceChange::new(owner));
+                balance_change.base_coin_received = balance_change.base_coin_received.saturating_add(amount);
+                if let Some(native_tokens) = output.native_tokens() {
+                    add_native_tokens(&mut balance_change.native_tokens_received, native_tokens);
+                }
+            }
+            if let Some(native_tokens) = output.native_tokens() {
+                add_native_tokens(&mut native_tokens_out, native_tokens);
+            }
+
+            if let Some(storage_deposit_return) = storage_deposit_return {
+                storage_deposits_locked.push((
+                    *storage_deposit_return.return_address(),
+                    storage_deposit_return.amount(),
+                ));
+            }
+
+            if let Some(chain_id) = output.chain_id() {
+                let input = prepared_transaction_data.inputs_data.iter().find(|input| {
+                    input
+                        .output
+                        .chain_id()
+                        .map(|input_chain_id| input_chain_id.or_from_output_id(input.output_id()))
+                        == Some(chain_id)
+                });
+                if input.is_none() {
+                    chain_transitions.push(ChainTransition {
+                        chain_id,
+                        from: None,
+                        to: owner,
+                    });
+                }
+
+                if let Output::Foundry(foundry) = output {
+                    let TokenScheme::Simple(token_scheme) = foundry.token_scheme();
+                    let (minted_before, melted_before) = match input.map(|input| &input.output) {
+                        Some(Output::Foundry(input_foundry)) => {
+                            let TokenScheme::Simple(input_token_scheme) = input_foundry.token_scheme();
+                            (input_token_scheme.minted_tokens(), input_token_scheme.melted_tokens())
+                        }
+                        _ => (U256::zero(), U256::zero()),
+                    };
+                    if token_scheme.minted_tokens() > minted_before {
+                        native_tokens_minted.insert(foundry.token_id(), token_scheme.minted_tokens() - minted_before);
+                    }
+                    if token_scheme.melted_tokens() > melted_before {
+                        native_tokens_melted.insert(foundry.token_id(), token_scheme.melted_tokens() - melted_before);
+                    }
+                }
+            }
+
+            let is_remainder = remainder_index.is_none() && Some(output) == remainder_output;
+            if is_remainder {
+                remainder_index.replace(index);
+            }
+
+            output_summaries.push(OutputSummary {
+                index: index as u16,
+                owner,
+                amount: output.amount(),
+                native_tokens: output.native_tokens().cloned(),
+                unlock_conditions: unlock_conditions.map_or_else(Vec::new, |unlock_conditions| {
+                    unlock_conditions.iter().cloned().collect()
+                }),
+                is_remainder,
+            });
+        }
+
+        // Native tokens that are neither transferred nor melted are burned, this includes tokens that are minted by the
+        // transaction and tokens that are explicitly burned
+        let mut native_tokens_burned = BTreeMap::new();
+        let token_ids = native_tokens_in
+            .keys()
+            .chain(native_tokens_minted.keys())
+            .chain(burn.into_iter().flat_map(|burn| burn.native_tokens().keys()))
+            .copied()
+            .collect::<std::collections::BTreeSet<_>>();
+        for token_id in token_ids {
+            let available = native_tokens_in
+                .get(&token_id)
+                .copied()
+                .unwrap_or_default()
+                .checked_add(native_tokens_minted.get(&token_id).copied().unwrap_or_default())
+                .ok_or(BlockError::ConsumedNativeTokensAmountOverflow)?;
+            let used = native_tokens_out
+                .get(&token_id)
+                .copied()
+                .unwrap_or_default()
+                .checked_add(native_tokens_melted.get(&token_id).copied().unwrap_or_default())
+                .ok_or(BlockError::CreatedNativeTokensAmountOverflow)?;
+            if available > used {
+                native_tokens_burned.insert(token_id, available - used);
+            }
+        }
+
+        Ok(Self {
+            balance_changes: balance_changes.into_values().collect(),
+            chain_transitions,
+            outputs: output_summaries,
+            remainder_address: prepared_transaction_data
+                .remainder
+                .as_ref()
+                .map(|remainder| remainder.address),
+            storage_deposits_locked,
+            storage_deposits_returned,
+            native_tokens_minted,
+            native_tokens_melted,
+            native_tokens_burned,
+        })
+    }
+}
+
+impl TransactionSummary {
+    /// Returns an object that implements [`Display`](fmt::Display) to show the summary to users, with addresses
+    /// encoded with the given bech32 HRP.
+    pub fn display<'a>(&'a self, bech32_hrp: &'a str) -> TransactionSummaryDisplay<'a> {
+        TransactionSummaryDisplay {
+            summary: self,
+            bech32_hrp,
+        }
+    }
+}
+
+/// Helper struct to display a [`TransactionSummary`] with bech32 encoded addresses, see
+/// [`TransactionSummary::display()`].
+pub struct TransactionSummaryDisplay<'a> {
+    summary: &'a TransactionSummary,
+    bech32_hrp: &'a str,
+}
+
+impl fmt::Display for TransactionSummaryDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let summary = self.summary;
+        let bech32 = |address: &Address| address.to_bech32(self.bech32_hrp);
+
+        writeln!(f, "Balance changes:")?;
+        for balance_change in &summary.balance_changes {
+            writeln!(
+                f,
+                "  {}: {:+} base coins",
+                bech32(&balance_change.address),
+                balance_change.base_coin()
+            )?;
+            for (token_id, amount) in &balance_change.native_tokens_spent {
+                writeln!(f, "    -{amount} {token_id}")?;
+            }
+            for (token_id, amount) in &balance_change.native_tokens_received {
+                writeln!(f, "    +{amount} {token_id}")?;
+            }
+        }
+
+        if !summary.chain_transitions.is_empty() {
+            writeln!(f, "Chain transitions:")?;
+            for chain_transition in &summary.chain_transitions {
+                let chain_id = if chain_transition.chain_id.is_null() {
+                    "new".to_string()
+                } else {
+                    chain_transition.chain_id.to_string()
+                };
+                writeln!(
+                    f,
+                    "  {chain_id}: {} -> {}",
+                    chain_transition
+                        .from
+                        .as_ref()
+                        .map_or_else(|| "created".to_string(), bech32),
+                    chain_transition
+                        .to
+                        .as_ref()
+                        .map_or_else(|| "destroyed".to_string(), bech32)
+                )?;
+            }
+        }
+
+        writeln!(f, "Outputs:")?;
+        for output in &summary.outputs {
+            write!(
+                f,
+                "  #{} {}: {} base coins",
+                output.index,
+                output.owner.as_ref().map_or_else(|| "-".to_string(), bech32),
+                output.amount
+            )?;
+            if let Some(native_tokens) = &output.native_tokens {
+                for native_token in native_tokens.iter() {
+                    write!(f, ", {} {}", native_token.amount(), native_token.token_id())?;
+                }
+            }
+            for unlock_condition in &output.unlock_conditions {
+                match unlock_condition {
+                    UnlockCondition::StorageDepositReturn(storage_deposit_return) => write!(
+                        f,
+                        ", returns {} to {}",
+                        storage_deposit_return.amount(),
+                        bech32(storage_deposit_return.return_address())
+                    )?,
+                    UnlockCondition::Timelock(timelock) => write!(f, ", timelocked until {}", timelock.timestamp())?,
+                    UnlockCondition::Expiration(expiration) => write!(
+                        f,
+                        ", expires at {} to {}",
+                        expiration.timestamp(),
+                        bech32(expiration.return_address())
+                    )?,
+                    UnlockCondition::GovernorAddress(governor) => {
+                        write!(f, ", governor {}", bech32(governor.address()))?
+                    }
+                    _ => {}
+                }
+            }
+            if output.is_remainder {
+                write!(f, " (remainder)")?;
+            }
+            writeln!(f)?;
+        }
+
+        for (title, storage_deposits) in [
+            ("Storage deposits locked", &summary.storage_deposits_locked),
+            ("Storage deposits returned", &summary.storage_deposits_returned),
+        ] {
+            if !storage_deposits.is_empty() {
+                writeln!(f, "{title}:")?;
+                for (address, amount) in storage_deposits {
+                    writeln!(f, "  {}: {amount}", bech32(address))?;
+                }
+            }
+        }
+
+        for (title, native_tokens) in [
+            ("Native tokens minted", &summary.native_tokens_minted),
+            ("Native tokens melted", &summary.native_tokens_melted),
+            ("Native tokens burned", &summary.native_tokens_burned),
+        ] {
+            if !native_tokens.is_empty() {
+                writeln!(f, "{title}:")?;
+                for (token_id, amount) in native_tokens {
+                    writeln!(f, "  {token_id}: {amount}")?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// The address that owns an output, the state controller for aliases and the alias for foundries.
+fn output_owner(output: &Output) -> Option<Address> {
+    let unlock_conditions = output.unlock_conditions()?;
+
+    match output {
+        Output::Alias(_) => unlock_conditions.state_controller_address().map(|uc| *uc.address()),
+        Output::Foundry(_) => unlock_conditions.immutable_alias_address().map(|uc| *uc.address()),
+        _ => unlock_conditions.address().map(|uc| *uc.address()),
+    }
+}
+
+fn add_native_tokens(balances: &mut BTreeMap<TokenId, U256>, native_tokens: &NativeTokens) {
+    for native_token in native_tokens.iter() {
+        let balance = balances.entry(*native_token.token_id()).or_default();
+        *balance = balance.saturating_add(native_token.amount());
+    }
+}
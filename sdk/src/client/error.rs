@@ -13,7 +13,7 @@ use serde::{
 
 use crate::{
     client::{api::input_selection::Error as InputSelectionError, node_api::indexer::QueryParameter},
-    types::block::semantic::ConflictReason,
+    types::block::{output::OutputId, semantic::ConflictReason},
 };
 
 /// Type alias of `Result` in iota-client
@@ -187,9 +187,27 @@ pub enum Error {
     /// Input selection error.
     #[error("{0}")]
     InputSelection(#[from] InputSelectionError),
+    /// Error of a custom secret manager.
+    #[error("secret manager error: {0}")]
+    SecretManager(Box<dyn std::error::Error + Send + Sync>),
+    /// Remote secret manager error.
+    #[error("remote secret manager error: {0}")]
+    RemoteSecretManager(String),
+    /// A custom secret manager can't be converted to a DTO, so it can't be stored.
+    #[error("a custom secret manager can't be stored, it has to be provided again")]
+    CustomSecretManagerNotStorable,
     /// Missing BIP32 chain to sign with.
     #[error("missing BIP32 chain to sign with")]
     MissingBip32Chain,
+    /// Inputs of a partially signed transaction that are still missing an unlock.
+    #[error("missing unlocks for inputs: {0:?}")]
+    MissingUnlocks(Vec<OutputId>),
+    /// Partially signed transactions of different transaction essences can't be merged.
+    #[error("partially signed transactions of different transaction essences can't be merged")]
+    TransactionEssenceMismatch,
+    /// Invalid transaction bundle.
+    #[error("invalid transaction bundle: {0}")]
+    InvalidTransactionBundle(String),
 
     /// Participation error
     #[cfg(feature = "participation")]
@@ -203,6 +221,12 @@ pub enum Error {
     #[error("{0}")]
     Ledger(#[from] crate::client::secret::ledger_nano::Error),
 
+    /// Mock node error
+    #[cfg(feature = "test-utils")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+    #[error("{0}")]
+    MockNode(#[from] hyper::Error),
+
     /// MQTT error
     #[cfg(feature = "mqtt")]
     #[cfg_attr(docsrs, doc(cfg(feature = "mqtt")))]
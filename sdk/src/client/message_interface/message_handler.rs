@@ -26,7 +26,7 @@ use crate::{
         address::{dto::AddressDto, Address, Ed25519Address},
         input::dto::UtxoInputDto,
         output::{
-            dto::{OutputBuilderAmountDto, OutputDto, RentStructureDto},
+            dto::{OutputBuilderAmountDto, OutputDto},
             AliasId, AliasOutput, BasicOutput, FoundryId, FoundryOutput, NftId, NftOutput, Output,
         },
         payload::{
@@ -361,20 +361,7 @@ impl ClientMessageHandler {
             Message::GetTipsInterval => Ok(Response::TipsInterval(self.client.get_tips_interval())),
             Message::GetProtocolParameters => {
                 let params = self.client.get_protocol_parameters().await?;
-                let protocol_response = ProtocolParametersDto {
-                    protocol_version: params.protocol_version(),
-                    network_name: params.network_name().to_string(),
-                    bech32_hrp: params.bech32_hrp().to_string(),
-                    min_pow_score: params.min_pow_score(),
-                    below_max_depth: params.below_max_depth(),
-                    rent_structure: RentStructureDto {
-                        v_byte_cost: params.rent_structure().byte_cost(),
-                        v_byte_factor_key: params.rent_structure().byte_factor_key(),
-                        v_byte_factor_data: params.rent_structure().byte_factor_data(),
-                    },
-                    token_supply: params.token_supply().to_string(),
-                };
-                Ok(Response::ProtocolParameters(protocol_response))
+                Ok(Response::ProtocolParameters(ProtocolParametersDto::from(&params)))
             }
             Message::GetLocalPow => Ok(Response::Bool(self.client.get_local_pow())),
             Message::GetFallbackToLocalPow => Ok(Response::Bool(self.client.get_fallback_to_local_pow())),
@@ -0,0 +1,258 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! An in-process node serving the core and indexer APIs, to test clients and wallets without a real node.
+//!
+//! The node keeps a simulated ledger: posted blocks are stored and the transactions they contain are semantically
+//! validated and applied to the ledger when a milestone is issued, either on demand or periodically.
+//!
+//! ```no_run
+//! # use iota_sdk::{
+//! #     client::{mock_node::MockNode, Client, Result},
+//! #     types::block::{address::Address, output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder}},
+//! # };
+//! # #[tokio::main]
+//! # async fn main() -> Result<()> {
+//! # let address = Address::try_from_bech32("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy")?;
+//! let node = MockNode::builder()
+//!     .with_output(
+//!         BasicOutputBuilder::new_with_amount(1_000_000)
+//!             .add_unlock_condition(AddressUnlockCondition::new(address))
+//!             .finish_output(MockNode::default_protocol_parameters().token_supply())?,
+//!     )
+//!     .finish()?;
+//! let client = Client::builder().with_node(node.url().as_str())?.finish()?;
+//! # Ok(())}
+//! ```
+
+mod routes;
+mod state;
+
+use std::{
+    convert::Infallible,
+    net::SocketAddr,
+    sync::{Arc, Mutex},
+    thread::JoinHandle,
+    time::Duration,
+};
+
+use hyper::{
+    service::{make_service_fn, service_fn},
+    Server,
+};
+use tokio::sync::oneshot;
+use url::Url;
+
+use self::state::NodeState;
+use crate::{
+    client::Result,
+    types::block::{
+        ledger::LedgerSnapshot,
+        output::{Output, OutputId, RentStructure, OUTPUT_COUNT_MAX},
+        protocol::ProtocolParameters,
+    },
+};
+
+/// A node running in a background thread, serving the core and indexer APIs on a local port. The node is stopped when
+/// it's dropped.
+pub struct MockNode {
+    url: Url,
+    state: Arc<Mutex<NodeState>>,
+    shutdown: Option<oneshot::Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+/// Builder of a [`MockNode`].
+#[must_use]
+pub struct MockNodeBuilder {
+    protocol_parameters: ProtocolParameters,
+    outputs: Vec<Output>,
+    milestone_interval: Option<Duration>,
+}
+
+impl Default for MockNodeBuilder {
+    fn default() -> Self {
+        Self {
+            protocol_parameters: MockNode::default_protocol_parameters(),
+            outputs: Vec::new(),
+            milestone_interval: None,
+        }
+    }
+}
+
+impl MockNodeBuilder {
+    /// Sets the protocol parameters of the node, defaults to [`MockNode::default_protocol_parameters()`].
+    pub fn with_protocol_parameters(mut self, protocol_parameters: ProtocolParameters) -> Self {
+        self.protocol_parameters = protocol_parameters;
+        self
+    }
+
+    /// Adds an output to the genesis milestone.
+    pub fn with_output(mut self, output: Output) -> Self {
+        self.outputs.push(output);
+        self
+    }
+
+    /// Issues milestones periodically, so blocks get confirmed without calling [`MockNode::issue_milestone()`].
+    pub fn with_milestone_interval(mut self, milestone_interval: impl Into<Option<Duration>>) -> Self {
+        self.milestone_interval = milestone_interval.into();
+        self
+    }
+
+    /// Starts the node on a free local port, after issuing the genesis milestone.
+    pub fn finish(self) -> Result<MockNode> {
+        let mut state = NodeState::new(self.protocol_parameters);
+        issue_milestones(&mut state, self.outputs)?;
+        let state = Arc::new(Mutex::new(state));
+
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel::<()>();
+        let (address_sender, address_receiver) = std::sync::mpsc::channel();
+        let state_ = state.clone();
+        let milestone_interval = self.milestone_interval;
+
+        // The node runs on its own runtime, so it keeps responding while the caller's runtime is blocked.
+        let thread = std::thread::spawn(move || {
+            // PANIC: creating a runtime only fails if the system is out of resources.
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("failed to create Tokio runtime");
+
+            runtime.block_on(async move {
+                let server_state = state_.clone();
+                let make_service = make_service_fn(move |_| {
+                    let state = server_state.clone();
+                    async move {
+                        Ok::<_, Infallible>(service_fn(move |request| {
+                            routes::handle_request(state.clone(), request)
+                        }))
+                    }
+                });
+
+                let server = match Server::try_bind(&SocketAddr::from(([127, 0, 0, 1], 0))) {
+                    Ok(builder) => builder.serve(make_service),
+                    Err(error) => {
+                        address_sender.send(Err(error)).ok();
+                        return;
+                    }
+                };
+                address_sender.send(Ok(server.local_addr())).ok();
+
+                if let Some(milestone_interval) = milestone_interval {
+                    tokio::spawn(async move {
+                        let mut interval = tokio::time::interval(milestone_interval);
+                        // The first tick completes immediately
+                        interval.tick().await;
+                        loop {
+                            interval.tick().await;
+                            // PANIC: the lock is never held across a panic and milestones without minted outputs
+                            // can't fail.
+                            state_.lock().unwrap().issue_milestone(Vec::new()).unwrap();
+                        }
+                    });
+                }
+
+                // No graceful shutdown, it would wait for pooled client connections that never sent a request. The
+                // open connections are closed when the runtime is dropped.
+                tokio::select! {
+                    _ = server => {}
+                    _ = shutdown_receiver => {}
+                }
+            });
+        });
+
+        // PANIC: the thread always sends the address or an error before it stops.
+        let address = address_receiver.recv().expect("mock node thread stopped")?;
+
+        Ok(MockNode {
+            // PANIC: a socket address is a valid URL host.
+            url: format!("http://{address}").parse().unwrap(),
+            state,
+            shutdown: Some(shutdown_sender),
+            thread: Some(thread),
+        })
+    }
+}
+
+impl MockNode {
+    /// Creates a builder of a [`MockNode`].
+    pub fn builder() -> MockNodeBuilder {
+        MockNodeBuilder::default()
+    }
+
+    /// The protocol parameters used by default, those of a testnet with the `rms` HRP and without PoW.
+    pub fn default_protocol_parameters() -> ProtocolParameters {
+        // PANIC: these values are known to be correct.
+        ProtocolParameters::new(
+            2,
+            String::from("mock-node"),
+            String::from("rms"),
+            0,
+            15,
+            RentStructure::default(),
+            1_813_620_509_061_365,
+        )
+        .unwrap()
+    }
+
+    /// Returns the URL of the node.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    /// Returns the protocol parameters of the node.
+    pub fn protocol_parameters(&self) -> ProtocolParameters {
+        // PANIC: the lock is never held across a panic.
+        self.state.lock().unwrap().protocol_parameters().clone()
+    }
+
+    /// Issues a milestone that confirms all blocks posted since the previous milestone and returns its index.
+    /// Transactions that fail semantic validation are marked as conflicting.
+    pub fn issue_milestone(&self) -> u32 {
+        // PANIC: the lock is never held across a panic and milestones without minted outputs can't fail.
+        self.state.lock().unwrap().issue_milestone(Vec::new()).unwrap()
+    }
+
+    /// Creates outputs out of thin air, e.g. to fund addresses, and returns their ids. The outputs are created by a new
+    /// milestone, which also confirms all pending blocks.
+    pub fn add_outputs(&self, outputs: Vec<Output>) -> Result<Vec<OutputId>> {
+        // PANIC: the lock is never held across a panic.
+        let mut state = self.state.lock().unwrap();
+        let first_milestone = state.ledger_index() + 1;
+        issue_milestones(&mut state, outputs)?;
+
+        Ok((first_milestone..=state.ledger_index())
+            .filter_map(|index| state.ledger.utxo_changes(index))
+            .flat_map(|utxo_changes| utxo_changes.created_outputs.iter().copied())
+            .collect())
+    }
+
+    /// Takes a [`LedgerSnapshot`] of the node's ledger.
+    pub fn ledger_snapshot(&self) -> LedgerSnapshot {
+        // PANIC: the lock is never held across a panic and milestones are never left pending.
+        self.state.lock().unwrap().ledger.snapshot().unwrap()
+    }
+}
+
+impl Drop for MockNode {
+    fn drop(&mut self) {
+        if let Some(shutdown) = self.shutdown.take() {
+            shutdown.send(()).ok();
+        }
+        if let Some(thread) = self.thread.take() {
+            thread.join().ok();
+        }
+    }
+}
+
+// Issues as many milestones as needed to create the outputs, at least one.
+fn issue_milestones(state: &mut NodeState, mut outputs: Vec<Output>) -> Result<()> {
+    loop {
+        let rest = outputs.split_off(outputs.len().min(OUTPUT_COUNT_MAX as usize));
+        state.issue_milestone(outputs)?;
+        if rest.is_empty() {
+            return Ok(());
+        }
+        outputs = rest;
+    }
+}
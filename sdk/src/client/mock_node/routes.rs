@@ -0,0 +1,486 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Request handling of the mock node, following the routes of hornet.
+
+use std::{
+    convert::Infallible,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+
+use hyper::{
+    header::{ACCEPT, CONTENT_TYPE},
+    Body, Method, Request, Response, StatusCode,
+};
+use packable::PackableExt;
+use serde::Serialize;
+use serde_json::json;
+
+use super::state::{BlockEntry, NodeState};
+use crate::types::{
+    api::{
+        core::response::{
+            BaseTokenResponse, ConfirmedMilestoneResponse, InfoResponse, LatestMilestoneResponse, MetricsResponse,
+            OutputWithMetadataResponse, ReceiptsResponse, RoutesResponse, StatusResponse, SubmitBlockResponse,
+            TipsResponse, TreasuryResponse, UtxoChangesResponse,
+        },
+        plugins::indexer::OutputIdsResponse,
+    },
+    block::{
+        address::Address,
+        ledger::LedgerOutput,
+        output::{
+            dto::{OutputDto, OutputMetadataDto},
+            AliasId, FoundryId, NftId, Output, OutputId, OutputMetadata,
+        },
+        payload::{
+            milestone::{dto::MilestonePayloadDto, MilestoneId},
+            transaction::TransactionId,
+        },
+        protocol::dto::ProtocolParametersDto,
+        Block, BlockDto, BlockId,
+    },
+};
+
+const RAW_CONTENT_TYPE: &str = "application/vnd.iota.serializer-v1";
+
+type State = Arc<Mutex<NodeState>>;
+
+pub(crate) async fn handle_request(state: State, request: Request<Body>) -> Result<Response<Body>, Infallible> {
+    let method = request.method().clone();
+    let path = request.uri().path().trim_matches('/').to_string();
+    let query = request.uri().query().unwrap_or_default().to_string();
+    let raw = request
+        .headers()
+        .get(ACCEPT)
+        .map_or(false, |accept| accept.as_bytes() == RAW_CONTENT_TYPE.as_bytes());
+    let raw_body = request.headers().get(CONTENT_TYPE).map_or(false, |content_type| {
+        content_type.as_bytes() == RAW_CONTENT_TYPE.as_bytes()
+    });
+    let body = match hyper::body::to_bytes(request.into_body()).await {
+        Ok(body) => body,
+        Err(error) => return Ok(error_response(StatusCode::BAD_REQUEST, error.to_string())),
+    };
+    let segments = path.split('/').collect::<Vec<_>>();
+
+    // PANIC: the lock is never held across a panic.
+    let mut state = state.lock().unwrap();
+
+    let response = match (&method, segments.as_slice()) {
+        (&Method::GET, ["health"]) => Ok(Response::new(Body::empty())),
+        (&Method::GET, ["api", "routes"]) => json_response(&RoutesResponse {
+            routes: vec!["core/v2".to_string(), "indexer/v1".to_string()],
+        }),
+        (&Method::GET, ["api", "core", "v2", rest @ ..]) => core_route(&state, rest, raw),
+        (&Method::POST, ["api", "core", "v2", "blocks"]) => post_block(&mut state, &body, raw_body),
+        (&Method::GET, ["api", "indexer", "v1", "outputs", rest @ ..]) => indexer_route(&state, rest, &query),
+        _ => Err((StatusCode::NOT_FOUND, format!("route {method} /{path} not found"))),
+    };
+
+    Ok(response.unwrap_or_else(|(status, message)| error_response(status, message)))
+}
+
+type Error = (StatusCode, String);
+type RouteResult = Result<Response<Body>, Error>;
+
+fn core_route(state: &NodeState, segments: &[&str], raw: bool) -> RouteResult {
+    match segments {
+        ["info"] => json_response(&info(state)),
+        ["tips"] => json_response(&TipsResponse {
+            tips: state.tips().iter().map(BlockId::to_string).collect(),
+        }),
+        ["blocks", block_id] => {
+            let block = &block_entry(state, block_id)?.block;
+            if raw {
+                Ok(raw_response(block.pack_to_vec()))
+            } else {
+                json_response(&BlockDto::from(block))
+            }
+        }
+        ["blocks", block_id, "metadata"] => json_response(&block_entry(state, block_id)?.metadata()),
+        ["outputs", output_id] => {
+            let ledger_output = output(state, output_id)?;
+            if raw {
+                Ok(raw_response(ledger_output.output().pack_to_vec()))
+            } else {
+                json_response(&OutputWithMetadataResponse {
+                    metadata: output_metadata_dto(state, ledger_output.metadata()),
+                    output: OutputDto::from(ledger_output.output()),
+                })
+            }
+        }
+        ["outputs", output_id, "metadata"] => {
+            json_response(&output_metadata_dto(state, output(state, output_id)?.metadata()))
+        }
+        ["transactions", transaction_id, "included-block", rest @ ..] => {
+            let transaction_id = parse::<TransactionId>(transaction_id)?;
+            let block_id = state
+                .included_transactions
+                .get(&transaction_id)
+                .ok_or_else(|| not_found(format!("transaction {transaction_id}")))?;
+            let block_id = block_id.to_string();
+            let mut segments = vec!["blocks", block_id.as_str()];
+            segments.extend_from_slice(rest);
+            core_route(state, &segments, raw)
+        }
+        ["milestones", "by-index", index, rest @ ..] => {
+            let index = parse::<u32>(index)?;
+            milestone_route(state, index, rest, raw)
+        }
+        ["milestones", milestone_id, rest @ ..] => {
+            let milestone_id = parse::<MilestoneId>(milestone_id)?;
+            let index = state
+                .milestones
+                .iter()
+                .position(|milestone| milestone.payload.id() == milestone_id)
+                .ok_or_else(|| not_found(format!("milestone {milestone_id}")))?;
+            milestone_route(state, index as u32 + 1, rest, raw)
+        }
+        ["receipts"] | ["receipts", _] => json_response(&ReceiptsResponse { receipts: Vec::new() }),
+        ["treasury"] => json_response(&TreasuryResponse {
+            milestone_id: state
+                .latest_milestone()
+                .map_or_else(MilestoneId::null, |milestone| milestone.payload.id())
+                .to_string(),
+            amount: "0".to_string(),
+        }),
+        ["peers"] => json_response(&json!([])),
+        _ => Err(not_found(format!("route /api/core/v2/{}", segments.join("/")))),
+    }
+}
+
+fn milestone_route(state: &NodeState, index: u32, segments: &[&str], raw: bool) -> RouteResult {
+    let milestone = state
+        .milestone(index)
+        .ok_or_else(|| not_found(format!("milestone {index}")))?;
+
+    match segments {
+        [] if raw => Ok(raw_response(milestone.payload.pack_to_vec())),
+        [] => json_response(&MilestonePayloadDto::from(&milestone.payload)),
+        ["utxo-changes"] => {
+            // PANIC: the ledger has the changes of every milestone.
+            json_response(&UtxoChangesResponse::from(state.ledger.utxo_changes(index).unwrap()))
+        }
+        _ => Err(not_found(format!("milestone route {}", segments.join("/")))),
+    }
+}
+
+fn post_block(state: &mut NodeState, body: &[u8], raw: bool) -> RouteResult {
+    let block = if raw {
+        Block::unpack_strict(body, state.protocol_parameters()).map_err(|error| bad_request(format!("{error:?}")))?
+    } else {
+        let block_dto = serde_json::from_slice::<BlockDto>(body).map_err(|error| bad_request(error.to_string()))?;
+        Block::try_from_dto(&block_dto, state.protocol_parameters()).map_err(|error| bad_request(error.to_string()))?
+    };
+    let block_id = state.add_block(block).map_err(bad_request)?;
+
+    let mut response = json_response(&SubmitBlockResponse {
+        block_id: block_id.to_string(),
+    })?;
+    *response.status_mut() = StatusCode::CREATED;
+
+    Ok(response)
+}
+
+fn indexer_route(state: &NodeState, segments: &[&str], query: &str) -> RouteResult {
+    let unspent_outputs = state.ledger.unspent_outputs().map(|ledger_output| {
+        (
+            ledger_output.metadata().output_id(),
+            ledger_output.output(),
+            ledger_output.metadata(),
+        )
+    });
+
+    let mut items = match segments {
+        [kind @ ("basic" | "alias" | "foundry" | "nft")] => {
+            let filters = url::form_urlencoded::parse(query.as_bytes()).collect::<Vec<_>>();
+            let mut items = Vec::new();
+
+            for (output_id, output, metadata) in unspent_outputs {
+                let kind_matches = matches!(
+                    (*kind, output),
+                    ("basic", Output::Basic(_))
+                        | ("alias", Output::Alias(_))
+                        | ("foundry", Output::Foundry(_))
+                        | ("nft", Output::Nft(_))
+                );
+                if !kind_matches {
+                    continue;
+                }
+
+                let mut filters_match = true;
+                for (key, value) in &filters {
+                    filters_match &= matches_filter(output, metadata, key, value)?;
+                }
+                if filters_match {
+                    items.push((metadata.milestone_timestamp_booked(), *output_id));
+                }
+            }
+
+            items
+        }
+        ["alias", alias_id] => {
+            let alias_id = parse::<AliasId>(alias_id)?;
+            unspent_outputs
+                .filter(|(output_id, output, _)| {
+                    matches!(output, Output::Alias(alias) if alias.alias_id_non_null(output_id) == alias_id)
+                })
+                .map(|(output_id, _, metadata)| (metadata.milestone_timestamp_booked(), *output_id))
+                .collect()
+        }
+        ["foundry", foundry_id] => {
+            let foundry_id = parse::<FoundryId>(foundry_id)?;
+            unspent_outputs
+                .filter(|(_, output, _)| matches!(output, Output::Foundry(foundry) if foundry.id() == foundry_id))
+                .map(|(output_id, _, metadata)| (metadata.milestone_timestamp_booked(), *output_id))
+                .collect()
+        }
+        ["nft", nft_id] => {
+            let nft_id = parse::<NftId>(nft_id)?;
+            unspent_outputs
+                .filter(|(output_id, output, _)| {
+                    matches!(output, Output::Nft(nft) if nft.nft_id_non_null(output_id) == nft_id)
+                })
+                .map(|(output_id, _, metadata)| (metadata.milestone_timestamp_booked(), *output_id))
+                .collect()
+        }
+        _ => {
+            return Err(not_found(format!(
+                "route /api/indexer/v1/outputs/{}",
+                segments.join("/")
+            )))
+        }
+    };
+
+    if segments.len() == 2 && items.is_empty() {
+        return Err(not_found(format!("output of {}", segments.join(" "))));
+    }
+
+    // Like the indexer, outputs are sorted by the time they were booked
+    items.sort_unstable();
+
+    json_response(&OutputIdsResponse {
+        ledger_index: state.ledger_index(),
+        cursor: None,
+        items: items.into_iter().map(|(_, output_id)| output_id).collect(),
+    })
+}
+
+// Checks an indexer query parameter, pagination is ignored since all outputs are returned at once.
+fn matches_filter(output: &Output, metadata: &OutputMetadata, key: &str, value: &str) -> Result<bool, Error> {
+    let unlock_conditions = output.unlock_conditions();
+    let expiration = unlock_conditions.and_then(|unlock_conditions| unlock_conditions.expiration());
+    let timelock = unlock_conditions.and_then(|unlock_conditions| unlock_conditions.timelock());
+    let storage_deposit_return =
+        unlock_conditions.and_then(|unlock_conditions| unlock_conditions.storage_deposit_return());
+    let native_token_count = output.native_tokens().map_or(0, |native_tokens| native_tokens.len());
+
+    Ok(match key {
+        "address" => {
+            let owner = match output {
+                Output::Foundry(_) => unlock_conditions
+                    .and_then(|unlock_conditions| unlock_conditions.immutable_alias_address())
+                    .map(|unlock_condition| unlock_condition.address()),
+                _ => unlock_conditions
+                    .and_then(|unlock_conditions| unlock_conditions.address())
+                    .map(|unlock_condition| unlock_condition.address()),
+            };
+            owner == Some(&parse_address(value)?)
+        }
+        "aliasAddress" => {
+            unlock_conditions
+                .and_then(|unlock_conditions| unlock_conditions.immutable_alias_address())
+                .map(|unlock_condition| unlock_condition.address())
+                == Some(&parse_address(value)?)
+        }
+        "stateController" => {
+            unlock_conditions
+                .and_then(|unlock_conditions| unlock_conditions.state_controller_address())
+                .map(|unlock_condition| unlock_condition.address())
+                == Some(&parse_address(value)?)
+        }
+        "governor" => {
+            unlock_conditions
+                .and_then(|unlock_conditions| unlock_conditions.governor_address())
+                .map(|unlock_condition| unlock_condition.address())
+                == Some(&parse_address(value)?)
+        }
+        "issuer" => {
+            output
+                .immutable_features()
+                .and_then(|features| features.issuer())
+                .map(|issuer| issuer.address())
+                == Some(&parse_address(value)?)
+        }
+        "sender" => {
+            output
+                .features()
+                .and_then(|features| features.sender())
+                .map(|sender| sender.address())
+                == Some(&parse_address(value)?)
+        }
+        "tag" => {
+            let tag = prefix_hex::decode::<Vec<u8>>(value).map_err(|error| bad_request(format!("{error:?}")))?;
+            output
+                .features()
+                .and_then(|features| features.tag())
+                .map(|tag| tag.tag())
+                == Some(tag.as_slice())
+        }
+        "hasNativeTokens" => (native_token_count > 0) == parse::<bool>(value)?,
+        "minNativeTokenCount" => native_token_count >= parse::<usize>(value)?,
+        "maxNativeTokenCount" => native_token_count <= parse::<usize>(value)?,
+        "hasStorageDepositReturn" => storage_deposit_return.is_some() == parse::<bool>(value)?,
+        "storageDepositReturnAddress" => {
+            storage_deposit_return.map(|unlock_condition| unlock_condition.return_address())
+                == Some(&parse_address(value)?)
+        }
+        "hasExpiration" => expiration.is_some() == parse::<bool>(value)?,
+        "expirationReturnAddress" => {
+            expiration.map(|unlock_condition| unlock_condition.return_address()) == Some(&parse_address(value)?)
+        }
+        "expiresBefore" => {
+            let timestamp = parse::<u32>(value)?;
+            expiration.map_or(false, |unlock_condition| unlock_condition.timestamp() < timestamp)
+        }
+        "expiresAfter" => {
+            let timestamp = parse::<u32>(value)?;
+            expiration.map_or(false, |unlock_condition| unlock_condition.timestamp() > timestamp)
+        }
+        "hasTimelock" => timelock.is_some() == parse::<bool>(value)?,
+        "timelockedBefore" => {
+            let timestamp = parse::<u32>(value)?;
+            timelock.map_or(false, |unlock_condition| unlock_condition.timestamp() < timestamp)
+        }
+        "timelockedAfter" => {
+            let timestamp = parse::<u32>(value)?;
+            timelock.map_or(false, |unlock_condition| unlock_condition.timestamp() > timestamp)
+        }
+        "createdBefore" => metadata.milestone_timestamp_booked() < parse::<u32>(value)?,
+        "createdAfter" => metadata.milestone_timestamp_booked() > parse::<u32>(value)?,
+        "cursor" | "pageSize" => true,
+        _ => return Err(bad_request(format!("unsupported query parameter {key}"))),
+    })
+}
+
+fn info(state: &NodeState) -> InfoResponse {
+    let latest_milestone = state.latest_milestone();
+    let index = state.ledger_index();
+    let timestamp = latest_milestone.map(|milestone| milestone.payload.essence().timestamp());
+    let milestone_id = latest_milestone.map(|milestone| milestone.payload.id().to_string());
+
+    InfoResponse {
+        name: "mock-node".to_string(),
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        status: StatusResponse {
+            is_healthy: true,
+            latest_milestone: LatestMilestoneResponse {
+                index,
+                timestamp,
+                milestone_id: milestone_id.clone(),
+            },
+            confirmed_milestone: ConfirmedMilestoneResponse {
+                index,
+                timestamp,
+                milestone_id,
+            },
+            pruning_index: 0,
+        },
+        supported_protocol_versions: vec![state.protocol_parameters().protocol_version()],
+        protocol: ProtocolParametersDto::from(state.protocol_parameters()),
+        pending_protocol_parameters: Vec::new(),
+        base_token: BaseTokenResponse {
+            name: "Shimmer".to_string(),
+            ticker_symbol: "SMR".to_string(),
+            unit: "SMR".to_string(),
+            subunit: Some("glow".to_string()),
+            decimals: 6,
+            use_metric_prefix: false,
+        },
+        metrics: MetricsResponse {
+            blocks_per_second: 0.0,
+            referenced_blocks_per_second: 0.0,
+            referenced_rate: 0.0,
+        },
+        features: Vec::new(),
+    }
+}
+
+fn block_entry<'a>(state: &'a NodeState, block_id: &str) -> Result<&'a BlockEntry, Error> {
+    let block_id = parse::<BlockId>(block_id)?;
+
+    state
+        .blocks
+        .get(&block_id)
+        .ok_or_else(|| not_found(format!("block {block_id}")))
+}
+
+fn output<'a>(state: &'a NodeState, output_id: &str) -> Result<&'a LedgerOutput, Error> {
+    let output_id = parse::<OutputId>(output_id)?;
+
+    state
+        .ledger
+        .output(&output_id)
+        .ok_or_else(|| not_found(format!("output {output_id}")))
+}
+
+fn output_metadata_dto(state: &NodeState, metadata: &OutputMetadata) -> OutputMetadataDto {
+    let mut metadata = OutputMetadataDto::from(metadata);
+    metadata.ledger_index = state.ledger_index();
+    metadata
+}
+
+fn parse<T: FromStr>(value: &str) -> Result<T, Error>
+where
+    T::Err: std::fmt::Debug,
+{
+    value
+        .parse()
+        .map_err(|error| bad_request(format!("invalid parameter {value}: {error:?}")))
+}
+
+fn parse_address(value: &str) -> Result<Address, Error> {
+    Address::try_from_bech32(value).map_err(|error| bad_request(format!("invalid address {value}: {error}")))
+}
+
+fn json_response(value: &impl Serialize) -> RouteResult {
+    let body = serde_json::to_vec(value).map_err(|error| (StatusCode::INTERNAL_SERVER_ERROR, error.to_string()))?;
+
+    // PANIC: the response is built from valid parts.
+    Ok(Response::builder()
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body))
+        .unwrap())
+}
+
+fn raw_response(bytes: Vec<u8>) -> Response<Body> {
+    // PANIC: the response is built from valid parts.
+    Response::builder()
+        .header(CONTENT_TYPE, RAW_CONTENT_TYPE)
+        .body(Body::from(bytes))
+        .unwrap()
+}
+
+fn error_response(status: StatusCode, message: String) -> Response<Body> {
+    let body = json!({
+        "error": {
+            "code": status.as_u16().to_string(),
+            "message": message,
+        }
+    });
+    // PANIC: the response is built from valid parts.
+    Response::builder()
+        .status(status)
+        .header(CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+fn not_found(what: String) -> Error {
+    (StatusCode::NOT_FOUND, format!("{what} not found"))
+}
+
+fn bad_request(message: String) -> Error {
+    (StatusCode::BAD_REQUEST, message)
+}
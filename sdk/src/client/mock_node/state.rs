@@ -0,0 +1,285 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{hash_map::Entry, HashMap, HashSet};
+
+use crypto::hashes::{blake2b::Blake2b256, Digest};
+
+use crate::{
+    types::{
+        api::core::{dto::LedgerInclusionStateDto, response::BlockMetadataResponse},
+        block::{
+            ledger::Ledger,
+            output::{Output, OutputId},
+            parent::Parents,
+            payload::{
+                milestone::{
+                    MerkleRoot, MilestoneEssence, MilestoneId, MilestoneIndex, MilestoneOptions, MilestonePayload,
+                },
+                transaction::{TransactionEssence, TransactionId},
+                Payload,
+            },
+            protocol::ProtocolParameters,
+            semantic::ConflictReason,
+            signature::{Ed25519Signature, Signature},
+            Block, BlockBuilder, BlockId, Error,
+        },
+    },
+    utils::unix_timestamp_now,
+};
+
+// Maximum number of tips that are returned.
+const TIPS_MAX: usize = 8;
+
+pub(crate) struct BlockEntry {
+    pub(crate) block: Block,
+    pub(crate) referenced_by_milestone_index: Option<u32>,
+    pub(crate) inclusion_state: Option<LedgerInclusionStateDto>,
+    pub(crate) conflict_reason: Option<ConflictReason>,
+}
+
+impl BlockEntry {
+    pub(crate) fn metadata(&self) -> BlockMetadataResponse {
+        BlockMetadataResponse {
+            block_id: self.block.id().to_string(),
+            parents: self.block.parents().iter().map(BlockId::to_string).collect(),
+            is_solid: true,
+            referenced_by_milestone_index: self.referenced_by_milestone_index,
+            milestone_index: match self.block.payload() {
+                Some(Payload::Milestone(milestone)) => Some(*milestone.essence().index()),
+                _ => None,
+            },
+            ledger_inclusion_state: self.inclusion_state.clone(),
+            conflict_reason: self.conflict_reason.map(|conflict_reason| conflict_reason as u8),
+            white_flag_index: None,
+            should_promote: self.referenced_by_milestone_index.map_or(Some(false), |_| None),
+            should_reattach: self.referenced_by_milestone_index.map_or(Some(false), |_| None),
+        }
+    }
+}
+
+pub(crate) struct MilestoneEntry {
+    pub(crate) payload: MilestonePayload,
+    pub(crate) block_id: BlockId,
+}
+
+/// The tangle and ledger of a mock node.
+pub(crate) struct NodeState {
+    pub(crate) ledger: Ledger,
+    pub(crate) blocks: HashMap<BlockId, BlockEntry>,
+    pub(crate) milestones: Vec<MilestoneEntry>,
+    pub(crate) included_transactions: HashMap<TransactionId, BlockId>,
+    // Blocks that aren't referenced by a milestone yet, in the order they were posted
+    pending_blocks: Vec<BlockId>,
+}
+
+impl NodeState {
+    pub(crate) fn new(protocol_parameters: ProtocolParameters) -> Self {
+        Self {
+            ledger: Ledger::new(protocol_parameters),
+            blocks: HashMap::new(),
+            milestones: Vec::new(),
+            included_transactions: HashMap::new(),
+            pending_blocks: Vec::new(),
+        }
+    }
+
+    pub(crate) fn protocol_parameters(&self) -> &ProtocolParameters {
+        self.ledger.protocol_parameters()
+    }
+
+    pub(crate) fn latest_milestone(&self) -> Option<&MilestoneEntry> {
+        self.milestones.last()
+    }
+
+    pub(crate) fn milestone(&self, index: u32) -> Option<&MilestoneEntry> {
+        index
+            .checked_sub(1)
+            .and_then(|position| self.milestones.get(position as usize))
+    }
+
+    pub(crate) fn ledger_index(&self) -> u32 {
+        self.ledger.ledger_index()
+    }
+
+    pub(crate) fn tips(&self) -> Vec<BlockId> {
+        let mut tips = self
+            .pending_blocks
+            .iter()
+            .rev()
+            .take(TIPS_MAX)
+            .copied()
+            .collect::<Vec<_>>();
+
+        if tips.is_empty() {
+            tips.extend(self.latest_milestone().map(|milestone| milestone.block_id));
+        }
+
+        tips
+    }
+
+    /// Stores a block, it's applied to the ledger by the next milestone.
+    pub(crate) fn add_block(&mut self, block: Block) -> Result<BlockId, String> {
+        let protocol_parameters = self.protocol_parameters();
+
+        if block.protocol_version() != protocol_parameters.protocol_version() {
+            return Err(format!("invalid protocol version {}", block.protocol_version()));
+        }
+
+        match block.payload() {
+            Some(Payload::Transaction(transaction)) => {
+                let TransactionEssence::Regular(essence) = transaction.essence();
+                if essence.network_id() != protocol_parameters.network_id() {
+                    return Err(format!("invalid network id {}", essence.network_id()));
+                }
+            }
+            Some(Payload::Milestone(_)) => return Err("milestones can't be posted".to_string()),
+            _ => {}
+        }
+
+        let block_id = block.id();
+
+        if let Entry::Vacant(entry) = self.blocks.entry(block_id) {
+            entry.insert(BlockEntry {
+                block,
+                referenced_by_milestone_index: None,
+                inclusion_state: None,
+                conflict_reason: None,
+            });
+            self.pending_blocks.push(block_id);
+        }
+
+        Ok(block_id)
+    }
+
+    /// Issues a milestone that references all pending blocks and applies their transactions to the ledger. The given
+    /// outputs are created out of thin air by the milestone.
+    pub(crate) fn issue_milestone(&mut self, minted_outputs: Vec<Output>) -> Result<u32, Error> {
+        let index = self.ledger_index() + 1;
+        let minted_transaction_id = {
+            let mut hasher = Blake2b256::new();
+            hasher.update(b"mock node");
+            hasher.update(index.to_le_bytes());
+            TransactionId::new(hasher.finalize().into())
+        };
+
+        // Minted outputs are checked first, so that the milestone can't fail halfway.
+        let mut minted_chain_ids = HashSet::new();
+        for (output_index, output) in minted_outputs.iter().enumerate() {
+            let output_id = OutputId::new(minted_transaction_id, output_index as u16)?;
+
+            output.verify_storage_deposit(
+                *self.protocol_parameters().rent_structure(),
+                self.protocol_parameters().token_supply(),
+            )?;
+            if let Some(chain_id) = output.chain_id() {
+                let chain_id = chain_id.or_from_output_id(&output_id);
+
+                if self.ledger.chain_output(&chain_id).is_some() || !minted_chain_ids.insert(chain_id) {
+                    return Err(Error::DuplicateOutputChain(chain_id));
+                }
+            }
+        }
+
+        let previous_milestone = self.latest_milestone();
+        // Milestone timestamps need to be strictly increasing
+        let timestamp = previous_milestone.map_or(0, |milestone| milestone.payload.essence().timestamp() + 1);
+        let timestamp = timestamp.max(unix_timestamp_now().as_secs() as u32);
+
+        // Only the latest pending blocks are approved directly, older ones are referenced through them in a real tangle
+        let mut parents = self
+            .pending_blocks
+            .iter()
+            .rev()
+            .take(*Parents::COUNT_RANGE.end() as usize - 1)
+            .copied()
+            .chain(previous_milestone.map(|milestone| milestone.block_id))
+            .collect::<Vec<_>>();
+        if parents.is_empty() {
+            parents.push(BlockId::null());
+        }
+        // PANIC: the number of parents is in range.
+        let parents = Parents::from_vec(parents).unwrap();
+
+        // PANIC: the essence is built from valid parts.
+        let essence = MilestoneEssence::new(
+            MilestoneIndex(index),
+            timestamp,
+            self.protocol_parameters().protocol_version(),
+            previous_milestone.map_or_else(MilestoneId::null, |milestone| milestone.payload.id()),
+            parents.clone(),
+            MerkleRoot::null(),
+            MerkleRoot::null(),
+            Vec::new(),
+            MilestoneOptions::from_vec(Vec::new()).unwrap(),
+        )
+        .unwrap();
+        // Milestone signatures aren't verified by clients, a placeholder is enough.
+        let signature = Signature::Ed25519(Ed25519Signature::new([0; 32], [0; 64]));
+        // PANIC: there is exactly one signature.
+        let payload = MilestonePayload::new(essence, vec![signature]).unwrap();
+        // PANIC: a block with a milestone payload is valid.
+        let milestone_block = BlockBuilder::new(parents)
+            .with_protocol_version(self.protocol_parameters().protocol_version())
+            .with_payload(Payload::from(payload.clone()))
+            .finish()
+            .unwrap();
+        let milestone_block_id = milestone_block.id();
+
+        // PANIC: no milestone is pending and the timestamp is greater than the previous one.
+        self.ledger.begin_milestone(timestamp).unwrap();
+
+        for (output_index, output) in minted_outputs.into_iter().enumerate() {
+            // PANIC: the minted outputs have been checked.
+            let output_id = OutputId::new(minted_transaction_id, output_index as u16).unwrap();
+            self.ledger
+                .create_output(output_id, output, milestone_block_id)
+                .unwrap();
+        }
+
+        for block_id in std::mem::take(&mut self.pending_blocks) {
+            let (inclusion_state, conflict_reason) = match self.blocks[&block_id].block.payload() {
+                Some(Payload::Transaction(transaction)) => {
+                    // The network id has been checked when the block was posted.
+                    match self
+                        .ledger
+                        .apply_transaction(transaction, block_id)
+                        .unwrap_or(ConflictReason::SemanticValidationFailed)
+                    {
+                        ConflictReason::None => {
+                            self.included_transactions.insert(transaction.id(), block_id);
+                            (LedgerInclusionStateDto::Included, None)
+                        }
+                        conflict_reason => (LedgerInclusionStateDto::Conflicting, Some(conflict_reason)),
+                    }
+                }
+                _ => (LedgerInclusionStateDto::NoTransaction, None),
+            };
+
+            // PANIC: pending blocks are stored.
+            let entry = self.blocks.get_mut(&block_id).unwrap();
+            entry.referenced_by_milestone_index = Some(index);
+            entry.inclusion_state = Some(inclusion_state);
+            entry.conflict_reason = conflict_reason;
+        }
+
+        // PANIC: a milestone has been begun.
+        self.ledger.confirm_milestone().unwrap();
+
+        self.blocks.insert(
+            milestone_block_id,
+            BlockEntry {
+                block: milestone_block,
+                referenced_by_milestone_index: Some(index),
+                inclusion_state: Some(LedgerInclusionStateDto::NoTransaction),
+                conflict_reason: None,
+            },
+        );
+        self.milestones.push(MilestoneEntry {
+            payload,
+            block_id: milestone_block_id,
+        });
+
+        Ok(index)
+    }
+}
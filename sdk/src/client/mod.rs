@@ -43,6 +43,9 @@ pub mod error;
 #[cfg(feature = "message_interface")]
 #[cfg_attr(docsrs, doc(cfg(feature = "message_interface")))]
 pub mod message_interface;
+#[cfg(feature = "test-utils")]
+#[cfg_attr(docsrs, doc(cfg(feature = "test-utils")))]
+pub mod mock_node;
 pub mod node_api;
 pub mod node_manager;
 pub mod secret;
@@ -10,6 +10,8 @@ pub mod ledger_nano;
 pub mod mnemonic;
 /// Module for the PlaceholderSecretManager
 pub mod placeholder;
+/// Module for signing with a remote signing service
+pub mod remote;
 /// Module for signing with a Stronghold vault
 #[cfg(feature = "stronghold")]
 #[cfg_attr(docsrs, doc(cfg(feature = "stronghold")))]
@@ -30,25 +32,26 @@ use self::ledger_nano::LedgerSecretManager;
 #[cfg(feature = "stronghold")]
 use self::stronghold::StrongholdSecretManager;
 pub use self::types::{GenerateAddressOptions, LedgerNanoStatus};
-use self::{mnemonic::MnemonicSecretManager, placeholder::PlaceholderSecretManager};
+use self::{
+    mnemonic::MnemonicSecretManager, placeholder::PlaceholderSecretManager, remote::RemoteSecretManager,
+    types::RemoteSecretManagerDto,
+};
 #[cfg(feature = "stronghold")]
 use crate::client::secret::types::StrongholdDto;
 use crate::{
     client::{
         api::{
-            input_selection::{is_alias_transition, Error as InputSelectionError},
-            transaction::validate_transaction_payload_length,
+            transaction::{place_unlocks, validate_transaction_payload_length},
             verify_semantic, PreparedTransactionData,
         },
         Error,
     },
     types::block::{
         address::Address,
-        output::Output,
-        payload::{transaction::TransactionEssence, Payload, TransactionPayload},
+        payload::{Payload, TransactionPayload},
         semantic::ConflictReason,
         signature::{Ed25519Signature, Signature},
-        unlock::{AliasUnlock, NftUnlock, ReferenceUnlock, SignatureUnlock, Unlock, Unlocks},
+        unlock::{SignatureUnlock, Unlock, Unlocks},
     },
     utils::unix_timestamp_now,
 };
@@ -120,6 +123,14 @@ pub enum SecretManager {
     /// Secret manager that's just a placeholder, so it can be provided to an online wallet, but can't be used for
     /// signing.
     Placeholder(PlaceholderSecretManager),
+
+    /// Secret manager that forwards address generation and signing to a remote signing service.
+    Remote(RemoteSecretManager),
+
+    /// Secret manager with a custom implementation of [`SecretManage`], e.g. for a hardware security module or a key
+    /// management service. Transaction essences are signed with [`SecretManage::signature_unlock()`] for each
+    /// required signature.
+    Custom(Box<dyn SecretManage<Error = Error>>),
 }
 
 impl std::fmt::Debug for SecretManager {
@@ -131,6 +142,8 @@ impl std::fmt::Debug for SecretManager {
             Self::LedgerNano(_) => f.debug_tuple("LedgerNano").field(&"...").finish(),
             Self::Mnemonic(_) => f.debug_tuple("Mnemonic").field(&"...").finish(),
             Self::Placeholder(_) => f.debug_struct("Placeholder").finish(),
+            Self::Remote(secret_manager) => f.debug_tuple("Remote").field(secret_manager).finish(),
+            Self::Custom(_) => f.debug_tuple("Custom").field(&"...").finish(),
         }
     }
 }
@@ -165,6 +178,9 @@ pub enum SecretManagerDto {
     /// Placeholder
     #[serde(alias = "placeholder")]
     Placeholder,
+    /// Remote signing service
+    #[serde(alias = "remote")]
+    Remote(RemoteSecretManagerDto),
 }
 
 impl TryFrom<&SecretManagerDto> for SecretManager {
@@ -195,13 +211,25 @@ impl TryFrom<&SecretManagerDto> for SecretManager {
             SecretManagerDto::HexSeed(hex_seed) => Self::Mnemonic(MnemonicSecretManager::try_from_hex_seed(hex_seed)?),
 
             SecretManagerDto::Placeholder => Self::Placeholder(PlaceholderSecretManager),
+
+            SecretManagerDto::Remote(remote_dto) => {
+                let mut secret_manager = RemoteSecretManager::new(remote_dto.url.parse()?);
+
+                if let Some(bearer_token) = &remote_dto.bearer_token {
+                    secret_manager = secret_manager.with_bearer_token(bearer_token);
+                }
+
+                Self::Remote(secret_manager)
+            }
         })
     }
 }
 
-impl From<&SecretManager> for SecretManagerDto {
-    fn from(value: &SecretManager) -> Self {
-        match value {
+impl TryFrom<&SecretManager> for SecretManagerDto {
+    type Error = Error;
+
+    fn try_from(value: &SecretManager) -> crate::client::Result<Self> {
+        Ok(match value {
             #[cfg(feature = "stronghold")]
             SecretManager::Stronghold(stronghold_adapter) => Self::Stronghold(StrongholdDto {
                 password: None,
@@ -222,7 +250,13 @@ impl From<&SecretManager> for SecretManagerDto {
             // to know the type
             SecretManager::Mnemonic(_mnemonic) => Self::Mnemonic("...".to_string()),
             SecretManager::Placeholder(_) => Self::Placeholder,
-        }
+            SecretManager::Remote(remote) => Self::Remote(RemoteSecretManagerDto {
+                url: remote.url().to_string(),
+                bearer_token: None,
+            }),
+            // A custom secret manager can't be created from a DTO, so it has to be provided again after a restore
+            SecretManager::Custom(_) => return Err(Error::CustomSecretManagerNotStorable),
+        })
     }
 }
 
@@ -256,6 +290,16 @@ impl SecretManage for SecretManager {
                     .generate_addresses(coin_type, account_index, address_indexes, options)
                     .await
             }
+            Self::Remote(secret_manager) => {
+                secret_manager
+                    .generate_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
+            Self::Custom(secret_manager) => {
+                secret_manager
+                    .generate_addresses(coin_type, account_index, address_indexes, options)
+                    .await
+            }
         }
     }
 
@@ -267,6 +311,17 @@ impl SecretManage for SecretManager {
             Self::LedgerNano(secret_manager) => Ok(secret_manager.sign_ed25519(msg, chain).await?),
             Self::Mnemonic(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
             Self::Placeholder(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
+            Self::Remote(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
+            Self::Custom(secret_manager) => secret_manager.sign_ed25519(msg, chain).await,
+        }
+    }
+
+    async fn signature_unlock(&self, essence_hash: &[u8; 32], chain: &Chain) -> crate::client::Result<Unlock> {
+        match self {
+            Self::Custom(secret_manager) => secret_manager.signature_unlock(essence_hash, chain).await,
+            _ => Ok(Unlock::Signature(SignatureUnlock::new(Signature::Ed25519(
+                self.sign_ed25519(essence_hash, chain).await?,
+            )))),
         }
     }
 }
@@ -293,6 +348,10 @@ impl SecretManageExt for SecretManager {
                     .await
             }
             Self::Placeholder(_) => self.sign_transaction_essence(prepared_transaction_data, time).await,
+            Self::Remote(_) | Self::Custom(_) => {
+                self.default_sign_transaction_essence(prepared_transaction_data, time)
+                    .await
+            }
         }
     }
 }
@@ -308,7 +367,8 @@ impl SecretManager {
         Ok(Self::Mnemonic(MnemonicSecretManager::try_from_hex_seed(seed)?))
     }
 
-    // Shared implementation for MnemonicSecretManager and StrongholdSecretManager
+    // Shared implementation for MnemonicSecretManager, StrongholdSecretManager, RemoteSecretManager and custom
+    // secret managers
     async fn default_sign_transaction_essence<'a>(
         &self,
         prepared_transaction_data: &PreparedTransactionData,
@@ -316,65 +376,40 @@ impl SecretManager {
     ) -> crate::client::Result<Unlocks> {
         // The hashed_essence gets signed
         let hashed_essence = prepared_transaction_data.essence.hash();
-        let mut blocks = Vec::new();
-        let mut block_indexes = HashMap::<Address, usize>::new();
-
-        // Assuming inputs_data is ordered by address type
-        for (current_block_index, input) in prepared_transaction_data.inputs_data.iter().enumerate() {
-            // Get the address that is required to unlock the input
-            let TransactionEssence::Regular(regular) = &prepared_transaction_data.essence;
-            let alias_transition = is_alias_transition(input, regular.outputs()).map(|t| t.0);
-            let (input_address, _) = input.output.required_and_unlocked_address(
-                time.unwrap_or_else(|| unix_timestamp_now().as_secs() as u32),
-                input.output_metadata.output_id(),
-                alias_transition,
-            )?;
-
-            // Check if we already added an [Unlock] for this address
-            match block_indexes.get(&input_address) {
-                // If we already have an [Unlock] for this address, add a [Unlock] based on the address type
-                Some(block_index) => match input_address {
-                    Address::Alias(_alias) => blocks.push(Unlock::Alias(AliasUnlock::new(*block_index as u16)?)),
-                    Address::Ed25519(_ed25519) => {
-                        blocks.push(Unlock::Reference(ReferenceUnlock::new(*block_index as u16)?));
-                    }
-                    Address::Nft(_nft) => blocks.push(Unlock::Nft(NftUnlock::new(*block_index as u16)?)),
-                },
-                None => {
-                    // We can only sign ed25519 addresses and block_indexes needs to contain the alias or nft
-                    // address already at this point, because the reference index needs to be lower
-                    // than the current block index
-                    if !input_address.is_ed25519() {
-                        return Err(InputSelectionError::MissingInputWithEd25519Address)?;
-                    }
-
-                    let chain = input.chain.as_ref().ok_or(Error::MissingBip32Chain)?;
-
-                    let block = self.signature_unlock(&hashed_essence, chain).await?;
-                    blocks.push(block);
-
-                    // Add the ed25519 address to the block_indexes, so it gets referenced if further inputs have
-                    // the same address in their unlock condition
-                    block_indexes.insert(input_address, current_block_index);
-                }
-            }
 
-            // When we have an alias or Nft output, we will add their alias or nft address to block_indexes,
-            // because they can be used to unlock outputs via [Unlock::Alias] or [Unlock::Nft],
-            // that have the corresponding alias or nft address in their unlock condition
-            match &input.output {
-                Output::Alias(alias_output) => block_indexes.insert(
-                    Address::Alias(alias_output.alias_address(input.output_id())),
-                    current_block_index,
-                ),
-                Output::Nft(nft_output) => block_indexes.insert(
-                    Address::Nft(nft_output.nft_address(input.output_id())),
-                    current_block_index,
-                ),
-                _ => None,
-            };
+        // Signing is async while the unlocks are placed synchronously, so the signature unlocks are created first,
+        // once for every chain of the inputs
+        let mut signature_unlocks = HashMap::<Chain, Unlock>::new();
+        for chain in prepared_transaction_data
+            .inputs_data
+            .iter()
+            .filter_map(|input| input.chain.as_ref())
+        {
+            if !signature_unlocks.contains_key(chain) {
+                let unlock = self.signature_unlock(&hashed_essence, chain).await?;
+                signature_unlocks.insert(chain.clone(), unlock);
+            }
         }
 
+        let blocks = place_unlocks(
+            prepared_transaction_data,
+            time.unwrap_or_else(|| unix_timestamp_now().as_secs() as u32),
+            |input, address| {
+                let chain = input.chain.as_ref().ok_or(Error::MissingBip32Chain)?;
+                let unlock = signature_unlocks.get(chain).cloned();
+                // A remote signing service could sign with another key than the one of the input address, which is
+                // noticed here before the transaction is sent
+                if let (Self::Remote(_), Some(Unlock::Signature(signature_unlock))) = (self, &unlock) {
+                    let Signature::Ed25519(signature) = signature_unlock.signature();
+                    signature
+                        .is_valid(&hashed_essence, address)
+                        .map_err(|error| Error::RemoteSecretManager(format!("invalid signature: {error}")))?;
+                }
+                Ok(unlock)
+            },
+            &mut Vec::new(),
+        )?;
+
         Ok(Unlocks::new(blocks)?)
     }
 
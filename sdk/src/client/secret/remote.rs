@@ -0,0 +1,220 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Implementation of [`RemoteSecretManager`].
+//!
+//! The remote secret manager forwards address generation and signing to a separate signing service, so the keys never
+//! have to be loaded into the process that uses the SDK. The service is reached with [JSON-RPC 2.0](https://www.jsonrpc.org/specification)
+//! requests, sent as `POST` requests with a JSON body to the configured URL. If a bearer token is configured, it's
+//! sent in the `Authorization` header.
+//!
+//! The service has to provide the following methods:
+//!
+//! - `generateAddresses` with the params `{"coinType": 4219, "accountIndex": 0, "addressIndexes": {"start": 0, "end":
+//!   2}, "options": {"internal": false, "ledgerNanoPrompt": false}}`, where `options` can be `null`. The result is an
+//!   array of addresses, e.g. `[{"type": 0, "pubKeyHash": "0x..."}]`, one for each index of the range.
+//! - `signEd25519` with the params `{"message": "0x...", "chain": [2147483692, 2147487867, 2147483648, 2147483648,
+//!   2147483648]}`, where `message` is the hex encoded message and `chain` contains the BIP32 path segments, with the
+//!   `0x80000000` bit set for hardened segments. The result is the signature with its public key, e.g. `{"type": 0,
+//!   "publicKey": "0x...", "signature": "0x..."}`.
+//!
+//! Failures are reported with a JSON-RPC error object. Signatures are only accepted if they're valid for the message,
+//! and when signing a transaction, if their public key belongs to the address of the input.
+
+use std::{
+    ops::Range,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use async_trait::async_trait;
+use crypto::{
+    hashes::{blake2b::Blake2b256, Digest},
+    keys::slip10::Chain,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{json, Value};
+use url::Url;
+
+use super::{GenerateAddressOptions, SecretManage};
+use crate::{
+    client::Error,
+    types::block::{
+        address::{dto::AddressDto, Address, Ed25519Address},
+        signature::{dto::Ed25519SignatureDto, Ed25519Signature},
+    },
+};
+
+/// Secret manager that forwards address generation and signing to a remote signing service.
+pub struct RemoteSecretManager {
+    url: Url,
+    bearer_token: Option<String>,
+    client: reqwest::Client,
+    request_id: AtomicU64,
+}
+
+impl std::fmt::Debug for RemoteSecretManager {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RemoteSecretManager").field("url", &self.url).finish()
+    }
+}
+
+impl RemoteSecretManager {
+    /// Creates a new [`RemoteSecretManager`] for the signing service at `url`.
+    pub fn new(url: Url) -> Self {
+        Self {
+            url,
+            bearer_token: None,
+            client: reqwest::Client::new(),
+            request_id: AtomicU64::new(0),
+        }
+    }
+
+    /// Sets the token that is sent as bearer token with every request.
+    ///
+    /// The token isn't stored with the wallet data, so a wallet with a bearer token needs to be built with the secret
+    /// manager again when it's restored from the storage.
+    pub fn with_bearer_token(mut self, bearer_token: impl Into<String>) -> Self {
+        self.bearer_token.replace(bearer_token.into());
+        self
+    }
+
+    /// Returns the URL of the signing service.
+    pub fn url(&self) -> &Url {
+        &self.url
+    }
+
+    #[cfg(feature = "storage")]
+    pub(crate) fn has_bearer_token(&self) -> bool {
+        self.bearer_token.is_some()
+    }
+
+    async fn request<T: DeserializeOwned>(&self, method: &str, params: Value) -> Result<T, Error> {
+        let id = self.request_id.fetch_add(1, Ordering::Relaxed);
+        let mut request = self.client.post(self.url.clone()).json(&json!({
+            "jsonrpc": "2.0",
+            "id": id,
+            "method": method,
+            "params": params,
+        }));
+        if let Some(bearer_token) = &self.bearer_token {
+            request = request.bearer_auth(bearer_token);
+        }
+
+        let response = request.send().await?.error_for_status()?.json::<RpcResponse>().await?;
+
+        if response.id != Some(id) {
+            return Err(Error::RemoteSecretManager(format!(
+                "unexpected response id {:?}, expected {id}",
+                response.id
+            )));
+        }
+        if let Some(error) = response.error {
+            return Err(Error::RemoteSecretManager(format!(
+                "{} ({})",
+                error.message, error.code
+            )));
+        }
+
+        Ok(serde_json::from_value(response.result.ok_or_else(|| {
+            Error::RemoteSecretManager("missing result".to_string())
+        })?)?)
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcResponse {
+    id: Option<u64>,
+    result: Option<Value>,
+    error: Option<RpcError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+/// The params of the `generateAddresses` method.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GenerateAddressesParams {
+    /// The coin type
+    pub coin_type: u32,
+    /// The account index
+    pub account_index: u32,
+    /// The address indexes
+    pub address_indexes: Range<u32>,
+    /// The address generation options
+    pub options: Option<GenerateAddressOptions>,
+}
+
+/// The params of the `signEd25519` method.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SignEd25519Params {
+    /// The hex encoded message
+    pub message: String,
+    /// The BIP32 path segments, with the `0x80000000` bit set for hardened segments
+    pub chain: Vec<u32>,
+}
+
+#[async_trait]
+impl SecretManage for RemoteSecretManager {
+    type Error = Error;
+
+    async fn generate_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: Option<GenerateAddressOptions>,
+    ) -> Result<Vec<Address>, Self::Error> {
+        let expected_len = address_indexes.len();
+        let addresses: Vec<AddressDto> = self
+            .request(
+                "generateAddresses",
+                serde_json::to_value(GenerateAddressesParams {
+                    coin_type,
+                    account_index,
+                    address_indexes,
+                    options,
+                })?,
+            )
+            .await?;
+
+        if addresses.len() != expected_len {
+            return Err(Error::RemoteSecretManager(format!(
+                "received {} addresses, expected {expected_len}",
+                addresses.len()
+            )));
+        }
+
+        Ok(addresses.iter().map(Address::try_from).collect::<Result<Vec<_>, _>>()?)
+    }
+
+    async fn sign_ed25519(&self, msg: &[u8], chain: &Chain) -> Result<Ed25519Signature, Self::Error> {
+        let signature: Ed25519SignatureDto = self
+            .request(
+                "signEd25519",
+                serde_json::to_value(SignEd25519Params {
+                    message: prefix_hex::encode(msg),
+                    chain: chain
+                        .segments()
+                        .iter()
+                        .map(|segment| u32::from_be_bytes(segment.bs()))
+                        .collect(),
+                })?,
+            )
+            .await?;
+        let signature = Ed25519Signature::try_from(&signature)?;
+
+        // The signature has to be valid for the message, whether its key belongs to the address of the input is
+        // checked when the transaction is signed, without asking the service for the address again
+        let signature_address = Ed25519Address::new(Blake2b256::digest(signature.public_key()).into());
+        signature
+            .is_valid(msg, &signature_address)
+            .map_err(|error| Error::RemoteSecretManager(format!("invalid signature: {error}")))?;
+
+        Ok(signature)
+    }
+}
@@ -5,7 +5,6 @@
 
 use crypto::keys::slip10::Chain;
 use serde::{Deserialize, Serialize};
-#[cfg(feature = "stronghold")]
 use zeroize::ZeroizeOnDrop;
 
 use crate::{
@@ -32,6 +31,17 @@ pub struct StrongholdDto {
     /// The path for the Stronghold file
     pub snapshot_path: String,
 }
+
+/// Remote secret manager DTO to allow the creation of a remote secret manager from bindings
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize, ZeroizeOnDrop)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteSecretManagerDto {
+    /// The URL of the signing service
+    pub url: String,
+    /// The token that is sent as bearer token with every request
+    pub bearer_token: Option<String>,
+}
+
 /// An account address.
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
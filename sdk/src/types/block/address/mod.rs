@@ -152,7 +152,7 @@ impl Address {
 
                 let Signature::Ed25519(signature) = unlock.signature();
 
-                if signature.is_valid(&context.essence_hash, ed25519_address).is_err() {
+                if context.verify_signatures && signature.is_valid(&context.essence_hash, ed25519_address).is_err() {
                     return Err(ConflictReason::InvalidSignature);
                 }
 
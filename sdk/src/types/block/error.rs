@@ -12,7 +12,7 @@ use crate::types::block::{
     input::UtxoInput,
     output::{
         feature::FeatureCount, unlock_condition::UnlockConditionCount, AliasId, ChainId, MetadataFeatureLength,
-        NativeTokenCount, NftId, OutputIndex, StateMetadataLength, TagFeatureLength,
+        NativeTokenCount, NftId, OutputId, OutputIndex, StateMetadataLength, TagFeatureLength,
     },
     parent::ParentCount,
     payload::{
@@ -52,6 +52,8 @@ pub enum Error {
     InvalidFoundryOutputSupply { minted: U256, melted: U256, max: U256 },
     Hex(HexError),
     InvalidInputKind(u8),
+    InvalidIrc27Metadata(String),
+    InvalidIrc30Metadata(String),
     InvalidInputCount(<InputCount as TryFrom<usize>>::Error),
     InvalidInputOutputIndex(<OutputIndex as TryFrom<u16>>::Error),
     InvalidBech32Hrp(FromUtf8Error),
@@ -124,6 +126,10 @@ pub enum Error {
     UnsupportedOutputKind(u8),
     DuplicateOutputChain(ChainId),
     InvalidField(&'static str),
+    LedgerMilestonePending,
+    LedgerNoPendingMilestone,
+    LedgerOutputAlreadyExists(OutputId),
+    LedgerMilestoneTimestampNotIncreasing { previous: u32, next: u32 },
 }
 
 #[cfg(feature = "std")]
@@ -193,6 +199,8 @@ impl fmt::Display for Error {
             ),
             Self::Hex(error) => write!(f, "hex error: {error}"),
             Self::InvalidInputKind(k) => write!(f, "invalid input kind: {k}"),
+            Self::InvalidIrc27Metadata(reason) => write!(f, "invalid IRC27 metadata: {reason}"),
+            Self::InvalidIrc30Metadata(reason) => write!(f, "invalid IRC30 metadata: {reason}"),
             Self::InvalidInputCount(count) => write!(f, "invalid input count: {count}"),
             Self::InvalidInputOutputIndex(index) => write!(f, "invalid input or output index: {index}"),
             Self::InvalidBlockLength(length) => write!(f, "invalid block length {length}"),
@@ -337,6 +345,15 @@ impl fmt::Display for Error {
             Self::UnsupportedOutputKind(k) => write!(f, "unsupported output kind: {k}"),
             Self::DuplicateOutputChain(chain_id) => write!(f, "duplicate output chain {chain_id}"),
             Self::InvalidField(field) => write!(f, "invalid field: {field}"),
+            Self::LedgerMilestonePending => write!(f, "a ledger milestone is already pending"),
+            Self::LedgerNoPendingMilestone => write!(f, "no ledger milestone is pending"),
+            Self::LedgerOutputAlreadyExists(output_id) => write!(f, "ledger output {output_id} already exists"),
+            Self::LedgerMilestoneTimestampNotIncreasing { previous, next } => {
+                write!(
+                    f,
+                    "milestone timestamp {next} is not greater than the previous one {previous}"
+                )
+            }
         }
     }
 }
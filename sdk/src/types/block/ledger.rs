@@ -0,0 +1,401 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::{string::ToString, vec::Vec};
+
+use hashbrown::HashMap;
+
+use crate::types::{
+    api::core::response::UtxoChangesResponse,
+    block::{
+        input::Input,
+        output::{ChainId, Output, OutputId, OutputMetadata},
+        payload::transaction::{TransactionEssence, TransactionId, TransactionPayload},
+        protocol::ProtocolParameters,
+        semantic::{semantic_validation, ConflictReason, ValidationContext},
+        BlockId, Error,
+    },
+};
+
+/// An output of a [`Ledger`] and its metadata.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct LedgerOutput {
+    output: Output,
+    metadata: OutputMetadata,
+}
+
+impl LedgerOutput {
+    /// Returns the output of the [`LedgerOutput`].
+    pub fn output(&self) -> &Output {
+        &self.output
+    }
+
+    /// Returns the metadata of the [`LedgerOutput`].
+    pub fn metadata(&self) -> &OutputMetadata {
+        &self.metadata
+    }
+}
+
+/// The outputs created and consumed by a milestone.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct UtxoChanges {
+    /// The index of the milestone.
+    pub index: u32,
+    /// The outputs created by the milestone.
+    pub created_outputs: Vec<OutputId>,
+    /// The outputs consumed by the milestone.
+    pub consumed_outputs: Vec<OutputId>,
+}
+
+impl From<&UtxoChanges> for UtxoChangesResponse {
+    fn from(value: &UtxoChanges) -> Self {
+        Self {
+            index: value.index,
+            created_outputs: value.created_outputs.iter().map(ToString::to_string).collect(),
+            consumed_outputs: value.consumed_outputs.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+/// The serializable state of a [`Ledger`], taken between milestones.
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(rename_all = "camelCase")
+)]
+pub struct LedgerSnapshot {
+    /// The protocol parameters of the ledger.
+    pub protocol_parameters: ProtocolParameters,
+    /// The timestamp of the latest confirmed milestone.
+    pub milestone_timestamp: u32,
+    /// The spent and unspent outputs, sorted by id.
+    pub outputs: Vec<LedgerOutput>,
+    /// The changes of every confirmed milestone, by index.
+    pub utxo_changes: Vec<UtxoChanges>,
+}
+
+struct PendingMilestone {
+    timestamp: u32,
+    changes: UtxoChanges,
+}
+
+/// A UTXO ledger that applies transactions with full semantic validation.
+///
+/// Changes are grouped by milestone: [`Ledger::begin_milestone()`] opens a milestone, outputs are then created with
+/// [`Ledger::create_output()`] or by transactions with [`Ledger::apply_transaction()`], and
+/// [`Ledger::confirm_milestone()`] closes it and returns its [`UtxoChanges`].
+pub struct Ledger {
+    protocol_parameters: ProtocolParameters,
+    outputs: HashMap<OutputId, LedgerOutput>,
+    // The output holding the current state of each chain.
+    chains: HashMap<ChainId, OutputId>,
+    milestone_timestamp: u32,
+    utxo_changes: Vec<UtxoChanges>,
+    pending_milestone: Option<PendingMilestone>,
+}
+
+impl Ledger {
+    /// Creates an empty [`Ledger`], without any confirmed milestone.
+    pub fn new(protocol_parameters: ProtocolParameters) -> Self {
+        Self {
+            protocol_parameters,
+            outputs: HashMap::new(),
+            chains: HashMap::new(),
+            milestone_timestamp: 0,
+            utxo_changes: Vec::new(),
+            pending_milestone: None,
+        }
+    }
+
+    /// Restores a [`Ledger`] from a [`LedgerSnapshot`].
+    pub fn from_snapshot(snapshot: LedgerSnapshot) -> Self {
+        let mut ledger = Self::new(snapshot.protocol_parameters);
+
+        ledger.milestone_timestamp = snapshot.milestone_timestamp;
+        ledger.utxo_changes = snapshot.utxo_changes;
+
+        for ledger_output in snapshot.outputs {
+            let output_id = *ledger_output.metadata.output_id();
+
+            if !ledger_output.metadata.is_spent() {
+                if let Some(chain_id) = ledger_output.output.chain_id() {
+                    ledger.chains.insert(chain_id.or_from_output_id(&output_id), output_id);
+                }
+            }
+            ledger.outputs.insert(output_id, ledger_output);
+        }
+
+        ledger
+    }
+
+    /// Takes a [`LedgerSnapshot`] of the ledger, which can't be done while a milestone is pending.
+    pub fn snapshot(&self) -> Result<LedgerSnapshot, Error> {
+        if self.pending_milestone.is_some() {
+            return Err(Error::LedgerMilestonePending);
+        }
+
+        let mut outputs = self.outputs.values().cloned().collect::<Vec<_>>();
+        outputs.sort_unstable_by_key(|ledger_output| *ledger_output.metadata.output_id());
+
+        Ok(LedgerSnapshot {
+            protocol_parameters: self.protocol_parameters.clone(),
+            milestone_timestamp: self.milestone_timestamp,
+            outputs,
+            utxo_changes: self.utxo_changes.clone(),
+        })
+    }
+
+    /// Returns the protocol parameters of the ledger.
+    pub fn protocol_parameters(&self) -> &ProtocolParameters {
+        &self.protocol_parameters
+    }
+
+    /// Returns the index of the latest confirmed milestone, 0 if there is none.
+    pub fn ledger_index(&self) -> u32 {
+        self.utxo_changes.len() as u32
+    }
+
+    /// Returns the timestamp of the latest confirmed milestone.
+    pub fn milestone_timestamp(&self) -> u32 {
+        self.milestone_timestamp
+    }
+
+    /// Returns a spent or unspent output of the ledger.
+    pub fn output(&self, output_id: &OutputId) -> Option<&LedgerOutput> {
+        self.outputs.get(output_id)
+    }
+
+    /// Returns all spent and unspent outputs of the ledger.
+    pub fn outputs(&self) -> impl Iterator<Item = &LedgerOutput> {
+        self.outputs.values()
+    }
+
+    /// Returns the unspent outputs of the ledger.
+    pub fn unspent_outputs(&self) -> impl Iterator<Item = &LedgerOutput> {
+        self.outputs
+            .values()
+            .filter(|ledger_output| !ledger_output.metadata.is_spent())
+    }
+
+    /// Returns the unspent output holding the current state of an alias, foundry or NFT chain.
+    pub fn chain_output(&self, chain_id: &ChainId) -> Option<&LedgerOutput> {
+        self.chains
+            .get(chain_id)
+            .and_then(|output_id| self.outputs.get(output_id))
+    }
+
+    /// Returns the outputs created and consumed by a confirmed milestone.
+    pub fn utxo_changes(&self, index: u32) -> Option<&UtxoChanges> {
+        index
+            .checked_sub(1)
+            .and_then(|position| self.utxo_changes.get(position as usize))
+    }
+
+    /// Opens the next milestone, all changes until [`Ledger::confirm_milestone()`] belong to it. Its timestamp is used
+    /// to validate timelocks and expirations.
+    pub fn begin_milestone(&mut self, timestamp: u32) -> Result<u32, Error> {
+        if self.pending_milestone.is_some() {
+            return Err(Error::LedgerMilestonePending);
+        }
+        if self.ledger_index() > 0 && timestamp <= self.milestone_timestamp {
+            return Err(Error::LedgerMilestoneTimestampNotIncreasing {
+                previous: self.milestone_timestamp,
+                next: timestamp,
+            });
+        }
+
+        let index = self.ledger_index() + 1;
+        self.pending_milestone = Some(PendingMilestone {
+            timestamp,
+            changes: UtxoChanges {
+                index,
+                ..Default::default()
+            },
+        });
+
+        Ok(index)
+    }
+
+    /// Closes the pending milestone and returns the outputs it created and consumed.
+    pub fn confirm_milestone(&mut self) -> Result<&UtxoChanges, Error> {
+        let pending_milestone = self.pending_milestone.take().ok_or(Error::LedgerNoPendingMilestone)?;
+
+        self.milestone_timestamp = pending_milestone.timestamp;
+        self.utxo_changes.push(pending_milestone.changes);
+
+        // PANIC: a milestone has just been pushed.
+        Ok(self.utxo_changes.last().unwrap())
+    }
+
+    /// Creates an output out of thin air in the pending milestone, e.g. for a genesis or to fund an address.
+    pub fn create_output(&mut self, output_id: OutputId, output: Output, block_id: BlockId) -> Result<(), Error> {
+        if self.pending_milestone.is_none() {
+            return Err(Error::LedgerNoPendingMilestone);
+        }
+        if self.outputs.contains_key(&output_id) {
+            return Err(Error::LedgerOutputAlreadyExists(output_id));
+        }
+        if let Some(chain_id) = output.chain_id() {
+            let chain_id = chain_id.or_from_output_id(&output_id);
+
+            if self.chains.contains_key(&chain_id) {
+                return Err(Error::DuplicateOutputChain(chain_id));
+            }
+        }
+
+        output.verify_storage_deposit(
+            *self.protocol_parameters.rent_structure(),
+            self.protocol_parameters.token_supply(),
+        )?;
+
+        self.book_output(output_id, output, block_id);
+
+        Ok(())
+    }
+
+    /// Validates a transaction against the ledger at the timestamp of the pending milestone and applies it if it
+    /// doesn't conflict. The ledger is left untouched otherwise.
+    pub fn apply_transaction(
+        &mut self,
+        transaction: &TransactionPayload,
+        block_id: BlockId,
+    ) -> Result<ConflictReason, Error> {
+        let pending_milestone = self.pending_milestone.as_ref().ok_or(Error::LedgerNoPendingMilestone)?;
+        let TransactionEssence::Regular(essence) = transaction.essence();
+
+        if essence.network_id() != self.protocol_parameters.network_id() {
+            return Err(Error::NetworkIdMismatch {
+                expected: self.protocol_parameters.network_id(),
+                actual: essence.network_id(),
+            });
+        }
+
+        let mut inputs = Vec::with_capacity(essence.inputs().len());
+
+        for input in essence.inputs() {
+            let output_id = match input {
+                Input::Utxo(input) => *input.output_id(),
+                Input::Treasury(_) => return Ok(ConflictReason::SemanticValidationFailed),
+            };
+
+            match self.outputs.get(&output_id) {
+                Some(ledger_output) if ledger_output.metadata.is_spent() => {
+                    return Ok(
+                        if ledger_output.metadata.milestone_index_spent() == Some(pending_milestone.changes.index) {
+                            ConflictReason::InputUtxoAlreadySpentInThisMilestone
+                        } else {
+                            ConflictReason::InputUtxoAlreadySpent
+                        },
+                    );
+                }
+                Some(ledger_output) => inputs.push((output_id, &ledger_output.output)),
+                None => return Ok(ConflictReason::InputUtxoNotFound),
+            }
+        }
+
+        // Storage deposits are checked by nodes when the block is received, before semantic validation.
+        for output in essence.outputs() {
+            if output
+                .verify_storage_deposit(
+                    *self.protocol_parameters.rent_structure(),
+                    self.protocol_parameters.token_supply(),
+                )
+                .is_err()
+            {
+                return Ok(ConflictReason::SemanticValidationFailed);
+            }
+        }
+
+        let transaction_id = transaction.id();
+        let context = ValidationContext::new(
+            &transaction_id,
+            essence,
+            inputs.iter().map(|(output_id, output)| (output_id, *output)),
+            transaction.unlocks(),
+            pending_milestone.timestamp,
+        );
+        let conflict = semantic_validation(context, &inputs, transaction.unlocks())
+            .unwrap_or(ConflictReason::SemanticValidationFailed);
+
+        if conflict != ConflictReason::None {
+            return Ok(conflict);
+        }
+
+        for input in essence.inputs() {
+            let output_id = *input.as_utxo().output_id();
+            self.spend_output(output_id, transaction_id);
+        }
+        for (index, output) in essence.outputs().iter().enumerate() {
+            // PANIC: the number of outputs has been verified syntactically.
+            let output_id = OutputId::new(transaction_id, index as u16).unwrap();
+            self.book_output(output_id, output.clone(), block_id);
+        }
+
+        Ok(ConflictReason::None)
+    }
+
+    // Adds an output to the pending milestone, which has been checked to exist.
+    fn book_output(&mut self, output_id: OutputId, output: Output, block_id: BlockId) {
+        // PANIC: callers check that a milestone is pending.
+        let pending_milestone = self.pending_milestone.as_mut().unwrap();
+
+        if let Some(chain_id) = output.chain_id() {
+            self.chains.insert(chain_id.or_from_output_id(&output_id), output_id);
+        }
+
+        let metadata = OutputMetadata::new(
+            block_id,
+            output_id,
+            false,
+            None,
+            None,
+            None,
+            pending_milestone.changes.index,
+            pending_milestone.timestamp,
+            pending_milestone.changes.index,
+        );
+        self.outputs.insert(output_id, LedgerOutput { output, metadata });
+        pending_milestone.changes.created_outputs.push(output_id);
+    }
+
+    // Marks an unspent output as spent by the pending milestone, which has been checked to exist.
+    fn spend_output(&mut self, output_id: OutputId, transaction_id: TransactionId) {
+        // PANIC: callers check that a milestone is pending and that the output is unspent.
+        let pending_milestone = self.pending_milestone.as_mut().unwrap();
+        let ledger_output = self.outputs.get_mut(&output_id).unwrap();
+
+        if let Some(chain_id) = ledger_output.output.chain_id() {
+            let chain_id = chain_id.or_from_output_id(&output_id);
+
+            // Chains that are transitioned get their new state when the outputs are booked.
+            if self.chains.get(&chain_id) == Some(&output_id) {
+                self.chains.remove(&chain_id);
+            }
+        }
+
+        let metadata = &ledger_output.metadata;
+        ledger_output.metadata = OutputMetadata::new(
+            *metadata.block_id(),
+            output_id,
+            true,
+            Some(pending_milestone.changes.index),
+            Some(pending_milestone.timestamp),
+            Some(transaction_id),
+            metadata.milestone_index_booked(),
+            metadata.milestone_timestamp_booked(),
+            pending_milestone.changes.index,
+        );
+        pending_milestone.changes.consumed_outputs.push(output_id);
+    }
+}
@@ -19,6 +19,8 @@ pub mod dto;
 pub mod helper;
 /// A module that provides types and syntactic validations of inputs.
 pub mod input;
+/// A module that provides a ledger applying transactions with semantic validation.
+pub mod ledger;
 /// A module that provides types and syntactic validations of outputs.
 pub mod output;
 /// A module that provides types and syntactic validations of parents.
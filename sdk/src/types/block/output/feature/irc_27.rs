@@ -0,0 +1,286 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::{
+    collections::BTreeMap,
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::block::{address::Bech32Address, output::feature::MetadataFeature, Error};
+
+/// The NFT metadata standard defined in TIP-27, usually stored in the immutable metadata feature of an NFT output.
+///
+/// It's serialized as compact JSON, fields without a value are omitted to save storage deposit.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Irc27Metadata {
+    standard: String,
+    version: String,
+    /// The media type (MIME) of the asset.
+    #[serde(rename = "type")]
+    media_type: String,
+    /// URI pointing to the resource where the file with `media_type` is located.
+    uri: String,
+    /// The human-readable name of the NFT.
+    name: String,
+    /// The human-readable collection name of the NFT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    collection_name: Option<String>,
+    /// Royalty payment addresses mapped to their share of the royalties.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    royalties: BTreeMap<String, f64>,
+    /// The human-readable name of the creator.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    issuer_name: Option<String>,
+    /// The human-readable description of the NFT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    /// Additional attributes of the NFT.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    attributes: Vec<Irc27Attribute>,
+}
+
+impl Irc27Metadata {
+    /// The value of the `standard` field.
+    pub const STANDARD: &'static str = "IRC27";
+    /// The supported value of the `version` field.
+    pub const VERSION: &'static str = "v1.0";
+
+    /// Creates a new [`Irc27Metadata`] with the media type (e.g. `image/png`), the URI of the asset and the name.
+    pub fn new(media_type: impl Into<String>, uri: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            standard: Self::STANDARD.to_string(),
+            version: Self::VERSION.to_string(),
+            media_type: media_type.into(),
+            uri: uri.into(),
+            name: name.into(),
+            collection_name: None,
+            royalties: BTreeMap::new(),
+            issuer_name: None,
+            description: None,
+            attributes: Vec::new(),
+        }
+    }
+
+    /// Sets the collection name.
+    #[inline(always)]
+    pub fn with_collection_name(mut self, collection_name: impl Into<String>) -> Self {
+        self.collection_name.replace(collection_name.into());
+        self
+    }
+
+    /// Adds a royalty address with its share of the royalties, between 0 and 1.
+    #[inline(always)]
+    pub fn add_royalty(mut self, address: Bech32Address, share: f64) -> Self {
+        self.royalties.insert(address.to_string(), share);
+        self
+    }
+
+    /// Sets the royalty addresses with their share of the royalties.
+    #[inline(always)]
+    pub fn with_royalties(mut self, royalties: impl IntoIterator<Item = (Bech32Address, f64)>) -> Self {
+        self.royalties = royalties
+            .into_iter()
+            .map(|(address, share)| (address.to_string(), share))
+            .collect();
+        self
+    }
+
+    /// Sets the issuer name.
+    #[inline(always)]
+    pub fn with_issuer_name(mut self, issuer_name: impl Into<String>) -> Self {
+        self.issuer_name.replace(issuer_name.into());
+        self
+    }
+
+    /// Sets the description.
+    #[inline(always)]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description.replace(description.into());
+        self
+    }
+
+    /// Adds an [`Irc27Attribute`].
+    #[inline(always)]
+    pub fn add_attribute(mut self, attribute: Irc27Attribute) -> Self {
+        self.attributes.push(attribute);
+        self
+    }
+
+    /// Sets the [`Irc27Attribute`]s.
+    #[inline(always)]
+    pub fn with_attributes(mut self, attributes: impl IntoIterator<Item = Irc27Attribute>) -> Self {
+        self.attributes = attributes.into_iter().collect();
+        self
+    }
+
+    /// Returns the version.
+    pub fn version(&self) -> &str {
+        &self.version
+    }
+
+    /// Returns the media type.
+    pub fn media_type(&self) -> &str {
+        &self.media_type
+    }
+
+    /// Returns the URI.
+    pub fn uri(&self) -> &str {
+        &self.uri
+    }
+
+    /// Returns the name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the collection name.
+    pub fn collection_name(&self) -> Option<&str> {
+        self.collection_name.as_deref()
+    }
+
+    /// Returns the royalty addresses, as bech32 strings, with their share of the royalties.
+    pub fn royalties(&self) -> &BTreeMap<String, f64> {
+        &self.royalties
+    }
+
+    /// Returns the issuer name.
+    pub fn issuer_name(&self) -> Option<&str> {
+        self.issuer_name.as_deref()
+    }
+
+    /// Returns the description.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the attributes.
+    pub fn attributes(&self) -> &[Irc27Attribute] {
+        &self.attributes
+    }
+
+    /// Checks the metadata against the standard.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.standard != Self::STANDARD {
+            return Err(Error::InvalidIrc27Metadata(format!(
+                "invalid standard {}",
+                self.standard
+            )));
+        }
+        if self.version != Self::VERSION {
+            return Err(Error::InvalidIrc27Metadata(format!(
+                "unsupported version {}",
+                self.version
+            )));
+        }
+        match self.media_type.split_once('/') {
+            Some((kind, subtype)) if !kind.is_empty() && !subtype.is_empty() => {}
+            _ => {
+                return Err(Error::InvalidIrc27Metadata(format!(
+                    "invalid media type {}",
+                    self.media_type
+                )));
+            }
+        }
+        if self.uri.is_empty() {
+            return Err(Error::InvalidIrc27Metadata("empty uri".to_string()));
+        }
+        if self.name.is_empty() {
+            return Err(Error::InvalidIrc27Metadata("empty name".to_string()));
+        }
+
+        let mut royalties_sum = 0.0;
+        for (address, share) in &self.royalties {
+            Bech32Address::try_from_str(address)?;
+            if !(0.0..=1.0).contains(share) {
+                return Err(Error::InvalidIrc27Metadata(format!(
+                    "invalid royalty share {share} for {address}"
+                )));
+            }
+            royalties_sum += share;
+        }
+        if royalties_sum > 1.0 {
+            return Err(Error::InvalidIrc27Metadata(format!(
+                "royalty shares sum up to {royalties_sum}"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Validates the metadata and serializes it to compact JSON.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.validate()?;
+        // PANIC: the metadata only contains types that can be serialized to JSON.
+        Ok(serde_json::to_vec(self).unwrap())
+    }
+
+    /// Parses and validates metadata serialized as JSON.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let metadata: Self = serde_json::from_slice(bytes).map_err(|e| Error::InvalidIrc27Metadata(e.to_string()))?;
+        metadata.validate()?;
+        Ok(metadata)
+    }
+}
+
+impl TryFrom<&Irc27Metadata> for MetadataFeature {
+    type Error = Error;
+
+    fn try_from(metadata: &Irc27Metadata) -> Result<Self, Error> {
+        Self::new(metadata.to_bytes()?)
+    }
+}
+
+impl TryFrom<&MetadataFeature> for Irc27Metadata {
+    type Error = Error;
+
+    fn try_from(metadata: &MetadataFeature) -> Result<Self, Error> {
+        Self::from_bytes(metadata.data())
+    }
+}
+
+/// An attribute of an [`Irc27Metadata`].
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct Irc27Attribute {
+    trait_type: String,
+    value: serde_json::Value,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    display_type: Option<String>,
+}
+
+impl Irc27Attribute {
+    /// Creates a new [`Irc27Attribute`].
+    pub fn new(trait_type: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
+        Self {
+            trait_type: trait_type.into(),
+            value: value.into(),
+            display_type: None,
+        }
+    }
+
+    /// Sets how the attribute should be displayed.
+    #[inline(always)]
+    pub fn with_display_type(mut self, display_type: impl Into<String>) -> Self {
+        self.display_type.replace(display_type.into());
+        self
+    }
+
+    /// Returns the trait type.
+    pub fn trait_type(&self) -> &str {
+        &self.trait_type
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &serde_json::Value {
+        &self.value
+    }
+
+    /// Returns the display type.
+    pub fn display_type(&self) -> Option<&str> {
+        self.display_type.as_deref()
+    }
+}
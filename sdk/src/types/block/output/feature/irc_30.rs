@@ -0,0 +1,170 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::block::{output::feature::MetadataFeature, Error};
+
+/// The native token metadata standard defined in TIP-30, usually stored in the immutable metadata feature of the
+/// foundry output that controls the token.
+///
+/// It's serialized as compact JSON, fields without a value are omitted to save storage deposit.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Irc30Metadata {
+    standard: String,
+    /// The human-readable name of the native token.
+    name: String,
+    /// The human-readable description of the token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    /// The symbol/ticker of the token.
+    symbol: String,
+    /// Number of decimals the token uses (divide the token amount by `10^decimals` to get its user representation).
+    decimals: u32,
+    /// URL pointing to more resources about the token.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    url: Option<String>,
+    /// URL pointing to an image resource of the token logo.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    logo_url: Option<String>,
+    /// The SVG logo of the token encoded as a string.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    logo: Option<String>,
+}
+
+impl Irc30Metadata {
+    /// The value of the `standard` field.
+    pub const STANDARD: &'static str = "IRC30";
+
+    /// Creates a new [`Irc30Metadata`] with the name, symbol and decimals of the token.
+    pub fn new(name: impl Into<String>, symbol: impl Into<String>, decimals: u32) -> Self {
+        Self {
+            standard: Self::STANDARD.to_string(),
+            name: name.into(),
+            description: None,
+            symbol: symbol.into(),
+            decimals,
+            url: None,
+            logo_url: None,
+            logo: None,
+        }
+    }
+
+    /// Sets the description.
+    #[inline(always)]
+    pub fn with_description(mut self, description: impl Into<String>) -> Self {
+        self.description.replace(description.into());
+        self
+    }
+
+    /// Sets the URL.
+    #[inline(always)]
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url.replace(url.into());
+        self
+    }
+
+    /// Sets the logo URL.
+    #[inline(always)]
+    pub fn with_logo_url(mut self, logo_url: impl Into<String>) -> Self {
+        self.logo_url.replace(logo_url.into());
+        self
+    }
+
+    /// Sets the SVG logo.
+    #[inline(always)]
+    pub fn with_logo(mut self, logo: impl Into<String>) -> Self {
+        self.logo.replace(logo.into());
+        self
+    }
+
+    /// Returns the name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the description.
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// Returns the symbol.
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    /// Returns the decimals.
+    pub fn decimals(&self) -> u32 {
+        self.decimals
+    }
+
+    /// Returns the URL.
+    pub fn url(&self) -> Option<&str> {
+        self.url.as_deref()
+    }
+
+    /// Returns the logo URL.
+    pub fn logo_url(&self) -> Option<&str> {
+        self.logo_url.as_deref()
+    }
+
+    /// Returns the SVG logo.
+    pub fn logo(&self) -> Option<&str> {
+        self.logo.as_deref()
+    }
+
+    /// Checks the metadata against the standard.
+    pub fn validate(&self) -> Result<(), Error> {
+        if self.standard != Self::STANDARD {
+            return Err(Error::InvalidIrc30Metadata(format!(
+                "invalid standard {}",
+                self.standard
+            )));
+        }
+        if self.name.is_empty() {
+            return Err(Error::InvalidIrc30Metadata("empty name".to_string()));
+        }
+        if self.symbol.is_empty() {
+            return Err(Error::InvalidIrc30Metadata("empty symbol".to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// Validates the metadata and serializes it to compact JSON.
+    pub fn to_bytes(&self) -> Result<Vec<u8>, Error> {
+        self.validate()?;
+        // PANIC: the metadata only contains types that can be serialized to JSON.
+        Ok(serde_json::to_vec(self).unwrap())
+    }
+
+    /// Parses and validates metadata serialized as JSON.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, Error> {
+        let metadata: Self = serde_json::from_slice(bytes).map_err(|e| Error::InvalidIrc30Metadata(e.to_string()))?;
+        metadata.validate()?;
+        Ok(metadata)
+    }
+}
+
+impl TryFrom<&Irc30Metadata> for MetadataFeature {
+    type Error = Error;
+
+    fn try_from(metadata: &Irc30Metadata) -> Result<Self, Error> {
+        Self::new(metadata.to_bytes()?)
+    }
+}
+
+impl TryFrom<&MetadataFeature> for Irc30Metadata {
+    type Error = Error;
+
+    fn try_from(metadata: &MetadataFeature) -> Result<Self, Error> {
+        Self::from_bytes(metadata.data())
+    }
+}
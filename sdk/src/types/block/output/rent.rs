@@ -201,6 +201,16 @@ pub mod dto {
         pub v_byte_factor_data: u8,
     }
 
+    impl From<&RentStructure> for RentStructureDto {
+        fn from(value: &RentStructure) -> Self {
+            Self {
+                v_byte_cost: value.byte_cost(),
+                v_byte_factor_key: value.byte_factor_key(),
+                v_byte_factor_data: value.byte_factor_data(),
+            }
+        }
+    }
+
     impl From<RentStructureDto> for RentStructure {
         fn from(value: RentStructureDto) -> Self {
             Self::new(value.v_byte_cost, value.v_byte_factor_key, value.v_byte_factor_data)
@@ -163,6 +163,20 @@ pub mod dto {
         pub token_supply: String,
     }
 
+    impl From<&ProtocolParameters> for ProtocolParametersDto {
+        fn from(value: &ProtocolParameters) -> Self {
+            Self {
+                protocol_version: value.protocol_version(),
+                network_name: value.network_name().to_string(),
+                bech32_hrp: value.bech32_hrp().to_string(),
+                min_pow_score: value.min_pow_score(),
+                below_max_depth: value.below_max_depth(),
+                rent_structure: RentStructureDto::from(value.rent_structure()),
+                token_supply: value.token_supply().to_string(),
+            }
+        }
+    }
+
     impl TryFrom<ProtocolParametersDto> for ProtocolParameters {
         type Error = Error;
 
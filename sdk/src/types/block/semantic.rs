@@ -135,6 +135,8 @@ pub struct ValidationContext<'a> {
     pub storage_deposit_returns: HashMap<Address, u64>,
     ///
     pub simple_deposits: HashMap<Address, u64>,
+    /// Whether signatures are verified, disabled to validate transactions with placeholder signatures.
+    pub verify_signatures: bool,
 }
 
 impl<'a> ValidationContext<'a> {
@@ -179,6 +181,7 @@ impl<'a> ValidationContext<'a> {
             unlocked_addresses: HashSet::new(),
             storage_deposit_returns: HashMap::new(),
             simple_deposits: HashMap::new(),
+            verify_signatures: true,
         }
     }
 }
@@ -30,6 +30,7 @@ pub struct AccountBuilder {
     coin_type: u32,
     secret_manager: Arc<RwLock<SecretManager>>,
     accounts: Arc<RwLock<Vec<Account>>>,
+    watch_only_addresses: Option<Vec<Bech32Address>>,
     #[cfg(feature = "events")]
     event_emitter: Arc<tokio::sync::Mutex<EventEmitter>>,
     #[cfg(feature = "storage")]
@@ -54,6 +55,7 @@ impl AccountBuilder {
             coin_type,
             secret_manager,
             accounts,
+            watch_only_addresses: None,
             #[cfg(feature = "events")]
             event_emitter,
             #[cfg(feature = "storage")]
@@ -80,6 +82,15 @@ impl AccountBuilder {
         self
     }
 
+    /// Create a watch-only account for the provided addresses, which can sync, get the balance and prepare
+    /// transactions to be signed offline, but can't generate addresses or sign transactions. Addresses are derived
+    /// with hardened Ed25519 derivation, for which there are no extended public keys, so all addresses to watch need to
+    /// be provided. Can't be combined with [`AccountBuilder::with_addresses()`].
+    pub fn with_watch_only_addresses(mut self, addresses: impl Into<Option<Vec<Bech32Address>>>) -> Self {
+        self.watch_only_addresses = addresses.into();
+        self
+    }
+
     /// Build the Account and add it to the accounts from Wallet
     /// Also generates the first address of the account and if it's not the first account, the address for the first
     /// account will also be generated and compared, so no accounts get generated with different seeds
@@ -114,17 +125,48 @@ impl AccountBuilder {
 
         // If addresses are provided we will use them directly without the additional checks, because then we assume
         // that it's for offline signing and the secretManager can't be used
-        let addresses = match &self.addresses {
-            Some(addresses) => addresses.clone(),
-            None => {
+        let addresses = match (&self.watch_only_addresses, &self.addresses) {
+            (Some(_), Some(_)) => return Err(Error::WatchOnlyAccount("set addresses")),
+            (Some(watch_only_addresses), None) => {
+                if watch_only_addresses.is_empty() {
+                    return Err(Error::MissingParameter("watch-only addresses"));
+                }
+                for address in watch_only_addresses {
+                    client.bech32_hrp_matches(address.hrp()).await?;
+                }
+                watch_only_addresses
+                    .iter()
+                    .enumerate()
+                    .map(|(key_index, address)| AccountAddress {
+                        address: address.clone(),
+                        key_index: key_index as u32,
+                        internal: false,
+                        used: false,
+                    })
+                    .collect()
+            }
+            (None, Some(addresses)) => addresses.clone(),
+            (None, None) => {
                 let mut bech32_hrp = self.bech32_hrp.clone();
-                if let Some(first_account) = accounts.first() {
-                    let first_account_coin_type = *first_account.read().await.coin_type();
+                // Watch-only accounts weren't created from the secret manager, so they can't be compared
+                let mut first_account = None;
+                for account in accounts.iter() {
+                    if !account.read().await.watch_only {
+                        first_account.replace(account);
+                        break;
+                    }
+                }
+                if let Some(first_account) = first_account {
+                    let (first_account_index, first_account_coin_type) = {
+                        let first_account = first_account.read().await;
+                        (first_account.index, first_account.coin_type)
+                    };
                     // Generate the first address of the first account and compare it to the stored address from the
                     // first account to prevent having multiple accounts created with different
                     // seeds
                     let first_account_public_address =
-                        get_first_public_address(&self.secret_manager, first_account_coin_type, 0).await?;
+                        get_first_public_address(&self.secret_manager, first_account_coin_type, first_account_index)
+                            .await?;
                     let first_account_addresses = first_account.public_addresses().await;
 
                     if first_account_public_address
@@ -184,6 +226,8 @@ impl AccountBuilder {
             incoming_transactions: HashMap::new(),
             inaccessible_incoming_transactions: HashSet::new(),
             native_token_foundries: HashMap::new(),
+            watch_only: self.watch_only_addresses.is_some(),
+            refundable_payments: HashMap::new(),
         };
 
         let account = Account::new(
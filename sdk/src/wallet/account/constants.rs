@@ -1,6 +1,8 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::time::Duration;
+
 /// Amount at which outputs on a single addresses will get consolidated by default if consolidatioin is enabled
 pub(crate) const DEFAULT_OUTPUT_CONSOLIDATION_THRESHOLD: usize = 100;
 /// Amount at which outputs on a single addresses will get consolidated by default with a ledger secret_manager if
@@ -15,5 +17,17 @@ pub(crate) const PARALLEL_REQUESTS_AMOUNT: usize = 500;
 /// this is done to prevent unnecessary simultaneous synchronizations
 pub(crate) const MIN_SYNC_INTERVAL: u128 = 5;
 
+/// Default time after which the payment queue is flushed, if it didn't reach the max payments before
+pub(crate) const DEFAULT_PAYMENT_QUEUE_MAX_DELAY: Duration = Duration::from_secs(10);
+/// Amount of statuses of sent or failed payments from the payment queue that are kept, older ones are removed
+pub(crate) const PAYMENT_STATUSES_MAX: usize = 1000;
+/// Amount of claimed or refunded payments sent with a refund deadline that are kept, older ones are removed
+pub(crate) const REFUNDABLE_PAYMENTS_MAX: usize = 1000;
+
 // Default expiration time for [ExpirationUnlockCondition] when sending native tokens, one day in seconds
 pub(crate) const DEFAULT_EXPIRATION_TIME: u32 = 86400;
+
+// Seconds before a claimable output expires to its return address, in which the OutputExpiring event is emitted, one
+// day
+#[cfg(feature = "events")]
+pub(crate) const OUTPUT_EXPIRING_EVENT_TIME: u32 = 86400;
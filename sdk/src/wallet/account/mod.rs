@@ -25,13 +25,19 @@ use tokio::sync::{Mutex, RwLock};
 
 #[cfg(feature = "participation")]
 pub use self::operations::participation::{AccountParticipationOverview, ParticipationEventWithNodes};
-use self::types::{
-    address::{AccountAddress, AddressWithUnspentOutputs},
-    AccountBalance, OutputData, Transaction,
+#[cfg(feature = "events")]
+use self::operations::syncing::events::BalanceEvents;
+use self::{
+    operations::transaction::high_level::payment_queue::PaymentQueue,
+    types::{
+        address::{AccountAddress, AddressWithUnspentOutputs},
+        AccountBalance, OutputData, Transaction,
+    },
 };
 pub use self::{
     operations::{
         output_claiming::OutputsToClaim,
+        sweep::{SweepResult, UnsweptOutput},
         syncing::{
             options::{AccountSyncOptions, AliasSyncOptions, NftSyncOptions},
             SyncOptions,
@@ -46,19 +52,22 @@ pub use self::{
                     mint_native_token::{MintTokenTransactionDto, NativeTokenOptions, NativeTokenOptionsDto},
                     mint_nfts::{NftOptions, NftOptionsDto},
                 },
+                payment_queue::{Payment, PaymentQueueOptions, PaymentStatus},
+                refundable_payment::{RefundablePayment, RefundablePaymentOptions, RefundablePaymentStatus},
+                vesting::{VestingBalance, VestingSchedule, VestingUnlock},
             },
             prepare_output::{
                 Assets, Features, OutputOptions, OutputOptionsDto, ReturnStrategy, StorageDeposit, Unlocks,
             },
-            RemainderValueStrategy, TransactionOptions, TransactionOptionsDto,
+            RemainderValueStrategy, TransactionOptions, TransactionOptionsDto, TransactionSimulation,
         },
     },
     types::OutputDataDto,
 };
 #[cfg(feature = "events")]
-use crate::wallet::events::EventEmitter;
+use crate::wallet::events::{types::WalletEvent, EventEmitter};
 #[cfg(feature = "storage")]
-use crate::wallet::storage::manager::StorageManager;
+use crate::wallet::storage::manager::{AccountChanges, StorageManager};
 use crate::{
     client::{secret::SecretManager, Client},
     types::{
@@ -99,11 +108,11 @@ pub struct FilterOptions {
 #[serde(rename_all = "camelCase")]
 pub struct AccountDetails {
     /// The account index
-    index: u32,
+    pub(crate) index: u32,
     /// The coin type
-    coin_type: u32,
+    pub(crate) coin_type: u32,
     /// The account alias.
-    alias: String,
+    pub(crate) alias: String,
     /// Public addresses
     pub(crate) public_addresses: Vec<AccountAddress>,
     /// Internal addresses
@@ -111,36 +120,47 @@ pub struct AccountDetails {
     /// Addresses with unspent outputs
     // used to improve performance for syncing and get balance because it's in most cases only a subset of all
     // addresses
-    addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
-    /// Outputs
-    // stored separated from the account for performance?
-    outputs: HashMap<OutputId, OutputData>,
+    pub(crate) addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
+    /// Outputs that are loaded in memory
+    ///
+    /// After loading the account from the storage, these are only the unspent and locked outputs, the ones of pending
+    /// transactions and the ones synced afterwards, use [`Account::outputs()`] to also get the spent ones.
+    pub(crate) outputs: HashMap<OutputId, OutputData>,
     /// Unspent outputs that are currently used as input for transactions
     // outputs used in transactions should be locked here so they don't get used again, which would result in a
     // conflicting transaction
     pub(crate) locked_outputs: HashSet<OutputId>,
     /// Unspent outputs
     // have unspent outputs in a separated hashmap so we don't need to iterate over all outputs we have
-    unspent_outputs: HashMap<OutputId, OutputData>,
-    /// Sent transactions
+    pub(crate) unspent_outputs: HashMap<OutputId, OutputData>,
+    /// Sent transactions that are loaded in memory
+    ///
+    /// After loading the account from the storage, these are only the pending transactions and the ones sent
+    /// afterwards, use [`Account::transactions()`] to also get the historical ones.
     // stored separated from the account for performance and only the transaction id here? where to add the network id?
     // transactions: HashSet<TransactionId>,
-    transactions: HashMap<TransactionId, types::Transaction>,
+    pub(crate) transactions: HashMap<TransactionId, types::Transaction>,
     /// Pending transactions
     // Maybe pending transactions even additionally separated?
-    pending_transactions: HashSet<TransactionId>,
+    pub(crate) pending_transactions: HashSet<TransactionId>,
     /// Transaction payloads for received outputs with inputs when not pruned before syncing, can be used to determine
     /// the sender address/es
     #[serde(deserialize_with = "deserialize_or_convert")]
-    incoming_transactions: HashMap<TransactionId, Transaction>,
+    pub(crate) incoming_transactions: HashMap<TransactionId, Transaction>,
     /// Some incoming transactions can be pruned by the node before we requested them, then this node can never return
     /// it. To avoid useless requests, these transaction ids are stored here and cleared when new client options are
     /// set, because another node might still have them.
     #[serde(default)]
-    inaccessible_incoming_transactions: HashSet<TransactionId>,
+    pub(crate) inaccessible_incoming_transactions: HashSet<TransactionId>,
     /// Foundries for native tokens in outputs
     #[serde(default)]
-    native_token_foundries: HashMap<FoundryId, FoundryOutput>,
+    pub(crate) native_token_foundries: HashMap<FoundryId, FoundryOutput>,
+    /// If the account only watches imported addresses, so it can't generate addresses or sign transactions
+    #[serde(default)]
+    pub(crate) watch_only: bool,
+    /// Payments sent with a refund deadline, which are reclaimed when syncing after the deadline passed
+    #[serde(default)]
+    pub(crate) refundable_payments: HashMap<OutputId, RefundablePayment>,
 }
 
 /// A thread guard over an account, so we can lock the account during operations.
@@ -156,6 +176,11 @@ pub struct Account {
     pub(crate) default_sync_options: Arc<Mutex<SyncOptions>>,
     #[cfg(feature = "events")]
     pub(crate) event_emitter: Arc<Mutex<EventEmitter>>,
+    // the last synced balance and reported outputs, so balance and output events are only emitted on changes
+    #[cfg(feature = "events")]
+    pub(crate) balance_events: Arc<Mutex<BalanceEvents>>,
+    // payments queued to be sent together, and the status of the payments queued before, stored with the account
+    pub(crate) payment_queue: Arc<Mutex<PaymentQueue>>,
     #[cfg(feature = "storage")]
     pub(crate) storage_manager: Arc<Mutex<StorageManager>>,
 }
@@ -187,8 +212,30 @@ impl Account {
             .unwrap_or_default();
         #[cfg(not(feature = "storage"))]
         let default_sync_options = Default::default();
+        #[cfg(feature = "storage")]
+        let payment_queue = {
+            let storage_manager = storage_manager.lock().await;
+            match storage_manager.get_payment_queue(*details.index()).await? {
+                Some(payment_queue) => {
+                    // Payments that were being sent are looked up in the stored transactions
+                    let transactions = if payment_queue.has_in_flight_payments() {
+                        storage_manager.get_transactions(*details.index()).await?
+                    } else {
+                        Vec::new()
+                    };
+                    payment_queue.restore(&transactions)
+                }
+                None => PaymentQueue::default(),
+            }
+        };
+        #[cfg(not(feature = "storage"))]
+        let payment_queue = PaymentQueue::default();
+
+        // Restored payments wait for the max delay again
+        #[cfg(not(target_family = "wasm"))]
+        let has_queued_payments = payment_queue.has_queued_payments();
 
-        Ok(Self {
+        let account = Self {
             details: Arc::new(RwLock::new(details)),
             client,
             secret_manager,
@@ -196,9 +243,18 @@ impl Account {
             default_sync_options: Arc::new(Mutex::new(default_sync_options)),
             #[cfg(feature = "events")]
             event_emitter,
+            #[cfg(feature = "events")]
+            balance_events: Default::default(),
+            payment_queue: Arc::new(Mutex::new(payment_queue)),
             #[cfg(feature = "storage")]
             storage_manager,
-        })
+        };
+        #[cfg(not(target_family = "wasm"))]
+        if has_queued_payments {
+            account.spawn_payment_queue_timer();
+        }
+
+        Ok(account)
     }
 
     pub async fn alias(&self) -> String {
@@ -211,8 +267,21 @@ impl Account {
     }
 
     /// Get the [`OutputData`] of an output stored in the account
-    pub async fn get_output(&self, output_id: &OutputId) -> Option<OutputData> {
-        self.read().await.outputs().get(output_id).cloned()
+    pub async fn get_output(&self, output_id: &OutputId) -> Result<Option<OutputData>> {
+        let account_details = self.read().await;
+        if let Some(output_data) = account_details.outputs().get(output_id) {
+            return Ok(Some(output_data.clone()));
+        }
+        // Spent outputs are only loaded from the storage when requested
+        #[cfg(feature = "storage")]
+        return self
+            .storage_manager
+            .lock()
+            .await
+            .get_output(account_details.index, output_id)
+            .await;
+        #[cfg(not(feature = "storage"))]
+        Ok(None)
     }
 
     /// Get the [`Output`] that minted a native token by the token ID. First try to get it
@@ -239,8 +308,21 @@ impl Account {
     }
 
     /// Get the [`Transaction`] of a transaction stored in the account
-    pub async fn get_transaction(&self, transaction_id: &TransactionId) -> Option<Transaction> {
-        self.read().await.transactions().get(transaction_id).cloned()
+    pub async fn get_transaction(&self, transaction_id: &TransactionId) -> Result<Option<Transaction>> {
+        let account_details = self.read().await;
+        if let Some(transaction) = account_details.transactions().get(transaction_id) {
+            return Ok(Some(transaction.clone()));
+        }
+        // Historical transactions are only loaded from the storage when requested
+        #[cfg(feature = "storage")]
+        return self
+            .storage_manager
+            .lock()
+            .await
+            .get_transaction(account_details.index, transaction_id)
+            .await;
+        #[cfg(not(feature = "storage"))]
+        Ok(None)
     }
 
     /// Get the transaction with inputs of an incoming transaction stored in the account
@@ -337,7 +419,21 @@ impl Account {
 
     /// Returns outputs of the account
     pub async fn outputs(&self, filter: impl Into<Option<FilterOptions>> + Send) -> Result<Vec<OutputData>> {
-        self.filter_outputs(self.read().await.outputs.values(), filter)
+        let account_details = self.read().await;
+        #[cfg_attr(not(feature = "storage"), allow(unused_mut))]
+        let mut outputs = account_details.outputs.clone();
+        // Spent outputs are only loaded from the storage when requested
+        #[cfg(feature = "storage")]
+        for output_data in self
+            .storage_manager
+            .lock()
+            .await
+            .get_outputs(account_details.index)
+            .await?
+        {
+            outputs.entry(output_data.output_id).or_insert(output_data);
+        }
+        self.filter_outputs(outputs.values(), filter)
     }
 
     /// Returns unspent outputs of the account
@@ -382,7 +478,21 @@ impl Account {
 
     /// Returns all transactions of the account
     pub async fn transactions(&self) -> Result<Vec<Transaction>> {
-        Ok(self.read().await.transactions.values().cloned().collect())
+        let account_details = self.read().await;
+        #[cfg_attr(not(feature = "storage"), allow(unused_mut))]
+        let mut transactions = account_details.transactions.clone();
+        // Historical transactions are only loaded from the storage when requested
+        #[cfg(feature = "storage")]
+        for transaction in self
+            .storage_manager
+            .lock()
+            .await
+            .get_transactions(account_details.index)
+            .await?
+        {
+            transactions.entry(transaction.transaction_id).or_insert(transaction);
+        }
+        Ok(transactions.into_values().collect())
     }
 
     /// Returns all pending transactions of the account
@@ -420,10 +530,29 @@ impl Account {
         }
         Ok(())
     }
+
+    /// Save only the changed records of the account to the database
+    #[cfg(feature = "storage")]
+    pub(crate) async fn save_changes(&self, account: &AccountDetails, changes: AccountChanges) -> Result<()> {
+        log::debug!("[save_changes] saving account changes to database");
+        self.storage_manager
+            .lock()
+            .await
+            .save_account_changes(account, changes)
+            .await
+    }
+
+    /// Emit an event, events from syncing are added to the event log together with the account changes
+    #[cfg(feature = "events")]
+    pub(crate) async fn emit(&self, account_index: u32, event: WalletEvent) {
+        self.event_emitter.lock().await.emit(account_index, event);
+    }
 }
 
 // Custom deserialization to stay backwards compatible
-fn deserialize_or_convert<'de, D>(deserializer: D) -> std::result::Result<HashMap<TransactionId, Transaction>, D::Error>
+pub(crate) fn deserialize_or_convert<'de, D>(
+    deserializer: D,
+) -> std::result::Result<HashMap<TransactionId, Transaction>, D::Error>
 where
     D: Deserializer<'de>,
 {
@@ -566,6 +695,8 @@ fn serialize() {
         incoming_transactions,
         inaccessible_incoming_transactions: HashSet::new(),
         native_token_foundries: HashMap::new(),
+        watch_only: false,
+        refundable_payments: HashMap::new(),
     };
 
     serde_json::from_str::<AccountDetails>(&serde_json::to_string(&account).unwrap()).unwrap();
@@ -39,6 +39,9 @@ impl Account {
         }
 
         let account_details = self.read().await;
+        if account_details.watch_only {
+            return Err(crate::wallet::Error::WatchOnlyAccount("generate addresses"));
+        }
 
         // get the highest index for the public or internal addresses
         let highest_current_index_plus_one = if options.internal {
@@ -137,6 +140,16 @@ impl Account {
                     .await?
             }
             SecretManager::Placeholder(_) => vec![],
+            secret_manager @ (SecretManager::Remote(_) | SecretManager::Custom(_)) => {
+                secret_manager
+                    .generate_addresses(
+                        account_details.coin_type,
+                        account_details.index,
+                        address_range,
+                        Some(options),
+                    )
+                    .await?
+            }
         };
 
         drop(account_details);
@@ -20,6 +20,8 @@ pub(crate) mod output_finder;
 pub(crate) mod participation;
 /// The module for retrying blocks or transactions
 pub(crate) mod retry;
+/// The module for moving the funds of another secret manager into an account
+pub(crate) mod sweep;
 /// The module for synchronization of an account
 pub(crate) mod syncing;
 /// The module for transactions
@@ -41,7 +41,7 @@ impl Account {
     ) -> crate::wallet::Result<BlockId> {
         log::debug!("[retry_transaction_until_included]");
 
-        let transaction = self.read().await.transactions.get(transaction_id).cloned();
+        let transaction = self.get_transaction(transaction_id).await?;
 
         if let Some(transaction) = transaction {
             if transaction.inclusion_state == InclusionState::Confirmed {
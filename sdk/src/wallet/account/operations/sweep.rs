@@ -0,0 +1,319 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
+
+use crypto::keys::slip10::Chain;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{
+        api::{input_selection::Selected, transaction::validate_transaction_payload_length, SignedTransactionData},
+        node_api::indexer::query_parameters::QueryParameter,
+        secret::{types::InputSigningData, GenerateAddressOptions, SecretManage, SecretManageExt, SecretManager},
+    },
+    types::block::{
+        address::Address,
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition,
+            },
+            AliasOutputBuilder, BasicOutputBuilder, NativeTokens, NativeTokensBuilder, NftOutputBuilder, Output,
+            OutputId, OutputMetadata, OUTPUT_COUNT_MAX,
+        },
+        payload::transaction::TransactionPayload,
+    },
+    wallet::{
+        account::{operations::output_claiming::get_new_native_token_count, types::Transaction, Account},
+        Error,
+    },
+};
+
+/// The result of [`Account::sweep_from_secret_manager()`]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SweepResult {
+    /// The transactions that moved the outputs into the account, their inputs are the swept outputs
+    pub transactions: Vec<Transaction>,
+    /// The outputs that were found, but couldn't be moved into the account
+    pub unswept_outputs: Vec<UnsweptOutput>,
+}
+
+/// An output that was found by [`Account::sweep_from_secret_manager()`], but couldn't be moved into the account
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsweptOutput {
+    /// The id of the output
+    pub output_id: OutputId,
+    /// Why the output couldn't be moved
+    pub reason: String,
+}
+
+impl Account {
+    /// Move the funds of another mnemonic or seed into this account. For every account index in `account_range`, the
+    /// public and internal addresses of the secret manager are scanned until `address_gap_limit` consecutive addresses
+    /// without outputs are found. Basic and NFT outputs that are only locked to an address, and alias outputs which
+    /// governor address is controlled by the secret manager, are transferred to the first address of this account,
+    /// including their native tokens. The basic outputs are merged, NFT and alias outputs keep their ids, and the
+    /// control over an alias output is transferred with a governance transition, so its foundries stay with it.
+    /// Outputs with other unlock conditions, aliases with a foreign governor and outputs of transactions that failed
+    /// are returned as unswept with the reason.
+    pub async fn sweep_from_secret_manager(
+        &self,
+        secret_manager: &SecretManager,
+        account_range: Range<u32>,
+        address_gap_limit: u32,
+    ) -> crate::wallet::Result<SweepResult> {
+        log::debug!("[SWEEP] sweep_from_secret_manager");
+        let address_gap_limit = address_gap_limit.max(1);
+        let coin_type = self.read().await.coin_type;
+        let bech32_hrp = self.client.get_bech32_hrp().await?;
+        let token_supply = self.client.get_token_supply().await?;
+        let destination = self
+            .public_addresses()
+            .await
+            .first()
+            .ok_or(Error::FailedToGetRemainder)?
+            .address
+            .inner;
+
+        // The chain of every scanned address, so inputs can be signed
+        let mut chains = HashMap::new();
+        let mut output_ids = HashSet::new();
+
+        for account_index in account_range {
+            for internal in [false, true] {
+                let mut address_index = 0;
+                loop {
+                    let addresses = secret_manager
+                        .generate_addresses(
+                            coin_type,
+                            account_index,
+                            address_index..address_index + address_gap_limit,
+                            Some(GenerateAddressOptions {
+                                internal,
+                                ..Default::default()
+                            }),
+                        )
+                        .await?;
+
+                    let mut found_outputs = false;
+                    for (offset, address) in addresses.into_iter().enumerate() {
+                        // 44 is for BIP 44 (HD wallets)
+                        chains.insert(
+                            address,
+                            Chain::from_u32_hardened(vec![
+                                44,
+                                coin_type,
+                                account_index,
+                                internal as u32,
+                                address_index + offset as u32,
+                            ]),
+                        );
+                        let address_output_ids = self.sweepable_output_ids(address.to_bech32(&bech32_hrp)).await?;
+                        found_outputs |= !address_output_ids.is_empty();
+                        output_ids.extend(address_output_ids);
+                    }
+
+                    if !found_outputs {
+                        break;
+                    }
+                    address_index += address_gap_limit;
+                }
+            }
+        }
+        log::debug!("[SWEEP] found {} outputs", output_ids.len());
+
+        let mut sweep_result = SweepResult::default();
+        let mut inputs = Vec::new();
+
+        for output_response in self.client.get_outputs(output_ids.into_iter().collect()).await? {
+            let output = Output::try_from_dto(&output_response.output, token_supply)?;
+            let output_metadata = OutputMetadata::try_from(&output_response.metadata)?;
+
+            let unlock_address = match &output {
+                Output::Alias(alias_output) => Ok(alias_output.governor_address()),
+                Output::Basic(_) | Output::Nft(_) => match output.unlock_conditions() {
+                    Some(unlock_conditions) if unlock_conditions.len() == 1 => unlock_conditions
+                        .address()
+                        .map(|unlock_condition| unlock_condition.address())
+                        .ok_or("the output isn't locked to an address"),
+                    _ => Err("the output has unlock conditions other than an address unlock condition"),
+                },
+                _ => Err("the output type can't be swept"),
+            };
+
+            match unlock_address.and_then(|address| {
+                chains
+                    .get(address)
+                    .ok_or("the unlock address isn't controlled by the secret manager")
+            }) {
+                Ok(chain) => inputs.push(InputSigningData {
+                    output,
+                    output_metadata,
+                    chain: Some(chain.clone()),
+                }),
+                Err(reason) => sweep_result.unswept_outputs.push(UnsweptOutput {
+                    output_id: *output_metadata.output_id(),
+                    reason: reason.to_string(),
+                }),
+            }
+        }
+
+        for chunk in sweep_chunks(inputs)? {
+            match self
+                .sweep_inputs(secret_manager, chunk.clone(), destination, token_supply)
+                .await
+            {
+                Ok(transaction) => sweep_result.transactions.push(transaction),
+                Err(err) => {
+                    log::debug!("[SWEEP] sweeping outputs failed: {err}");
+                    sweep_result
+                        .unswept_outputs
+                        .extend(chunk.iter().map(|input| UnsweptOutput {
+                            output_id: *input.output_id(),
+                            reason: err.to_string(),
+                        }));
+                }
+            }
+        }
+
+        Ok(sweep_result)
+    }
+
+    // Get the ids of the basic and NFT outputs on an address and of the alias outputs it controls
+    async fn sweepable_output_ids(&self, bech32_address: String) -> crate::wallet::Result<Vec<OutputId>> {
+        let mut output_ids = self
+            .client
+            .basic_output_ids(vec![QueryParameter::Address(bech32_address.clone())])
+            .await?
+            .items;
+        output_ids.extend(
+            self.client
+                .nft_output_ids(vec![QueryParameter::Address(bech32_address.clone())])
+                .await?
+                .items,
+        );
+        // Aliases with the address only as state controller are requested too, so they can be reported as unswept
+        output_ids.extend(
+            self.client
+                .alias_output_ids(vec![QueryParameter::Governor(bech32_address.clone())])
+                .await?
+                .items,
+        );
+        output_ids.extend(
+            self.client
+                .alias_output_ids(vec![QueryParameter::StateController(bech32_address)])
+                .await?
+                .items,
+        );
+
+        Ok(output_ids)
+    }
+
+    // Transfer the inputs to the destination address in a transaction signed by the secret manager
+    async fn sweep_inputs(
+        &self,
+        secret_manager: &SecretManager,
+        inputs: Vec<InputSigningData>,
+        destination: Address,
+        token_supply: u64,
+    ) -> crate::wallet::Result<Transaction> {
+        let mut outputs = Vec::new();
+        let mut basic_amount = 0;
+        let mut basic_native_tokens = NativeTokensBuilder::new();
+
+        for input in &inputs {
+            match &input.output {
+                Output::Basic(basic_output) => {
+                    basic_amount += basic_output.amount();
+                    basic_native_tokens.add_native_tokens(basic_output.native_tokens().clone())?;
+                }
+                Output::Nft(nft_output) => outputs.push(
+                    NftOutputBuilder::from(nft_output)
+                        .with_nft_id(nft_output.nft_id_non_null(input.output_id()))
+                        .with_unlock_conditions([AddressUnlockCondition::new(destination)])
+                        .finish_output(token_supply)?,
+                ),
+                // Governance transition, the state stays unchanged
+                Output::Alias(alias_output) => outputs.push(
+                    AliasOutputBuilder::from(alias_output)
+                        .with_alias_id(alias_output.alias_id_non_null(input.output_id()))
+                        .replace_unlock_condition(StateControllerAddressUnlockCondition::new(destination))
+                        .replace_unlock_condition(GovernorAddressUnlockCondition::new(destination))
+                        .finish_output(token_supply)?,
+                ),
+                _ => {}
+            }
+        }
+
+        if basic_amount > 0 {
+            outputs.push(
+                BasicOutputBuilder::new_with_amount(basic_amount)
+                    .add_unlock_condition(AddressUnlockCondition::new(destination))
+                    .with_native_tokens(basic_native_tokens.finish()?)
+                    .finish_output(token_supply)?,
+            );
+        }
+
+        let prepared_transaction_data = self
+            .build_transaction_essence(
+                Selected {
+                    inputs,
+                    outputs,
+                    remainder: None,
+                },
+                None,
+            )
+            .await?;
+
+        let unlocks = secret_manager
+            .sign_transaction_essence(&prepared_transaction_data, None)
+            .await?;
+        let transaction_payload = TransactionPayload::new(prepared_transaction_data.essence, unlocks)?;
+        validate_transaction_payload_length(&transaction_payload)?;
+
+        self.submit_and_store_transaction(SignedTransactionData {
+            transaction_payload,
+            inputs_data: prepared_transaction_data.inputs_data,
+        })
+        .await
+    }
+}
+
+// Split the inputs into chunks that fit into one transaction each, with space for the output with the merged basic
+// outputs, which can't hold more than the max native tokens count
+fn sweep_chunks(inputs: Vec<InputSigningData>) -> crate::wallet::Result<Vec<Vec<InputSigningData>>> {
+    let mut chunks = Vec::new();
+    let mut chunk = Vec::new();
+    let mut chunk_native_tokens = NativeTokensBuilder::new();
+
+    for input in inputs {
+        let native_tokens = match &input.output {
+            Output::Basic(basic_output) => Some(basic_output.native_tokens()),
+            _ => None,
+        };
+        let exceeds_native_token_count = match native_tokens {
+            Some(native_tokens) => {
+                get_new_native_token_count(&chunk_native_tokens, native_tokens)? > NativeTokens::COUNT_MAX.into()
+            }
+            None => false,
+        };
+        if chunk.len() == OUTPUT_COUNT_MAX as usize - 1 || exceeds_native_token_count {
+            chunks.push(std::mem::take(&mut chunk));
+            chunk_native_tokens = NativeTokensBuilder::new();
+        }
+        if let Some(native_tokens) = native_tokens {
+            chunk_native_tokens.add_native_tokens(native_tokens.clone())?;
+        }
+        chunk.push(input);
+    }
+    if !chunk.is_empty() {
+        chunks.push(chunk);
+    }
+
+    Ok(chunks)
+}
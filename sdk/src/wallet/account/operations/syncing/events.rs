@@ -0,0 +1,124 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use crate::{
+    types::block::{address::Address, output::OutputId},
+    wallet::{
+        account::{
+            constants::OUTPUT_EXPIRING_EVENT_TIME,
+            types::{AccountAddress, AccountBalance, AccountBalanceDto, OutputDataDto},
+            Account, AccountDetails, OutputsToClaim,
+        },
+        events::types::{BalanceChangedEvent, OutputClaimableEvent, OutputExpiringEvent, WalletEvent},
+    },
+};
+
+impl Account {
+    /// Stores the balance from before the first sync, so a change by the first sync is reported too.
+    pub(crate) async fn init_balance_events(&self) -> crate::wallet::Result<()> {
+        if self.balance_events.lock().await.balance.is_none() {
+            let balance = self.balance().await?;
+            self.balance_events.lock().await.balance.get_or_insert(balance);
+        }
+        Ok(())
+    }
+
+    /// Emits the events for the synced state: if the balance changed, outputs that became claimable and claimable
+    /// outputs that expire soon to their return address.
+    pub(crate) async fn emit_balance_events(&self, balance: &AccountBalance) -> crate::wallet::Result<()> {
+        let claimable_output_ids = self
+            .get_unlockable_outputs_with_additional_unlock_conditions(OutputsToClaim::All)
+            .await?;
+        let account_addresses = self.addresses().await?;
+        let local_time = self.client.get_time_checked().await?;
+
+        let account_details = self.read().await;
+        let account_index = account_details.index;
+        let events = self.balance_events.lock().await.update(
+            &account_details,
+            balance,
+            &claimable_output_ids,
+            &account_addresses,
+            local_time,
+        );
+        drop(account_details);
+
+        for event in events {
+            self.emit(account_index, event).await;
+        }
+        Ok(())
+    }
+}
+
+/// The last synced balance and the outputs for which events were emitted already.
+#[derive(Debug, Default)]
+pub(crate) struct BalanceEvents {
+    balance: Option<AccountBalance>,
+    claimable_outputs: HashSet<OutputId>,
+    expiring_outputs: HashSet<OutputId>,
+}
+
+impl BalanceEvents {
+    /// Returns the events for a newly synced balance.
+    fn update(
+        &mut self,
+        account_details: &AccountDetails,
+        balance: &AccountBalance,
+        claimable_output_ids: &[OutputId],
+        account_addresses: &[AccountAddress],
+        local_time: u32,
+    ) -> Vec<WalletEvent> {
+        let mut events = Vec::new();
+        let is_account_address = |address: &Address| account_addresses.iter().any(|a| a.address.inner == *address);
+
+        // Forget outputs that got spent
+        self.claimable_outputs
+            .retain(|output_id| account_details.unspent_outputs.contains_key(output_id));
+        self.expiring_outputs
+            .retain(|output_id| account_details.unspent_outputs.contains_key(output_id));
+
+        for output_id in claimable_output_ids {
+            let Some(output_data) = account_details.unspent_outputs.get(output_id) else {
+                continue;
+            };
+            let Some(unlock_conditions) = output_data.output.unlock_conditions() else {
+                continue;
+            };
+            let expiration = unlock_conditions.expiration();
+
+            // Claimable because the timelock or the expiration passed
+            let time_condition_passed = unlock_conditions.timelock().is_some()
+                || expiration.is_some_and(|expiration| is_account_address(expiration.return_address()));
+            if time_condition_passed && self.claimable_outputs.insert(*output_id) {
+                events.push(WalletEvent::OutputClaimable(Box::new(OutputClaimableEvent {
+                    output: OutputDataDto::from(output_data),
+                })));
+            }
+
+            if let Some(expiration) = expiration {
+                if !is_account_address(expiration.return_address())
+                    && expiration.timestamp().saturating_sub(local_time) <= OUTPUT_EXPIRING_EVENT_TIME
+                    && self.expiring_outputs.insert(*output_id)
+                {
+                    events.push(WalletEvent::OutputExpiring(Box::new(OutputExpiringEvent {
+                        output: OutputDataDto::from(output_data),
+                        expiration_time: expiration.timestamp(),
+                    })));
+                }
+            }
+        }
+
+        if let Some(previous_balance) = self.balance.replace(balance.clone()) {
+            if &previous_balance != balance {
+                events.push(WalletEvent::BalanceChanged(Box::new(BalanceChangedEvent {
+                    previous_balance: AccountBalanceDto::from(&previous_balance),
+                    balance: AccountBalanceDto::from(balance),
+                })));
+            }
+        }
+
+        events
+    }
+}
@@ -2,7 +2,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
 pub(crate) mod addresses;
+#[cfg(feature = "events")]
+pub(crate) mod events;
 pub(crate) mod foundries;
+#[cfg(feature = "mqtt")]
+pub(crate) mod mqtt;
 pub(crate) mod options;
 pub(crate) mod outputs;
 pub(crate) mod transactions;
@@ -67,6 +71,9 @@ impl Account {
             return self.balance().await;
         }
 
+        #[cfg(feature = "events")]
+        self.init_balance_events().await?;
+
         self.sync_internal(&options).await?;
 
         // Sync transactions after updating account with outputs, so we can use them to check the transaction
@@ -80,11 +87,31 @@ impl Account {
             }
         };
 
+        // Reclaim expired refundable payments, after the outputs are synced so they are known as unspent outputs.
+        // Syncing shouldn't fail because of the payments, they're handled again on the next sync
+        if let Err(err) = self.sync_refundable_payments().await {
+            log::warn!("[SYNC] syncing refundable payments failed: {err}");
+        }
+
         let account_balance = self.balance().await?;
+        #[cfg(feature = "events")]
+        self.emit_balance_events(&account_balance).await?;
         // Update last_synced mutex
         let time_now = crate::utils::unix_timestamp_now().as_millis();
         *last_synced = time_now;
+        drop(last_synced);
         log::debug!("[SYNC] finished syncing in {:.2?}", syc_start_time.elapsed());
+
+        // Send the queued payments that waited long enough, after the lock is released, so other syncs don't wait for
+        // the transactions
+        if options.flush_payment_queue {
+            match self.flush_due_payment_queue().await {
+                Ok(transactions) if !transactions.is_empty() => return self.balance().await,
+                Ok(_) => {}
+                Err(err) => log::warn!("[SYNC] flushing the payment queue failed: {err}"),
+            }
+        }
+
         Ok(account_balance)
     }
 
@@ -0,0 +1,223 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use crate::{
+    client::node_api::mqtt::Topic,
+    types::{api::core::response::OutputWithMetadataResponse, block::payload::transaction::TransactionId},
+    wallet::account::{operations::syncing::SyncOptions, types::AddressWithUnspentOutputs, Account},
+};
+
+impl Account {
+    /// Returns the MQTT topics with which the outputs of all account addresses and the inclusion of the pending
+    /// transactions can be followed.
+    pub(crate) async fn mqtt_syncing_topics(&self) -> crate::wallet::Result<Vec<Topic>> {
+        let account_details = self.read().await;
+        let mut topics = Vec::new();
+
+        for address in account_details
+            .public_addresses
+            .iter()
+            .chain(account_details.internal_addresses.iter())
+        {
+            topics.push(Topic::try_new(format!("outputs/unlock/address/{}", address.address))?);
+            topics.push(Topic::try_new(format!(
+                "outputs/unlock/address/{}/spent",
+                address.address
+            ))?);
+        }
+        for transaction_id in &account_details.pending_transactions {
+            topics.push(Topic::try_new(format!("transactions/{transaction_id}/included-block"))?);
+        }
+
+        Ok(topics)
+    }
+
+    /// Returns true if the transaction is pending for this account.
+    pub(crate) async fn is_pending_transaction(&self, transaction_id: &TransactionId) -> bool {
+        self.read().await.pending_transactions.contains(transaction_id)
+    }
+
+    /// Update the account with an output that got created or spent on an address of the account, as received from
+    /// MQTT. Returns false if the address doesn't belong to the account.
+    pub(crate) async fn update_account_with_output_response(
+        &self,
+        bech32_address: &str,
+        output_response: OutputWithMetadataResponse,
+        options: &SyncOptions,
+    ) -> crate::wallet::Result<bool> {
+        log::debug!("[SYNC] update account with output from {bech32_address}");
+
+        let output_id = output_response.metadata.output_id()?;
+
+        let (mut addresses_with_unspent_outputs, associated_address) = {
+            let account_details = self.read().await;
+            let Some(address) = account_details
+                .public_addresses
+                .iter()
+                .chain(account_details.internal_addresses.iter())
+                .find(|a| a.address.to_string() == bech32_address)
+            else {
+                return Ok(false);
+            };
+            (
+                account_details.addresses_with_unspent_outputs.clone(),
+                AddressWithUnspentOutputs {
+                    address: address.address.clone(),
+                    key_index: address.key_index,
+                    internal: address.internal,
+                    output_ids: Vec::new(),
+                },
+            )
+        };
+
+        let position = addresses_with_unspent_outputs
+            .iter()
+            .position(|a| a.address == associated_address.address)
+            .unwrap_or_else(|| {
+                addresses_with_unspent_outputs.push(associated_address.clone());
+                addresses_with_unspent_outputs.len() - 1
+            });
+
+        let mut unspent_outputs = Vec::new();
+        let mut spent_output_metadata_map = HashMap::new();
+
+        if output_response.metadata.is_spent {
+            addresses_with_unspent_outputs[position]
+                .output_ids
+                .retain(|id| id != &output_id);
+            spent_output_metadata_map.insert(output_id, Some(output_response.metadata));
+        } else {
+            if !addresses_with_unspent_outputs[position].output_ids.contains(&output_id) {
+                addresses_with_unspent_outputs[position].output_ids.push(output_id);
+            }
+            unspent_outputs = self
+                .output_response_to_output_data(vec![output_response], &associated_address)
+                .await?;
+            if options.sync_incoming_transactions {
+                self.request_incoming_transaction_data(vec![*output_id.transaction_id()])
+                    .await?;
+            }
+        }
+        addresses_with_unspent_outputs.retain(|a| !a.output_ids.is_empty());
+
+        // All addresses with unspent outputs are provided, so none of the existing ones should be kept
+        let options = SyncOptions {
+            address_start_index: 0,
+            address_start_index_internal: 0,
+            ..options.clone()
+        };
+        self.update_account(
+            addresses_with_unspent_outputs,
+            unspent_outputs,
+            spent_output_metadata_map,
+            &options,
+        )
+        .await?;
+
+        Ok(true)
+    }
+}
+
+#[cfg(all(test, feature = "test-utils"))]
+mod tests {
+    use super::*;
+    use crate::{
+        client::{
+            constants::SHIMMER_COIN_TYPE,
+            mock_node::MockNode,
+            secret::{mnemonic::MnemonicSecretManager, SecretManager},
+            Client,
+        },
+        types::block::{
+            address::{Address, Ed25519Address},
+            output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+        },
+        wallet::{ClientOptions, Wallet},
+    };
+
+    #[tokio::test]
+    async fn update_account_with_mock_node_outputs() -> crate::wallet::Result<()> {
+        let storage_path = "test-storage/update_account_with_mock_node_outputs";
+        std::fs::remove_dir_all(storage_path).ok();
+
+        let node = MockNode::builder().finish()?;
+        let secret_manager = MnemonicSecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+        #[allow(unused_mut)]
+        let mut wallet_builder = Wallet::builder()
+            .with_secret_manager(SecretManager::Mnemonic(secret_manager))
+            .with_client_options(ClientOptions::new().with_node(node.url().as_str())?)
+            .with_coin_type(SHIMMER_COIN_TYPE);
+        #[cfg(feature = "storage")]
+        {
+            wallet_builder = wallet_builder.with_storage_path(storage_path);
+        }
+        let wallet = wallet_builder.finish().await?;
+        let account = wallet.create_account().finish().await?;
+        let address = account.addresses().await?[0].address().clone();
+        let options = SyncOptions::default();
+
+        let output_id = node.add_outputs(vec![BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(*address.inner()))
+            .finish_output(node.protocol_parameters().token_supply())?])?[0];
+
+        // Outputs on addresses of other accounts are ignored
+        let other_address = Address::Ed25519(Ed25519Address::new([0; 32])).to_bech32(address.hrp());
+        let output_response = account.client().get_output(&output_id).await?;
+        assert!(
+            !account
+                .update_account_with_output_response(&other_address, output_response.clone(), &options)
+                .await?
+        );
+        assert!(account.unspent_outputs(None).await?.is_empty());
+
+        assert!(
+            account
+                .update_account_with_output_response(&address.to_string(), output_response, &options)
+                .await?
+        );
+        let unspent_outputs = account.unspent_outputs(None).await?;
+        assert_eq!(unspent_outputs.len(), 1);
+        assert_eq!(unspent_outputs[0].output_id, output_id);
+        assert_eq!(
+            account.read().await.addresses_with_unspent_outputs[0].output_ids,
+            [output_id]
+        );
+
+        // Spend the output and apply the spent output as received from MQTT
+        account
+            .send(
+                vec![BasicOutputBuilder::new_with_amount(1_000_000)
+                    .add_unlock_condition(AddressUnlockCondition::new(*address.inner()))
+                    .finish_output(node.protocol_parameters().token_supply())?],
+                None,
+            )
+            .await?;
+        node.issue_milestone();
+
+        let output_response = account.client().get_output(&output_id).await?;
+        assert!(output_response.metadata.is_spent);
+        assert!(
+            account
+                .update_account_with_output_response(&address.to_string(), output_response, &options)
+                .await?
+        );
+        assert!(account.get_output(&output_id).await.unwrap().unwrap().is_spent);
+        assert!(account
+            .unspent_outputs(None)
+            .await?
+            .iter()
+            .all(|output_data| output_data.output_id != output_id));
+        assert!(account
+            .read()
+            .await
+            .addresses_with_unspent_outputs
+            .iter()
+            .all(|address| !address.output_ids.contains(&output_id)));
+
+        std::fs::remove_dir_all(storage_path).ok();
+
+        Ok(())
+    }
+}
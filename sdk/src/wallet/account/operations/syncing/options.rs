@@ -9,6 +9,7 @@ const DEFAULT_SYNC_INCOMING_TRANSACTIONS: bool = false;
 const DEFAULT_SYNC_ONLY_MOST_BASIC_OUTPUTS: bool = false;
 const DEFAULT_SYNC_PENDING_TRANSACTIONS: bool = true;
 const DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES: bool = false;
+const DEFAULT_FLUSH_PAYMENT_QUEUE: bool = false;
 
 /// The synchronization options
 #[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
@@ -54,6 +55,10 @@ pub struct SyncOptions {
     /// Sync native token foundries, so their metadata can be returned in the balance.
     #[serde(default = "default_sync_native_token_foundries")]
     pub sync_native_token_foundries: bool,
+    /// Send the queued payments after syncing if the payment queue waited for its max delay. Needed on wasm, where
+    /// there is no timer to flush the queue.
+    #[serde(default = "default_flush_payment_queue")]
+    pub flush_payment_queue: bool,
 }
 
 fn default_address_start_index() -> u32 {
@@ -80,6 +85,10 @@ fn default_sync_native_token_foundries() -> bool {
     DEFAULT_SYNC_NATIVE_TOKEN_FOUNDRIES
 }
 
+fn default_flush_payment_queue() -> bool {
+    DEFAULT_FLUSH_PAYMENT_QUEUE
+}
+
 impl Default for SyncOptions {
     fn default() -> Self {
         Self {
@@ -94,6 +103,7 @@ impl Default for SyncOptions {
             sync_only_most_basic_outputs: default_sync_only_most_basic_outputs(),
             sync_native_token_foundries: default_sync_native_token_foundries(),
             force_syncing: default_force_syncing(),
+            flush_payment_queue: default_flush_payment_queue(),
         }
     }
 }
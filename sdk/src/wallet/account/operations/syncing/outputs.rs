@@ -50,13 +50,16 @@ impl Account {
                 .map_or(false, |tx| !tx.incoming);
 
             // 44 is for BIP 44 (HD wallets) and 4218 is the registered index for IOTA https://github.com/satoshilabs/slips/blob/master/slip-0044.md
-            let chain = Chain::from_u32_hardened(vec![
-                44,
-                account_details.coin_type,
-                account_details.index,
-                associated_address.internal as u32,
-                associated_address.key_index,
-            ]);
+            // Watch-only accounts don't know from which seed and path their addresses are derived
+            let chain = (!account_details.watch_only).then(|| {
+                Chain::from_u32_hardened(vec![
+                    44,
+                    account_details.coin_type,
+                    account_details.index,
+                    associated_address.internal as u32,
+                    associated_address.key_index,
+                ])
+            });
 
             outputs.push(OutputData {
                 output_id: OutputId::new(transaction_id, output_response.metadata.output_index)?,
@@ -66,7 +69,7 @@ impl Account {
                 address: associated_address.address.inner,
                 network_id,
                 remainder,
-                chain: Some(chain),
+                chain,
             });
         }
 
@@ -133,8 +136,15 @@ impl Account {
         for transaction_ids_chunk in transaction_ids.chunks(100).map(|x: &[TransactionId]| x.to_vec()) {
             let mut tasks = Vec::new();
             let account_details = self.read().await;
+            // Historical transactions aren't loaded, but are known to the storage
+            #[cfg(feature = "storage")]
+            let storage_manager = self.storage_manager.lock().await;
 
             for transaction_id in transaction_ids_chunk {
+                #[cfg(feature = "storage")]
+                if storage_manager.contains_transaction(account_details.index, &transaction_id) {
+                    continue;
+                }
                 // Don't request known or inaccessible transactions again
                 if account_details.transactions.contains_key(&transaction_id)
                     || account_details.incoming_transactions.contains_key(&transaction_id)
@@ -173,6 +183,8 @@ impl Account {
                 });
             }
 
+            #[cfg(feature = "storage")]
+            drop(storage_manager);
             drop(account_details);
 
             let results = futures::future::try_join_all(tasks).await?;
@@ -9,8 +9,11 @@ use crate::{
         address::AliasAddress,
         dto::U256Dto,
         output::{
-            dto::AliasIdDto, feature::MetadataFeature, unlock_condition::ImmutableAliasAddressUnlockCondition, AliasId,
-            AliasOutputBuilder, FoundryId, FoundryOutputBuilder, Output, SimpleTokenScheme, TokenId, TokenScheme,
+            dto::AliasIdDto,
+            feature::{Irc30Metadata, MetadataFeature},
+            unlock_condition::ImmutableAliasAddressUnlockCondition,
+            AliasId, AliasOutputBuilder, FoundryId, FoundryOutputBuilder, Output, SimpleTokenScheme, TokenId,
+            TokenScheme,
         },
         Error,
     },
@@ -34,6 +37,14 @@ pub struct NativeTokenOptions {
     pub foundry_metadata: Option<Vec<u8>>,
 }
 
+impl NativeTokenOptions {
+    /// Sets the foundry metadata to the TIP-30 metadata, which is validated and has to fit into a metadata feature.
+    pub fn with_irc_30_metadata(mut self, metadata: &Irc30Metadata) -> crate::wallet::Result<Self> {
+        self.foundry_metadata = Some(MetadataFeature::try_from(metadata)?.data().to_vec());
+        Ok(self)
+    }
+}
+
 /// Dto for NativeTokenOptions
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -4,13 +4,12 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    client::api::PreparedTransactionData,
     types::block::{
         address::Address,
         output::{
-            feature::{IssuerFeature, MetadataFeature, SenderFeature, TagFeature},
+            feature::{Irc27Metadata, IssuerFeature, MetadataFeature, SenderFeature, TagFeature},
             unlock_condition::AddressUnlockCondition,
-            NftId, NftOutputBuilder,
+            NftId, NftOutputBuilder, Output,
         },
         Error as BlockError,
     },
@@ -21,7 +20,7 @@ use crate::{
 };
 
 /// Address and NFT for `send_nft()`.
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NftOptions {
     /// Bech32 encoded address to which the NFT will be minted. Default will use the
@@ -39,6 +38,14 @@ pub struct NftOptions {
     pub immutable_metadata: Option<Vec<u8>>,
 }
 
+impl NftOptions {
+    /// Sets the immutable metadata to the TIP-27 metadata, which is validated and has to fit into a metadata feature.
+    pub fn with_irc_27_metadata(mut self, metadata: &Irc27Metadata) -> crate::wallet::Result<Self> {
+        self.immutable_metadata = Some(MetadataFeature::try_from(metadata)?.data().to_vec());
+        Ok(self)
+    }
+}
+
 /// Dto for NftOptions.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -86,8 +93,9 @@ impl TryFrom<&NftOptionsDto> for NftOptions {
 
 impl Account {
     /// Function to mint nfts.
-    /// Calls [Account.send()](crate::account::Account.send) internally, the options can define the
-    /// RemainderValueStrategy or custom inputs.
+    /// Calls [Account.send_chunked()](crate::account::Account.send_chunked) internally, the options can define the
+    /// RemainderValueStrategy or custom inputs. If the NFTs don't fit into a single transaction, they're minted in
+    /// multiple ones, so all created transactions are returned.
     /// Address needs to be Bech32 encoded
     /// ```ignore
     /// let nft_id: [u8; 38] =
@@ -103,30 +111,28 @@ impl Account {
     ///     immutable_metadata: Some(b"some immutable nft metadata".to_vec()),
     /// }];
     ///
-    /// let transaction = account.mint_nfts(nft_options, None).await?;
-    /// println!(
-    ///     "Transaction sent: {}/transaction/{}",
-    ///     std::env::var("EXPLORER_URL").unwrap(),
-    ///     transaction.transaction_id,
-    /// );
+    /// for transaction in account.mint_nfts(nft_options, None).await? {
+    ///     println!(
+    ///         "Transaction sent: {}/transaction/{}",
+    ///         std::env::var("EXPLORER_URL").unwrap(),
+    ///         transaction.transaction_id,
+    ///     );
+    /// }
     /// ```
     pub async fn mint_nfts(
         &self,
         nfts_options: Vec<NftOptions>,
         options: Option<TransactionOptions>,
-    ) -> crate::wallet::Result<Transaction> {
-        let prepared_transaction = self.prepare_mint_nfts(nfts_options, options).await?;
-        self.sign_and_submit_transaction(prepared_transaction).await
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] mint_nfts");
+        let outputs = self.nft_options_to_outputs(nfts_options).await?;
+        self.send_chunked(outputs, options).await
     }
 
-    /// Function to prepare the transaction for
-    /// [Account.mint_nfts()](crate::account::Account.mint_nfts)
-    async fn prepare_mint_nfts(
+    pub(crate) async fn nft_options_to_outputs(
         &self,
         nfts_options: Vec<NftOptions>,
-        options: Option<TransactionOptions>,
-    ) -> crate::wallet::Result<PreparedTransactionData> {
-        log::debug!("[TRANSACTION] prepare_mint_nfts");
+    ) -> crate::wallet::Result<Vec<Output>> {
         let rent_structure = self.client.get_rent_structure().await?;
         let token_supply = self.client.get_token_supply().await?;
         let account_addresses = self.addresses().await?;
@@ -177,6 +183,6 @@ impl Account {
             outputs.push(nft_builder.finish_output(token_supply)?);
         }
 
-        self.prepare_transaction(outputs, options).await
+        Ok(outputs)
     }
 }
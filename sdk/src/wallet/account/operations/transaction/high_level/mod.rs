@@ -5,6 +5,11 @@ pub(crate) mod burning_melting;
 pub(crate) mod create_alias;
 pub(crate) mod minimum_storage_deposit;
 pub(crate) mod minting;
+pub(crate) mod nft_collection;
+pub(crate) mod payment_queue;
+pub(crate) mod refundable_payment;
 pub(crate) mod send_amount;
 pub(crate) mod send_native_tokens;
 pub(crate) mod send_nft;
+pub(crate) mod update_alias;
+pub(crate) mod vesting;
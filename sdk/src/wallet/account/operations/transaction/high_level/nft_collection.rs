@@ -0,0 +1,120 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{BTreeMap, HashSet};
+
+use crate::{
+    types::block::{
+        address::{Address, NftAddress},
+        output::{NftId, Output, OUTPUT_COUNT_MAX},
+    },
+    wallet::{
+        account::{types::Transaction, Account, NftOptions, TransactionOptions},
+        Error,
+    },
+};
+
+impl Account {
+    /// Mint NFTs of a collection, which is the NFT that issues them. The issuer of every NFT is set to the collection
+    /// NFT, which needs to be an unspent output of the account. The collection NFT is an input of every minting
+    /// transaction, so the NFTs are minted in as few transactions as the output count and the transaction size allow,
+    /// each transaction after the first waiting for the inclusion of the previous one.
+    /// ```ignore
+    /// let nfts_options = (0..100)
+    ///     .map(|index| NftOptions {
+    ///         address: None,
+    ///         sender: None,
+    ///         metadata: None,
+    ///         tag: None,
+    ///         issuer: None,
+    ///         immutable_metadata: Some(format!("collection NFT #{index}").into_bytes()),
+    ///     })
+    ///     .collect();
+    ///
+    /// let transactions = account
+    ///     .mint_collection_nfts(collection_nft_id, nfts_options, None)
+    ///     .await?;
+    /// ```
+    pub async fn mint_collection_nfts(
+        &self,
+        collection_nft_id: NftId,
+        nfts_options: Vec<NftOptions>,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] mint_collection_nfts");
+        let collection_nft_in_account = self.read().await.unspent_outputs().values().any(|output_data| {
+            matches!(&output_data.output, Output::Nft(nft_output)
+                if nft_output.nft_id_non_null(&output_data.output_id) == collection_nft_id)
+        });
+        if !collection_nft_in_account {
+            return Err(Error::NftNotFoundInUnspentOutputs);
+        }
+
+        let bech32_hrp = self.client.get_bech32_hrp().await?;
+        let issuer = Address::Nft(NftAddress::new(collection_nft_id)).to_bech32(bech32_hrp);
+        let nfts_options = nfts_options
+            .into_iter()
+            .map(|nft_options| NftOptions {
+                issuer: Some(issuer.clone()),
+                ..nft_options
+            })
+            .collect();
+        let outputs = self.nft_options_to_outputs(nfts_options).await?;
+
+        // Leave space for the collection NFT and a remainder output
+        self.send_in_chunks(outputs, OUTPUT_COUNT_MAX as usize - 2, options)
+            .await
+    }
+
+    /// Check if the issuer chain of an NFT links back to a collection NFT, that is if the NFT was issued by the
+    /// collection NFT or by an NFT which is part of the collection itself. The NFTs of the chain are requested from
+    /// the node, so the NFT and the issuers between it and the collection NFT need to be unspent.
+    pub async fn verify_nft_collection(&self, nft_id: NftId, collection_nft_id: NftId) -> crate::wallet::Result<bool> {
+        log::debug!("[verify_nft_collection]");
+        let token_supply = self.client.get_token_supply().await?;
+        let mut current_nft_id = nft_id;
+        let mut visited_nft_ids = HashSet::new();
+
+        while visited_nft_ids.insert(current_nft_id) {
+            let output_id = self.client.nft_output_id(current_nft_id).await?;
+            let output = Output::try_from_dto(&self.client.get_output(&output_id).await?.output, token_supply)?;
+
+            match output
+                .immutable_features()
+                .and_then(|features| features.issuer())
+                .map(|issuer| issuer.address())
+            {
+                Some(Address::Nft(nft_address)) if nft_address.nft_id() == &collection_nft_id => return Ok(true),
+                Some(Address::Nft(nft_address)) => current_nft_id = *nft_address.nft_id(),
+                // Issued by another address type or not issued at all
+                _ => return Ok(false),
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Get the ids of the unspent NFTs of the account grouped by the NFT that issued them. NFTs that aren't issued by
+    /// an NFT aren't part of a collection and are left out.
+    pub async fn nfts_by_collection(&self) -> BTreeMap<NftId, Vec<NftId>> {
+        let mut collections = BTreeMap::<NftId, Vec<NftId>>::new();
+
+        for output_data in self.read().await.unspent_outputs().values() {
+            if let Output::Nft(nft_output) = &output_data.output {
+                if let Some(Address::Nft(issuer)) =
+                    nft_output.immutable_features().issuer().map(|issuer| issuer.address())
+                {
+                    collections
+                        .entry(*issuer.nft_id())
+                        .or_default()
+                        .push(nft_output.nft_id_non_null(&output_data.output_id));
+                }
+            }
+        }
+        for nft_ids in collections.values_mut() {
+            nft_ids.sort_unstable();
+        }
+
+        collections
+    }
+}
@@ -0,0 +1,482 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::BTreeMap, time::Duration};
+
+use instant::Instant;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        output::{Output, OUTPUT_COUNT_MAX},
+        payload::transaction::TransactionId,
+    },
+    wallet::{
+        account::{
+            constants::{DEFAULT_PAYMENT_QUEUE_MAX_DELAY, PAYMENT_STATUSES_MAX},
+            operations::transaction::{
+                high_level::send_amount::AddressWithAmount, send_chunked::exceeds_transaction_limits,
+            },
+            types::Transaction,
+            Account, OutputOptions, TransactionOptions,
+        },
+        Error,
+    },
+};
+
+/// A payment that can be queued with [`Account::queue_payment()`]
+#[derive(Debug, Clone)]
+pub enum Payment {
+    /// A payment like with [`Account::send_amount()`]
+    Amount(AddressWithAmount),
+    /// A payment like with [`Account::prepare_output()`]
+    Output(OutputOptions),
+}
+
+impl From<AddressWithAmount> for Payment {
+    fn from(address_with_amount: AddressWithAmount) -> Self {
+        Self::Amount(address_with_amount)
+    }
+}
+
+impl From<OutputOptions> for Payment {
+    fn from(output_options: OutputOptions) -> Self {
+        Self::Output(output_options)
+    }
+}
+
+/// The status of a queued payment
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum PaymentStatus {
+    /// The payment waits for the queue to be flushed
+    Queued,
+    /// The payment was sent in the transaction
+    #[serde(rename_all = "camelCase")]
+    Sent { transaction_id: TransactionId },
+    /// Sending the payment failed
+    Failed { error: String },
+}
+
+/// Options for the payment queue of an account
+#[derive(Debug, Clone)]
+pub struct PaymentQueueOptions {
+    /// The queue is flushed once this many payments are queued, at most one less than the max output count of a
+    /// transaction, so there is space for a remainder output.
+    pub max_payments: usize,
+    /// The queue is flushed once the first queued payment waits for this long.
+    pub max_delay: Duration,
+    /// The options for the transactions created when flushing the queue.
+    pub transaction_options: Option<TransactionOptions>,
+}
+
+impl Default for PaymentQueueOptions {
+    fn default() -> Self {
+        Self {
+            max_payments: OUTPUT_COUNT_MAX as usize - 1,
+            max_delay: DEFAULT_PAYMENT_QUEUE_MAX_DELAY,
+            transaction_options: None,
+        }
+    }
+}
+
+/// The queued payments of an account and the status of the payments queued before, stored with the account.
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PaymentQueue {
+    #[serde(skip)]
+    options: PaymentQueueOptions,
+    next_payment_id: u64,
+    // payment ids with the output for them, in the order they were queued
+    payments: Vec<(u64, Output)>,
+    // payment ids with the output for them and the time in milliseconds they were taken from the queue, of the
+    // payments that are being sent
+    #[serde(default)]
+    in_flight: Vec<(u64, Output, u128)>,
+    // the time the first of the currently queued payments was queued
+    #[serde(skip)]
+    first_queued_at: Option<Instant>,
+    // the statuses of the queued payments and of the last `PAYMENT_STATUSES_MAX` sent or failed payments
+    statuses: BTreeMap<u64, PaymentStatus>,
+}
+
+impl PaymentQueue {
+    /// Restores a stored payment queue. Payments that were still queued wait for the max delay again. Payments that
+    /// were being sent when the wallet was closed are looked up in the stored transactions of the account: if a
+    /// transaction sent after they were taken from the queue has their output, they are marked as sent with it,
+    /// otherwise they were never submitted and are queued again.
+    #[cfg(feature = "storage")]
+    pub(crate) fn restore(mut self, transactions: &[Transaction]) -> Self {
+        use crate::types::block::payload::transaction::TransactionEssence;
+
+        for (payment_id, output, taken_at) in std::mem::take(&mut self.in_flight) {
+            let transaction_id = transactions
+                .iter()
+                .filter(|transaction| !transaction.incoming && transaction.timestamp >= taken_at)
+                .find(|transaction| {
+                    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+                    essence.outputs().contains(&output)
+                })
+                .map(|transaction| transaction.transaction_id);
+            match transaction_id {
+                Some(transaction_id) => {
+                    self.statuses.insert(payment_id, PaymentStatus::Sent { transaction_id });
+                }
+                None => self.payments.push((payment_id, output)),
+            }
+        }
+        // Keep the payments in the order they were queued
+        self.payments.sort_by_key(|(payment_id, _)| *payment_id);
+
+        if !self.payments.is_empty() {
+            self.first_queued_at = Some(Instant::now());
+        }
+        // Queues stored before in flight payments were stored don't have them anymore, so it's unknown if they were
+        // sent
+        for (payment_id, status) in self.statuses.iter_mut() {
+            if *status == PaymentStatus::Queued && !self.payments.iter().any(|(id, _)| id == payment_id) {
+                *status = PaymentStatus::Failed {
+                    error: "the wallet was closed while sending the payment".to_string(),
+                };
+            }
+        }
+        self
+    }
+
+    #[cfg(feature = "storage")]
+    pub(crate) fn has_in_flight_payments(&self) -> bool {
+        !self.in_flight.is_empty()
+    }
+
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn has_queued_payments(&self) -> bool {
+        !self.payments.is_empty()
+    }
+
+    fn is_due(&self) -> bool {
+        self.payments.len() >= self.max_payments()
+            || self
+                .first_queued_at
+                .is_some_and(|queued_at| queued_at.elapsed() >= self.options.max_delay)
+    }
+
+    fn max_payments(&self) -> usize {
+        self.options.max_payments.clamp(1, OUTPUT_COUNT_MAX as usize - 1)
+    }
+
+    // Removes the oldest statuses of sent or failed payments, so at most `PAYMENT_STATUSES_MAX` of them are kept
+    fn prune_statuses(&mut self) {
+        let finished = self
+            .statuses
+            .values()
+            .filter(|status| **status != PaymentStatus::Queued)
+            .count();
+        let mut to_remove = finished.saturating_sub(PAYMENT_STATUSES_MAX);
+        // Payment ids are increasing, so the oldest statuses come first
+        self.statuses.retain(|_, status| {
+            if to_remove > 0 && *status != PaymentStatus::Queued {
+                to_remove -= 1;
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
+
+impl Account {
+    /// Set the options of the payment queue. They are used from the next flush on.
+    pub async fn set_payment_queue_options(&self, options: PaymentQueueOptions) {
+        self.payment_queue.lock().await.options = options;
+    }
+
+    /// Queue a payment, so it gets sent together with other queued payments in a shared transaction, instead of one
+    /// transaction per payment. The queue is flushed when it reaches
+    /// [`PaymentQueueOptions::max_payments`] or the first payment waited for [`PaymentQueueOptions::max_delay`], and
+    /// can be flushed manually with [`Account::flush_payment_queue()`]. On wasm, where there is no timer, a queue that
+    /// waited for the max delay is flushed by the next queued payment instead, or by [`Account::sync()`] with
+    /// [`SyncOptions::flush_payment_queue`](crate::wallet::account::SyncOptions::flush_payment_queue). The output for
+    /// the payment is created right away, so invalid payments are rejected here. Returns the id with which the status of the payment can be requested from
+    /// [`Account::payment_status()`].
+    /// ```ignore
+    /// let payment_id = account
+    ///     .queue_payment(AddressWithAmount::new(
+    ///         "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu".to_string(),
+    ///         1_000_000,
+    ///     ))
+    ///     .await?;
+    /// account.flush_payment_queue().await?;
+    /// println!("{:?}", account.payment_status(payment_id).await);
+    /// ```
+    pub async fn queue_payment(&self, payment: impl Into<Payment> + Send) -> crate::wallet::Result<u64> {
+        log::debug!("[TRANSACTION] queue_payment");
+        let transaction_options = self.payment_queue.lock().await.options.transaction_options.clone();
+        let output = match payment.into() {
+            Payment::Amount(address_with_amount) => {
+                let default_return_address = self
+                    .public_addresses()
+                    .await
+                    .first()
+                    .ok_or(Error::FailedToGetRemainder)?
+                    .address
+                    .inner;
+                let local_time = self.client.get_time_checked().await?;
+                self.address_with_amount_to_output(
+                    address_with_amount,
+                    default_return_address,
+                    local_time,
+                    transaction_options.as_ref(),
+                )
+                .await?
+            }
+            Payment::Output(output_options) => self.prepare_output(output_options, transaction_options).await?,
+        };
+
+        let mut payment_queue = self.payment_queue.lock().await;
+        let payment_id = payment_queue.next_payment_id;
+        payment_queue.next_payment_id += 1;
+        payment_queue.payments.push((payment_id, output));
+        payment_queue.statuses.insert(payment_id, PaymentStatus::Queued);
+        #[cfg(feature = "storage")]
+        self.save_payment_queue(&payment_queue).await?;
+
+        if payment_queue.first_queued_at.is_none() {
+            payment_queue.first_queued_at = Some(Instant::now());
+            #[cfg(not(target_family = "wasm"))]
+            self.spawn_payment_queue_timer();
+        }
+
+        let is_due = payment_queue.is_due();
+        drop(payment_queue);
+        if is_due {
+            self.flush_payment_queue().await?;
+        }
+
+        Ok(payment_id)
+    }
+
+    /// Send all queued payments, in as few transactions as possible. If a transaction can't be sent, its payments are
+    /// marked as failed with the error and the remaining payments are still sent. Returns the created transactions.
+    pub async fn flush_payment_queue(&self) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] flush_payment_queue");
+        let (payments, max_payments, transaction_options) = {
+            let mut payment_queue = self.payment_queue.lock().await;
+            let payments = std::mem::take(&mut payment_queue.payments);
+            // Store the payments as in flight before sending them, so they aren't sent again if the wallet is closed
+            // while sending
+            let taken_at = crate::utils::unix_timestamp_now().as_millis();
+            payment_queue.in_flight.extend(
+                payments
+                    .iter()
+                    .map(|(payment_id, output)| (*payment_id, output.clone(), taken_at)),
+            );
+            #[cfg(feature = "storage")]
+            if let Err(err) = self.save_payment_queue(&payment_queue).await {
+                payment_queue
+                    .in_flight
+                    .retain(|(payment_id, _, _)| !payments.iter().any(|(id, _)| id == payment_id));
+                payment_queue.payments.splice(0..0, payments);
+                return Err(err);
+            }
+            payment_queue.first_queued_at = None;
+            (
+                payments,
+                payment_queue.max_payments(),
+                payment_queue.options.transaction_options.clone(),
+            )
+        };
+
+        let mut batches = payments
+            .chunks(max_payments)
+            .rev()
+            .map(|batch| batch.to_vec())
+            .collect::<Vec<_>>();
+        let mut transactions = Vec::new();
+
+        while let Some(batch) = batches.pop() {
+            let outputs = batch.iter().map(|(_, output)| output.clone()).collect();
+            let result = self.send(outputs, transaction_options.clone()).await;
+
+            let status = match result {
+                Ok(transaction) => {
+                    let status = PaymentStatus::Sent {
+                        transaction_id: transaction.transaction_id,
+                    };
+                    transactions.push(transaction);
+                    status
+                }
+                // Too many inputs or outputs are needed, so send the batch in two transactions instead
+                Err(err) if batch.len() > 1 && exceeds_transaction_limits(&err) => {
+                    let (first, second) = batch.split_at(batch.len() / 2);
+                    batches.push(second.to_vec());
+                    batches.push(first.to_vec());
+                    continue;
+                }
+                Err(err) => {
+                    log::debug!("[TRANSACTION] sending queued payments failed: {err}");
+                    PaymentStatus::Failed { error: err.to_string() }
+                }
+            };
+
+            let mut payment_queue = self.payment_queue.lock().await;
+            payment_queue
+                .in_flight
+                .retain(|(payment_id, _, _)| !batch.iter().any(|(id, _)| id == payment_id));
+            for (payment_id, _) in batch {
+                payment_queue.statuses.insert(payment_id, status.clone());
+            }
+            payment_queue.prune_statuses();
+            #[cfg(feature = "storage")]
+            self.save_payment_queue(&payment_queue).await?;
+        }
+
+        Ok(transactions)
+    }
+
+    /// Flush the payment queue if it reached the max payments or the first payment waited for the max delay. Returns
+    /// the created transactions.
+    pub(crate) async fn flush_due_payment_queue(&self) -> crate::wallet::Result<Vec<Transaction>> {
+        if self.payment_queue.lock().await.is_due() {
+            return self.flush_payment_queue().await;
+        }
+        Ok(Vec::new())
+    }
+
+    /// Flush the payment queue once it waited for the max delay, if it wasn't flushed before.
+    #[cfg(not(target_family = "wasm"))]
+    pub(crate) fn spawn_payment_queue_timer(&self) {
+        let account = self.clone();
+        crate::wallet::task::spawn(async move {
+            let max_delay = account.payment_queue.lock().await.options.max_delay;
+            tokio::time::sleep(max_delay).await;
+            if let Err(err) = account.flush_due_payment_queue().await {
+                log::warn!("[TRANSACTION] flushing the payment queue failed: {err}");
+            }
+        });
+    }
+
+    /// Get the status of a payment queued with [`Account::queue_payment()`]. Only the statuses of the last
+    /// `PAYMENT_STATUSES_MAX` sent or failed payments are kept.
+    pub async fn payment_status(&self, payment_id: u64) -> Option<PaymentStatus> {
+        self.payment_queue.lock().await.statuses.get(&payment_id).cloned()
+    }
+
+    #[cfg(feature = "storage")]
+    async fn save_payment_queue(&self, payment_queue: &PaymentQueue) -> crate::wallet::Result<()> {
+        let account_index = self.read().await.index;
+        self.storage_manager
+            .lock()
+            .await
+            .set_payment_queue(account_index, payment_queue)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prune_payment_statuses() {
+        let mut payment_queue = PaymentQueue::default();
+        for payment_id in 0..PAYMENT_STATUSES_MAX as u64 + 10 {
+            payment_queue.statuses.insert(
+                payment_id,
+                if payment_id == 0 {
+                    PaymentStatus::Queued
+                } else {
+                    PaymentStatus::Failed { error: String::new() }
+                },
+            );
+        }
+        payment_queue.prune_statuses();
+
+        // The oldest finished statuses are removed, queued ones are kept
+        assert_eq!(payment_queue.statuses.len(), PAYMENT_STATUSES_MAX + 1);
+        assert_eq!(payment_queue.statuses.get(&0), Some(&PaymentStatus::Queued));
+        assert!(!payment_queue.statuses.contains_key(&9));
+        assert!(payment_queue.statuses.contains_key(&10));
+    }
+
+    #[cfg(feature = "storage")]
+    #[test]
+    fn restore_in_flight_payments() {
+        use crate::{
+            types::block::{
+                address::{Address, Ed25519Address},
+                input::{Input, UtxoInput},
+                output::{unlock_condition::AddressUnlockCondition, BasicOutput, InputsCommitment},
+                payload::{
+                    transaction::{RegularTransactionEssence, TransactionEssence},
+                    TransactionPayload,
+                },
+                protocol::protocol_parameters,
+                signature::{Ed25519Signature, Signature},
+                unlock::{SignatureUnlock, Unlock, Unlocks},
+            },
+            wallet::account::types::InclusionState,
+        };
+
+        let protocol_parameters = protocol_parameters();
+        let output = |amount| {
+            Output::Basic(
+                BasicOutput::build_with_amount(amount)
+                    .add_unlock_condition(AddressUnlockCondition::new(Address::from(Ed25519Address::new([1; 32]))))
+                    .finish(protocol_parameters.token_supply())
+                    .unwrap(),
+            )
+        };
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(protocol_parameters.network_id(), InputsCommitment::from([0u8; 32]))
+                .with_inputs(vec![Input::Utxo(
+                    UtxoInput::new(TransactionId::new([0; 32]), 0).unwrap(),
+                )])
+                .add_output(output(1_000_000))
+                .finish(&protocol_parameters)
+                .unwrap(),
+        );
+        let unlocks = Unlocks::new(vec![Unlock::Signature(SignatureUnlock::from(Signature::Ed25519(
+            Ed25519Signature::new([0; 32], [0; 64]),
+        )))])
+        .unwrap();
+        let transaction = Transaction {
+            payload: TransactionPayload::new(essence, unlocks).unwrap(),
+            block_id: None,
+            inclusion_state: InclusionState::Pending,
+            timestamp: 10,
+            transaction_id: TransactionId::new([1; 32]),
+            network_id: protocol_parameters.network_id(),
+            incoming: false,
+            note: None,
+            inputs: Vec::new(),
+        };
+
+        let mut payment_queue = PaymentQueue::default();
+        payment_queue.payments.push((2, output(3_000_000)));
+        for (payment_id, amount) in [(0, 1_000_000), (1, 2_000_000)] {
+            payment_queue.in_flight.push((payment_id, output(amount), 5));
+        }
+        for payment_id in 0..3 {
+            payment_queue.statuses.insert(payment_id, PaymentStatus::Queued);
+        }
+        let payment_queue = payment_queue.restore(&[transaction]);
+
+        // The payment with an output in the transaction was sent, the other one is queued again
+        assert!(payment_queue.in_flight.is_empty());
+        assert_eq!(
+            payment_queue.statuses.get(&0),
+            Some(&PaymentStatus::Sent {
+                transaction_id: TransactionId::new([1; 32])
+            })
+        );
+        assert_eq!(payment_queue.statuses.get(&1), Some(&PaymentStatus::Queued));
+        assert_eq!(
+            payment_queue
+                .payments
+                .iter()
+                .map(|(payment_id, _)| *payment_id)
+                .collect::<Vec<_>>(),
+            [1, 2]
+        );
+    }
+}
@@ -0,0 +1,405 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::{Address, Bech32Address},
+        input::Input,
+        output::{
+            unlock_condition::{AddressUnlockCondition, ExpirationUnlockCondition},
+            BasicOutputBuilder, OutputId,
+        },
+        payload::transaction::{TransactionEssence, TransactionId},
+    },
+    wallet::{
+        account::{
+            constants::REFUNDABLE_PAYMENTS_MAX,
+            types::{OutputData, Transaction},
+            Account, TransactionOptions,
+        },
+        Error,
+    },
+};
+
+/// A payment for [`Account::send_with_refund_deadline()`]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundablePaymentOptions {
+    /// Bech32 encoded address of the recipient
+    pub address: String,
+    /// Amount, which needs to cover the storage deposit of the output
+    pub amount: u64,
+    /// Unix timestamp in seconds until which the recipient can claim the payment, afterwards it returns to the
+    /// return address
+    pub deadline: u32,
+    /// Bech32 encoded address of the account to which the payment returns, defaults to the first address of the
+    /// account
+    pub return_address: Option<String>,
+}
+
+impl RefundablePaymentOptions {
+    /// Creates new [`RefundablePaymentOptions`] that return to the first address of the account.
+    pub fn new(address: impl Into<String>, amount: u64, deadline: u32) -> Self {
+        Self {
+            address: address.into(),
+            amount,
+            deadline,
+            return_address: None,
+        }
+    }
+
+    /// Sets the address of the account to which the payment returns.
+    pub fn with_return_address(mut self, return_address: impl Into<Option<String>>) -> Self {
+        self.return_address = return_address.into();
+        self
+    }
+}
+
+/// A payment sent with [`Account::send_with_refund_deadline()`], tracked until it's claimed or refunded.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RefundablePayment {
+    /// The id of the output of the payment
+    pub output_id: OutputId,
+    /// The address of the recipient
+    pub address: Bech32Address,
+    /// The amount of the payment
+    pub amount: u64,
+    /// Unix timestamp in seconds after which the payment returns to the account
+    pub deadline: u32,
+    /// The status of the payment
+    pub status: RefundablePaymentStatus,
+    /// The error of the last failed attempt to reclaim the expired payment, it's tried again on the next sync
+    #[serde(default)]
+    pub reclaim_error: Option<String>,
+}
+
+/// The status of a [`RefundablePayment`]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum RefundablePaymentStatus {
+    /// The payment wasn't claimed or refunded yet
+    Pending,
+    /// The recipient claimed the payment
+    Claimed,
+    /// The deadline passed and the payment was spent by the account in the transaction
+    #[serde(rename_all = "camelCase")]
+    Refunded { transaction_id: TransactionId },
+}
+
+impl Account {
+    /// Send payments that the recipients need to claim before a deadline. Every output gets an
+    /// [`ExpirationUnlockCondition`] that returns it to an address of the account afterwards. The account tracks the
+    /// payments, see [`Account::refundable_payments()`], and reclaims the expired ones when syncing.
+    /// ```ignore
+    /// // The recipient has one day to claim the payment
+    /// let payment = RefundablePaymentOptions::new(
+    ///     "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu",
+    ///     1_000_000,
+    ///     now + 24 * 60 * 60,
+    /// );
+    /// let transaction = account.send_with_refund_deadline(vec![payment], None).await?;
+    /// ```
+    pub async fn send_with_refund_deadline(
+        &self,
+        payments: Vec<RefundablePaymentOptions>,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        log::debug!("[TRANSACTION] send_with_refund_deadline");
+        let rent_structure = self.client.get_rent_structure().await?;
+        let token_supply = self.client.get_token_supply().await?;
+        let bech32_hrp = self.client.get_bech32_hrp().await?;
+        let local_time = self.client.get_time_checked().await?;
+        let account_addresses = self.addresses().await?;
+        let default_return_address = account_addresses.first().ok_or(Error::FailedToGetRemainder)?;
+
+        let mut outputs = Vec::new();
+        for payment in payments {
+            if payment.deadline <= local_time {
+                return Err(Error::InvalidRefundDeadline {
+                    deadline: payment.deadline,
+                    current_time: local_time,
+                });
+            }
+            let (hrp, address) = Address::try_from_bech32_with_hrp(&payment.address)?;
+            self.client.bech32_hrp_matches(&hrp).await?;
+            let return_address = match payment.return_address {
+                Some(return_address) => {
+                    let (hrp, return_address) = Address::try_from_bech32_with_hrp(&return_address)?;
+                    self.client.bech32_hrp_matches(&hrp).await?;
+                    // The account can only reclaim the payment with one of its addresses
+                    if !account_addresses
+                        .iter()
+                        .any(|account_address| account_address.address.inner == return_address)
+                    {
+                        return Err(Error::AddressNotFoundInAccount(
+                            return_address.to_bech32(bech32_hrp.clone()),
+                        ));
+                    }
+                    return_address
+                }
+                None => default_return_address.address.inner,
+            };
+
+            let output = BasicOutputBuilder::new_with_amount(payment.amount)
+                .add_unlock_condition(AddressUnlockCondition::new(address))
+                .add_unlock_condition(ExpirationUnlockCondition::new(return_address, payment.deadline)?)
+                .finish_output(token_supply)?;
+            output.verify_storage_deposit(rent_structure, token_supply)?;
+            outputs.push(output);
+        }
+
+        let transaction = self.send(outputs.clone(), options).await?;
+
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        let mut account_details = self.write().await;
+        for (index, output) in essence.outputs().iter().enumerate() {
+            // Remove the matched output, so equal payments are matched to different outputs of the transaction
+            match outputs.iter().position(|payment_output| payment_output == output) {
+                Some(position) => outputs.swap_remove(position),
+                None => continue,
+            };
+            let unlock_conditions = output.as_basic().unlock_conditions();
+            if let (Some(address), Some(expiration)) = (unlock_conditions.address(), unlock_conditions.expiration()) {
+                let output_id = OutputId::new(transaction.transaction_id, index as u16)?;
+                account_details.refundable_payments.insert(
+                    output_id,
+                    RefundablePayment {
+                        output_id,
+                        address: Bech32Address::new(bech32_hrp.clone(), *address.address())?,
+                        amount: output.amount(),
+                        deadline: expiration.timestamp(),
+                        status: RefundablePaymentStatus::Pending,
+                        reclaim_error: None,
+                    },
+                );
+            }
+        }
+        #[cfg(feature = "storage")]
+        self.save_changes(&account_details, Default::default()).await?;
+
+        Ok(transaction)
+    }
+
+    /// Get the payments sent with [`Account::send_with_refund_deadline()`], sorted by their deadline. Only the latest
+    /// claimed or refunded payments are kept.
+    pub async fn refundable_payments(&self) -> Vec<RefundablePayment> {
+        let mut refundable_payments = self
+            .read()
+            .await
+            .refundable_payments
+            .values()
+            .cloned()
+            .collect::<Vec<_>>();
+        refundable_payments
+            .sort_by_key(|refundable_payment| (refundable_payment.deadline, refundable_payment.output_id));
+        refundable_payments
+    }
+
+    /// Update the status of the pending refundable payments and reclaim the expired ones that are unspent outputs of
+    /// the account, called when syncing.
+    pub(crate) async fn sync_refundable_payments(&self) -> crate::wallet::Result<()> {
+        let account_details = self.read().await;
+        let pending_output_ids = account_details
+            .refundable_payments
+            .values()
+            .filter(|refundable_payment| refundable_payment.status == RefundablePaymentStatus::Pending)
+            .map(|refundable_payment| refundable_payment.output_id)
+            .collect::<Vec<_>>();
+        // A refunded payment is still unspent if the transaction to reclaim it conflicted
+        let unspent_refunded_payment = account_details.refundable_payments.values().any(|refundable_payment| {
+            matches!(refundable_payment.status, RefundablePaymentStatus::Refunded { .. })
+                && account_details
+                    .unspent_outputs
+                    .contains_key(&refundable_payment.output_id)
+        });
+        drop(account_details);
+        if pending_output_ids.is_empty() && !unspent_refunded_payment {
+            return Ok(());
+        }
+        log::debug!("[SYNC] sync_refundable_payments");
+
+        // Syncing shouldn't fail because of the payments, their status is updated again on the next sync
+        let spent_output_metadata = match self.client.try_get_outputs_metadata(pending_output_ids).await {
+            Ok(output_metadata) => output_metadata
+                .into_iter()
+                .filter(|output_metadata| output_metadata.is_spent)
+                .collect::<Vec<_>>(),
+            Err(err) => {
+                log::debug!("[SYNC] requesting the metadata of refundable payments failed: {err}");
+                Vec::new()
+            }
+        };
+        // The transactions of the account, also the ones that are only stored and not loaded anymore
+        let account_transactions = if spent_output_metadata.is_empty() {
+            Vec::new()
+        } else {
+            self.transactions().await?
+        };
+        let local_time = self.client.get_time_checked().await?;
+
+        let mut account_details = self.write().await;
+        for output_metadata in spent_output_metadata {
+            let output_id = output_metadata.output_id()?;
+            // A payment spent by the account was refunded, one spent by someone else claimed
+            let refunding_transaction_id = match &output_metadata.transaction_id_spent {
+                Some(transaction_id) => Some(TransactionId::from_str(transaction_id)?),
+                None => account_transactions
+                    .iter()
+                    .find(|transaction| {
+                        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+                        essence
+                            .inputs()
+                            .iter()
+                            .any(|input| matches!(input, Input::Utxo(input) if *input.output_id() == output_id))
+                    })
+                    .map(|transaction| transaction.transaction_id),
+            }
+            .filter(|transaction_id| {
+                account_transactions
+                    .iter()
+                    .any(|transaction| transaction.transaction_id == *transaction_id)
+            });
+            if let Some(refundable_payment) = account_details.refundable_payments.get_mut(&output_id) {
+                refundable_payment.status = refunding_transaction_id
+                    .map_or(RefundablePaymentStatus::Claimed, |transaction_id| {
+                        RefundablePaymentStatus::Refunded { transaction_id }
+                    });
+            }
+        }
+        {
+            let account_details = &mut *account_details;
+            prune_refundable_payments(
+                &mut account_details.refundable_payments,
+                &account_details.unspent_outputs,
+            );
+        }
+
+        let expired_output_ids = account_details
+            .refundable_payments
+            .values()
+            .filter(|refundable_payment| {
+                refundable_payment.status != RefundablePaymentStatus::Claimed
+                    && refundable_payment.deadline <= local_time
+                    && account_details
+                        .unspent_outputs
+                        .contains_key(&refundable_payment.output_id)
+                    && !account_details.locked_outputs.contains(&refundable_payment.output_id)
+            })
+            .map(|refundable_payment| refundable_payment.output_id)
+            .collect::<Vec<_>>();
+        let watch_only = account_details.watch_only;
+        #[cfg(feature = "storage")]
+        self.save_changes(&account_details, Default::default()).await?;
+        drop(account_details);
+
+        // A watch-only account can't sign the transaction to reclaim the payments
+        if expired_output_ids.is_empty() || watch_only {
+            return Ok(());
+        }
+        log::debug!("[SYNC] reclaiming expired refundable payments {expired_output_ids:?}");
+        let result = self.claim_outputs(expired_output_ids.clone()).await;
+        let mut account_details = self.write().await;
+        for output_id in &expired_output_ids {
+            if let Some(refundable_payment) = account_details.refundable_payments.get_mut(output_id) {
+                match &result {
+                    Ok(transaction) => {
+                        refundable_payment.status = RefundablePaymentStatus::Refunded {
+                            transaction_id: transaction.transaction_id,
+                        };
+                        refundable_payment.reclaim_error = None;
+                    }
+                    Err(err) => refundable_payment.reclaim_error = Some(err.to_string()),
+                }
+            }
+        }
+        // Syncing shouldn't fail because of the payments, they are tried to be reclaimed again on the next sync
+        if let Err(err) = &result {
+            log::warn!("[SYNC] reclaiming expired refundable payments failed: {err}");
+        }
+        #[cfg(feature = "storage")]
+        self.save_changes(&account_details, Default::default()).await?;
+
+        Ok(())
+    }
+}
+
+// Removes the claimed or refunded payments with the oldest deadlines, so at most `REFUNDABLE_PAYMENTS_MAX` of them are
+// kept. Refunded payments are only final once the account spent them.
+fn prune_refundable_payments(
+    refundable_payments: &mut HashMap<OutputId, RefundablePayment>,
+    unspent_outputs: &HashMap<OutputId, OutputData>,
+) {
+    let is_final = |refundable_payment: &RefundablePayment| match refundable_payment.status {
+        RefundablePaymentStatus::Pending => false,
+        RefundablePaymentStatus::Claimed => true,
+        RefundablePaymentStatus::Refunded { .. } => !unspent_outputs.contains_key(&refundable_payment.output_id),
+    };
+    let mut finished = refundable_payments
+        .values()
+        .filter(|refundable_payment| is_final(refundable_payment))
+        .map(|refundable_payment| (refundable_payment.deadline, refundable_payment.output_id))
+        .collect::<Vec<_>>();
+    if finished.len() <= REFUNDABLE_PAYMENTS_MAX {
+        return;
+    }
+    finished.sort_unstable();
+    for (_, output_id) in &finished[..finished.len() - REFUNDABLE_PAYMENTS_MAX] {
+        refundable_payments.remove(output_id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prune_finished_refundable_payments() {
+        let refundable_payment = |index: u32, status| {
+            let mut transaction_id = [0; 32];
+            transaction_id[..4].copy_from_slice(&index.to_le_bytes());
+            let output_id = OutputId::new(TransactionId::new(transaction_id), 0).unwrap();
+            (
+                output_id,
+                RefundablePayment {
+                    output_id,
+                    address: Bech32Address::try_from_str(
+                        "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu",
+                    )
+                    .unwrap(),
+                    amount: 1_000_000,
+                    deadline: index,
+                    status,
+                    reclaim_error: None,
+                },
+            )
+        };
+        let mut refundable_payments = (0..REFUNDABLE_PAYMENTS_MAX as u32 + 10)
+            .map(|index| {
+                refundable_payment(
+                    index,
+                    if index == 0 {
+                        RefundablePaymentStatus::Pending
+                    } else {
+                        RefundablePaymentStatus::Claimed
+                    },
+                )
+            })
+            .collect::<HashMap<_, _>>();
+        prune_refundable_payments(&mut refundable_payments, &HashMap::new());
+
+        // The claimed payments with the oldest deadlines are removed, pending ones are kept
+        assert_eq!(refundable_payments.len(), REFUNDABLE_PAYMENTS_MAX + 1);
+        let deadlines = refundable_payments
+            .values()
+            .map(|refundable_payment| refundable_payment.deadline)
+            .collect::<std::collections::HashSet<_>>();
+        assert!(deadlines.contains(&0));
+        assert!(!deadlines.contains(&9));
+        assert!(deadlines.contains(&10));
+    }
+}
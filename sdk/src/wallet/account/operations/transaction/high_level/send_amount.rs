@@ -9,7 +9,7 @@ use crate::{
             unlock_condition::{
                 AddressUnlockCondition, ExpirationUnlockCondition, StorageDepositReturnUnlockCondition,
             },
-            BasicOutputBuilder,
+            BasicOutputBuilder, Output,
         },
     },
     wallet::{
@@ -64,8 +64,9 @@ impl AddressWithAmount {
 
 impl Account {
     /// Function to create basic outputs with which we then will call
-    /// [Account.send()](crate::account::Account.send), the options can define the
-    /// RemainderValueStrategy or custom inputs.
+    /// [Account.send_chunked()](crate::account::Account.send_chunked), the options can define the
+    /// RemainderValueStrategy or custom inputs. If the outputs don't fit into a single transaction, they're sent in
+    /// multiple ones, so all created transactions are returned.
     /// Address needs to be Bech32 encoded
     /// ```ignore
     /// let outputs = vec![AddressWithAmount{
@@ -73,23 +74,31 @@ impl Account {
     ///     amount: 1_000_000,
     /// }];
     ///
-    /// let tx = account.send_amount(outputs, None ).await?;
-    /// println!("Transaction created: {}", tx.transaction_id);
-    /// if let Some(block_id) = tx.block_id {
-    ///     println!("Block sent: {}", block_id);
+    /// let transactions = account.send_amount(outputs, None ).await?;
+    /// for tx in transactions {
+    ///     println!("Transaction created: {}", tx.transaction_id);
+    ///     if let Some(block_id) = tx.block_id {
+    ///         println!("Block sent: {}", block_id);
+    ///     }
     /// }
     /// ```
     pub async fn send_amount(
         &self,
         addresses_with_amount: Vec<AddressWithAmount>,
         options: impl Into<Option<TransactionOptions>> + Send,
-    ) -> crate::wallet::Result<Transaction> {
-        let prepared_transaction = self.prepare_send_amount(addresses_with_amount, options).await?;
-        self.sign_and_submit_transaction(prepared_transaction).await
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] send_amount");
+        let options = options.into();
+        let outputs = self
+            .addresses_with_amount_to_outputs(addresses_with_amount, options.as_ref())
+            .await?;
+
+        self.send_chunked(outputs, options).await
     }
 
     /// Function to prepare the transaction for
-    /// [Account.send_amount()](crate::account::Account.send_amount)
+    /// [Account.send_amount()](crate::account::Account.send_amount), all outputs have to fit into a single
+    /// transaction
     pub async fn prepare_send_amount(
         &self,
         addresses_with_amount: Vec<AddressWithAmount>,
@@ -97,90 +106,113 @@ impl Account {
     ) -> crate::wallet::Result<PreparedTransactionData> {
         log::debug!("[TRANSACTION] prepare_send_amount");
         let options = options.into();
-        let rent_structure = self.client.get_rent_structure().await?;
-        let token_supply = self.client.get_token_supply().await?;
+        let outputs = self
+            .addresses_with_amount_to_outputs(addresses_with_amount, options.as_ref())
+            .await?;
+
+        self.prepare_transaction(outputs, options).await
+    }
 
+    async fn addresses_with_amount_to_outputs(
+        &self,
+        addresses_with_amount: Vec<AddressWithAmount>,
+        options: Option<&TransactionOptions>,
+    ) -> crate::wallet::Result<Vec<Output>> {
         let account_addresses = self.addresses().await?;
         let default_return_address = account_addresses.first().ok_or(Error::FailedToGetRemainder)?;
 
         let local_time = self.client.get_time_checked().await?;
 
         let mut outputs = Vec::new();
-        for AddressWithAmount {
+        for address_with_amount in addresses_with_amount {
+            outputs.push(
+                self.address_with_amount_to_output(
+                    address_with_amount,
+                    default_return_address.address.inner,
+                    local_time,
+                    options,
+                )
+                .await?,
+            );
+        }
+        Ok(outputs)
+    }
+
+    /// Creates the output for an [`AddressWithAmount`], with a storage deposit return and expiration if the amount
+    /// alone doesn't cover the storage deposit
+    pub(crate) async fn address_with_amount_to_output(
+        &self,
+        address_with_amount: AddressWithAmount,
+        default_return_address: Address,
+        local_time: u32,
+        options: Option<&TransactionOptions>,
+    ) -> crate::wallet::Result<Output> {
+        let rent_structure = self.client.get_rent_structure().await?;
+        let token_supply = self.client.get_token_supply().await?;
+        let AddressWithAmount {
             address,
             amount,
             return_address,
             expiration,
-        } in addresses_with_amount
-        {
-            let (bech32_hrp, address) = Address::try_from_bech32_with_hrp(address)?;
-            self.client.bech32_hrp_matches(&bech32_hrp).await?;
-            let return_address = return_address
-                .map(|address| {
-                    let (hrp, address) = Address::try_from_bech32_with_hrp(address)?;
-                    if bech32_hrp != hrp {
-                        Err(crate::client::Error::InvalidBech32Hrp {
-                            provided: hrp,
-                            expected: bech32_hrp,
-                        })?;
-                    }
-                    Ok::<_, Error>(address)
-                })
-                .transpose()?
-                .unwrap_or(default_return_address.address.inner);
-
-            // Get the minimum required amount for an output assuming it does not need a storage deposit.
-            let output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
-                .add_unlock_condition(AddressUnlockCondition::new(address))
-                .finish_output(token_supply)?;
-
-            if amount >= output.amount() {
-                outputs.push(
-                    BasicOutputBuilder::from(output.as_basic())
-                        .with_amount(amount)
-                        .finish_output(token_supply)?,
-                )
-            } else {
-                let expiration_time = expiration.map_or(local_time + DEFAULT_EXPIRATION_TIME, |expiration_time| {
-                    local_time + expiration_time
-                });
-
-                // Since it does need a storage deposit, calculate how much that should be
-                let storage_deposit_amount = minimum_storage_deposit_basic_native_tokens(
-                    &rent_structure,
-                    &address,
-                    &return_address,
-                    None,
-                    token_supply,
-                )?;
-
-                if !options.as_ref().map(|o| o.allow_micro_amount).unwrap_or_default() {
-                    return Err(Error::InsufficientFunds {
-                        available: amount,
-                        required: amount + storage_deposit_amount,
-                    });
+        } = address_with_amount;
+
+        let (bech32_hrp, address) = Address::try_from_bech32_with_hrp(address)?;
+        self.client.bech32_hrp_matches(&bech32_hrp).await?;
+        let return_address = return_address
+            .map(|address| {
+                let (hrp, address) = Address::try_from_bech32_with_hrp(address)?;
+                if bech32_hrp != hrp {
+                    Err(crate::client::Error::InvalidBech32Hrp {
+                        provided: hrp,
+                        expected: bech32_hrp,
+                    })?;
                 }
+                Ok::<_, Error>(address)
+            })
+            .transpose()?
+            .unwrap_or(default_return_address);
+
+        // Get the minimum required amount for an output assuming it does not need a storage deposit.
+        let output = BasicOutputBuilder::new_with_minimum_storage_deposit(rent_structure)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .finish_output(token_supply)?;
+
+        if amount >= output.amount() {
+            Ok(BasicOutputBuilder::from(output.as_basic())
+                .with_amount(amount)
+                .finish_output(token_supply)?)
+        } else {
+            let expiration_time = expiration.map_or(local_time + DEFAULT_EXPIRATION_TIME, |expiration_time| {
+                local_time + expiration_time
+            });
+
+            // Since it does need a storage deposit, calculate how much that should be
+            let storage_deposit_amount = minimum_storage_deposit_basic_native_tokens(
+                &rent_structure,
+                &address,
+                &return_address,
+                None,
+                token_supply,
+            )?;
+
+            if !options.map(|o| o.allow_micro_amount).unwrap_or_default() {
+                return Err(Error::InsufficientFunds {
+                    available: amount,
+                    required: amount + storage_deposit_amount,
+                });
+            }
 
-                outputs.push(
-                    // Add address_and_amount.amount+storage_deposit_amount, so receiver can get
-                    // address_and_amount.amount
-                    BasicOutputBuilder::from(output.as_basic())
-                        .with_amount(amount + storage_deposit_amount)
-                        .add_unlock_condition(
-                            // We send the storage_deposit_amount back to the sender, so only the additional amount is
-                            // sent
-                            StorageDepositReturnUnlockCondition::new(
-                                return_address,
-                                storage_deposit_amount,
-                                token_supply,
-                            )?,
-                        )
-                        .add_unlock_condition(ExpirationUnlockCondition::new(return_address, expiration_time)?)
-                        .finish_output(token_supply)?,
+            // Add address_and_amount.amount+storage_deposit_amount, so receiver can get
+            // address_and_amount.amount
+            Ok(BasicOutputBuilder::from(output.as_basic())
+                .with_amount(amount + storage_deposit_amount)
+                .add_unlock_condition(
+                    // We send the storage_deposit_amount back to the sender, so only the additional amount is
+                    // sent
+                    StorageDepositReturnUnlockCondition::new(return_address, storage_deposit_amount, token_supply)?,
                 )
-            }
+                .add_unlock_condition(ExpirationUnlockCondition::new(return_address, expiration_time)?)
+                .finish_output(token_supply)?)
         }
-
-        self.prepare_transaction(outputs, options).await
     }
 }
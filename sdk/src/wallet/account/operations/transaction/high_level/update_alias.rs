@@ -0,0 +1,143 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    types::block::{
+        address::Address,
+        output::{
+            unlock_condition::{GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition},
+            AliasId, AliasOutput, AliasOutputBuilder, Output,
+        },
+    },
+    wallet::{
+        account::{types::Transaction, Account, OutputData, TransactionOptions},
+        Error,
+    },
+};
+
+impl Account {
+    /// Update the state metadata of an alias output with a state transition, which increments its state index. The
+    /// amount of the alias output is increased if the new state metadata requires a higher storage deposit. Requires
+    /// the state controller address of the alias to be in the account.
+    /// ```ignore
+    /// let transaction = account
+    ///     .update_alias_state(alias_id, b"updated state metadata".to_vec(), None)
+    ///     .await?;
+    /// ```
+    pub async fn update_alias_state(
+        &self,
+        alias_id: AliasId,
+        state_metadata: Vec<u8>,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        log::debug!("[TRANSACTION] update_alias_state");
+        let rent_structure = self.client.get_rent_structure().await?;
+        let token_supply = self.client.get_token_supply().await?;
+        let alias_output = self.alias_output(alias_id).await?;
+
+        let alias_output_builder = AliasOutputBuilder::from(&alias_output)
+            .with_alias_id(alias_id)
+            .with_state_index(alias_output.state_index() + 1)
+            .with_state_metadata(state_metadata);
+        let minimum_amount = alias_output_builder
+            .clone()
+            .with_minimum_storage_deposit(rent_structure)
+            .finish(token_supply)?
+            .amount();
+        let output = alias_output_builder
+            .with_amount(alias_output.amount().max(minimum_amount))
+            .finish_output(token_supply)?;
+
+        self.send(vec![output], options).await
+    }
+
+    /// Set the state controller address of an alias output with a governance transition. Requires the governor
+    /// address of the alias to be in the account.
+    pub async fn set_alias_state_controller(
+        &self,
+        alias_id: AliasId,
+        bech32_address: &str,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        log::debug!("[TRANSACTION] set_alias_state_controller");
+        let address = self.address_from_bech32(bech32_address).await?;
+        self.alias_governance_transition(alias_id, Some(address), None, options)
+            .await
+    }
+
+    /// Set the governor address of an alias output with a governance transition. Requires the current governor
+    /// address of the alias to be in the account.
+    pub async fn set_alias_governor(
+        &self,
+        alias_id: AliasId,
+        bech32_address: &str,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        log::debug!("[TRANSACTION] set_alias_governor");
+        let address = self.address_from_bech32(bech32_address).await?;
+        self.alias_governance_transition(alias_id, None, Some(address), options)
+            .await
+    }
+
+    /// Transfer an alias output to another address by setting its state controller and governor addresses to it, with
+    /// a governance transition. The foundries controlled by the alias are transferred with it. Requires the governor
+    /// address of the alias to be in the account.
+    pub async fn transfer_alias(
+        &self,
+        alias_id: AliasId,
+        bech32_address: &str,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        log::debug!("[TRANSACTION] transfer_alias");
+        let address = self.address_from_bech32(bech32_address).await?;
+        self.alias_governance_transition(alias_id, Some(address), Some(address), options)
+            .await
+    }
+
+    // Send the alias output with replaced controller addresses, the state index stays the same, so it's a governance
+    // transition and the amount, native tokens, state metadata and foundry counter can't change.
+    async fn alias_governance_transition(
+        &self,
+        alias_id: AliasId,
+        state_controller: Option<Address>,
+        governor: Option<Address>,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Transaction> {
+        let token_supply = self.client.get_token_supply().await?;
+        let alias_output = self.alias_output(alias_id).await?;
+
+        let mut alias_output_builder = AliasOutputBuilder::from(&alias_output).with_alias_id(alias_id);
+        if let Some(state_controller) = state_controller {
+            alias_output_builder = alias_output_builder
+                .replace_unlock_condition(StateControllerAddressUnlockCondition::new(state_controller));
+        }
+        if let Some(governor) = governor {
+            alias_output_builder =
+                alias_output_builder.replace_unlock_condition(GovernorAddressUnlockCondition::new(governor));
+        }
+
+        self.send(vec![alias_output_builder.finish_output(token_supply)?], options)
+            .await
+    }
+
+    // Get the alias output from the unspent outputs of the account
+    async fn alias_output(&self, alias_id: AliasId) -> crate::wallet::Result<AliasOutput> {
+        match self.get_alias_output(Some(alias_id)).await {
+            Some((
+                _,
+                OutputData {
+                    output: Output::Alias(alias_output),
+                    ..
+                },
+            )) => Ok(alias_output),
+            _ => Err(Error::AliasNotFoundInUnspentOutputs),
+        }
+    }
+
+    // Get the address of a bech32 string and check that its HRP matches the network
+    async fn address_from_bech32(&self, bech32_address: &str) -> crate::wallet::Result<Address> {
+        let (bech32_hrp, address) = Address::try_from_bech32_with_hrp(bech32_address)?;
+        self.client.bech32_hrp_matches(&bech32_hrp).await?;
+        Ok(address)
+    }
+}
@@ -0,0 +1,233 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::BTreeMap;
+
+use primitive_types::U256;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    types::block::{
+        address::Address,
+        output::{
+            unlock_condition::{AddressUnlockCondition, TimelockUnlockCondition},
+            BasicOutputBuilder, NativeToken, NativeTokens, NativeTokensBuilder, Output,
+        },
+    },
+    wallet::{
+        account::{types::Transaction, Account, TransactionOptions},
+        Error,
+    },
+};
+
+/// A schedule of timelocked outputs to distribute funds over time.
+///
+/// Every output is locked with a [`TimelockUnlockCondition`], the first one until the cliff and every following one
+/// until a period later. The outputs can have different amounts, for example to unlock a larger share at the cliff.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VestingSchedule {
+    /// Bech32 encoded address of the recipient
+    pub address: String,
+    /// Unix timestamp in seconds at which the first output unlocks
+    pub cliff: u32,
+    /// Seconds between the unlocks of two outputs
+    pub period: u32,
+    /// Amounts of the outputs, in the order they unlock
+    pub amounts: Vec<u64>,
+    /// Native tokens of every output
+    #[serde(default)]
+    pub native_tokens: Vec<NativeToken>,
+}
+
+impl VestingSchedule {
+    /// Creates a new [`VestingSchedule`] with an output per amount, without native tokens.
+    pub fn new(address: impl Into<String>, cliff: u32, period: u32, amounts: impl IntoIterator<Item = u64>) -> Self {
+        Self {
+            address: address.into(),
+            cliff,
+            period,
+            amounts: amounts.into_iter().collect(),
+            native_tokens: Vec::new(),
+        }
+    }
+
+    /// Sets the native tokens of every output.
+    pub fn with_native_tokens(mut self, native_tokens: impl IntoIterator<Item = NativeToken>) -> Self {
+        self.native_tokens = native_tokens.into_iter().collect();
+        self
+    }
+
+    /// Returns the timestamps at which the outputs unlock.
+    pub fn unlock_timestamps(&self) -> crate::wallet::Result<Vec<u32>> {
+        if self.amounts.is_empty() {
+            return Err(Error::InvalidVestingSchedule("no amounts".to_string()));
+        }
+        if self.amounts.len() > 1 && self.period == 0 {
+            return Err(Error::InvalidVestingSchedule("period is zero".to_string()));
+        }
+
+        (0..self.amounts.len())
+            .map(|index| {
+                u32::try_from(index)
+                    .ok()
+                    .and_then(|index| index.checked_mul(self.period))
+                    .and_then(|offset| self.cliff.checked_add(offset))
+                    .ok_or_else(|| Error::InvalidVestingSchedule("unlock timestamp overflows".to_string()))
+            })
+            .collect()
+    }
+}
+
+/// The funds of an account that are locked by a timelock, see [`Account::vesting_balance()`].
+#[derive(Debug, Clone, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VestingBalance {
+    /// Amount of the timelocked outputs that already unlocked
+    pub vested: u64,
+    /// Amount of the outputs that are still timelocked
+    pub locked: u64,
+    /// The still timelocked funds grouped by their unlock timestamp, in ascending order
+    pub unlocks: Vec<VestingUnlock>,
+}
+
+/// Funds that unlock at the same time, see [`VestingBalance`].
+#[derive(Debug, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VestingUnlock {
+    /// Unix timestamp in seconds at which the funds unlock
+    pub timestamp: u32,
+    /// Amount that unlocks
+    pub amount: u64,
+    /// Native tokens that unlock
+    pub native_tokens: NativeTokens,
+}
+
+impl Account {
+    /// Send the outputs of a [`VestingSchedule`], every output is locked to the address of the schedule with a
+    /// [`TimelockUnlockCondition`] and needs to cover its storage deposit. Fails if the available balance doesn't cover
+    /// all outputs. The outputs are sent in as few transactions as possible, see [`Account::send_chunked()`].
+    /// ```ignore
+    /// // Unlock 3 Mi in 90 days and then 1 Mi every 30 days over a year
+    /// let mut amounts = vec![1_000_000; 12];
+    /// amounts[0] = 3_000_000;
+    /// let schedule = VestingSchedule::new(
+    ///     "rms1qpszqzadsym6wpppd6z037dvlejmjuke7s24hm95s9fg9vpua7vluaw60xu",
+    ///     now + 90 * 24 * 60 * 60,
+    ///     30 * 24 * 60 * 60,
+    ///     amounts,
+    /// );
+    /// let transactions = account.send_vesting_schedule(schedule, None).await?;
+    /// ```
+    pub async fn send_vesting_schedule(
+        &self,
+        vesting_schedule: VestingSchedule,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] send_vesting_schedule");
+        let outputs = self.vesting_schedule_outputs(&vesting_schedule).await?;
+        self.check_vesting_schedule_funds(&vesting_schedule).await?;
+        self.send_chunked(outputs, options).await
+    }
+
+    async fn vesting_schedule_outputs(&self, vesting_schedule: &VestingSchedule) -> crate::wallet::Result<Vec<Output>> {
+        let rent_structure = self.client.get_rent_structure().await?;
+        let token_supply = self.client.get_token_supply().await?;
+        let (bech32_hrp, address) = Address::try_from_bech32_with_hrp(&vesting_schedule.address)?;
+        self.client.bech32_hrp_matches(&bech32_hrp).await?;
+        let native_tokens = NativeTokens::from_vec(vesting_schedule.native_tokens.clone())?;
+
+        vesting_schedule
+            .unlock_timestamps()?
+            .into_iter()
+            .zip(&vesting_schedule.amounts)
+            .map(|(timestamp, amount)| {
+                let output = BasicOutputBuilder::new_with_amount(*amount)
+                    .with_native_tokens(native_tokens.clone())
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .add_unlock_condition(TimelockUnlockCondition::new(timestamp)?)
+                    .finish_output(token_supply)?;
+                output.verify_storage_deposit(rent_structure, token_supply)?;
+                Ok(output)
+            })
+            .collect()
+    }
+
+    /// Get the vested and still locked funds of the basic outputs of the account that are only locked to an address
+    /// and by a timelock, at the given unix timestamp in seconds, or at the current time of the node if it's `None`.
+    pub async fn vesting_balance(&self, timestamp: Option<u32>) -> crate::wallet::Result<VestingBalance> {
+        log::debug!("[vesting_balance]");
+        let timestamp = match timestamp {
+            Some(timestamp) => timestamp,
+            None => self.client.get_time_checked().await?,
+        };
+        let mut vesting_balance = VestingBalance::default();
+        let mut unlocks = BTreeMap::<u32, (u64, NativeTokensBuilder)>::new();
+
+        for output_data in self.read().await.unspent_outputs().values() {
+            let basic_output = match &output_data.output {
+                Output::Basic(basic_output) => basic_output,
+                _ => continue,
+            };
+            let unlock_conditions = basic_output.unlock_conditions();
+            let timelock = match unlock_conditions.timelock() {
+                Some(timelock) if unlock_conditions.len() == 2 && unlock_conditions.address().is_some() => timelock,
+                _ => continue,
+            };
+
+            if timelock.timestamp() <= timestamp {
+                vesting_balance.vested += basic_output.amount();
+            } else {
+                vesting_balance.locked += basic_output.amount();
+                let (amount, native_tokens) = unlocks.entry(timelock.timestamp()).or_default();
+                *amount += basic_output.amount();
+                native_tokens.add_native_tokens(basic_output.native_tokens().clone())?;
+            }
+        }
+
+        for (timestamp, (amount, native_tokens)) in unlocks {
+            vesting_balance.unlocks.push(VestingUnlock {
+                timestamp,
+                amount,
+                native_tokens: native_tokens.finish()?,
+            });
+        }
+
+        Ok(vesting_balance)
+    }
+
+    // Check that the available balance covers all outputs of the schedule
+    async fn check_vesting_schedule_funds(&self, vesting_schedule: &VestingSchedule) -> crate::wallet::Result<()> {
+        let balance = self.balance().await?;
+        let required = vesting_schedule
+            .amounts
+            .iter()
+            .try_fold(0u64, |total, amount| total.checked_add(*amount))
+            .ok_or_else(|| Error::InvalidVestingSchedule("total amount overflows".to_string()))?;
+        let available = balance.base_coin().available();
+        if required > available {
+            return Err(Error::InsufficientFunds { available, required });
+        }
+
+        let output_count = U256::from(vesting_schedule.amounts.len());
+        for native_token in &vesting_schedule.native_tokens {
+            let required = native_token
+                .amount()
+                .checked_mul(output_count)
+                .ok_or_else(|| Error::InvalidVestingSchedule("total native token amount overflows".to_string()))?;
+            let available = balance
+                .native_tokens()
+                .iter()
+                .find(|native_tokens_balance| native_tokens_balance.token_id() == native_token.token_id())
+                .map_or_else(U256::zero, |native_tokens_balance| native_tokens_balance.available());
+            if required > available {
+                return Err(Error::InvalidVestingSchedule(format!(
+                    "{required} of native token {} required, but only {available} available",
+                    native_token.token_id()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
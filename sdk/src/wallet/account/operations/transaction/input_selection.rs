@@ -7,7 +7,7 @@ use std::collections::{hash_map::Values, HashSet};
 use crate::wallet::events::types::{TransactionProgressEvent, WalletEvent};
 use crate::{
     client::{
-        api::input_selection::{Burn, InputSelection, Selected},
+        api::input_selection::{Burn, CoinSelectionStrategy, InputSelection, Selected},
         secret::types::InputSigningData,
     },
     types::block::{
@@ -28,6 +28,7 @@ impl Account {
         mandatory_inputs: Option<HashSet<OutputId>>,
         remainder_address: Option<Address>,
         burn: Option<&Burn>,
+        coin_selection_strategy: CoinSelectionStrategy,
     ) -> crate::wallet::Result<Selected> {
         log::debug!("[TRANSACTION] select_inputs");
         // Voting output needs to be requested before to prevent a deadlock
@@ -96,7 +97,8 @@ impl Account {
                 protocol_parameters.clone(),
             )
             .required_inputs(custom_inputs)
-            .forbidden_inputs(forbidden_inputs);
+            .forbidden_inputs(forbidden_inputs)
+            .coin_selection_strategy(coin_selection_strategy);
 
             if let Some(address) = remainder_address {
                 input_selection = input_selection.remainder_address(address);
@@ -131,7 +133,8 @@ impl Account {
                 protocol_parameters.clone(),
             )
             .required_inputs(mandatory_inputs)
-            .forbidden_inputs(forbidden_inputs);
+            .forbidden_inputs(forbidden_inputs)
+            .coin_selection_strategy(coin_selection_strategy);
 
             if let Some(address) = remainder_address {
                 input_selection = input_selection.remainder_address(address);
@@ -162,7 +165,8 @@ impl Account {
             addresses,
             protocol_parameters.clone(),
         )
-        .forbidden_inputs(forbidden_inputs);
+        .forbidden_inputs(forbidden_inputs)
+        .coin_selection_strategy(coin_selection_strategy);
 
         if let Some(address) = remainder_address {
             input_selection = input_selection.remainder_address(address);
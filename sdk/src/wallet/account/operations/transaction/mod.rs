@@ -7,10 +7,16 @@ mod input_selection;
 mod options;
 pub(crate) mod prepare_output;
 mod prepare_transaction;
+pub(crate) mod send_chunked;
 mod sign_transaction;
+mod simulate_transaction;
 pub(crate) mod submit_transaction;
+mod transaction_bundle;
 
-pub use self::options::{RemainderValueStrategy, TransactionOptions, TransactionOptionsDto};
+pub use self::{
+    options::{RemainderValueStrategy, TransactionOptions, TransactionOptionsDto},
+    simulate_transaction::TransactionSimulation,
+};
 use crate::{
     client::{
         api::{verify_semantic, PreparedTransactionData, SignedTransactionData},
@@ -186,7 +192,7 @@ impl Account {
         #[cfg(feature = "storage")]
         {
             log::debug!("[TRANSACTION] storing account {}", account_details.index());
-            self.save(Some(&account_details)).await?;
+            self.save_changes(&account_details, Default::default()).await?;
         }
 
         Ok(transaction)
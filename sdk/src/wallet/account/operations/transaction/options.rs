@@ -4,7 +4,7 @@
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    client::api::input_selection::{Burn, BurnDto},
+    client::api::input_selection::{Burn, BurnDto, CoinSelectionStrategy},
     types::block::{
         output::OutputId,
         payload::{dto::TaggedDataPayloadDto, tagged_data::TaggedDataPayload},
@@ -31,6 +31,8 @@ pub struct TransactionOptions {
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    #[serde(default)]
+    pub coin_selection_strategy: CoinSelectionStrategy,
 }
 
 impl TransactionOptions {
@@ -48,6 +50,7 @@ impl TransactionOptions {
             burn: value.burn.as_ref().map(Burn::try_from).transpose()?,
             note: value.note.clone(),
             allow_micro_amount: value.allow_micro_amount,
+            coin_selection_strategy: value.coin_selection_strategy,
         })
     }
 }
@@ -70,6 +73,8 @@ pub struct TransactionOptionsDto {
     pub note: Option<String>,
     #[serde(default)]
     pub allow_micro_amount: bool,
+    #[serde(default)]
+    pub coin_selection_strategy: CoinSelectionStrategy,
 }
 
 #[allow(clippy::enum_variant_names)]
@@ -105,6 +105,10 @@ impl Account {
                     .map(|inputs| HashSet::from_iter(inputs.clone())),
                 remainder_address,
                 options.as_ref().and_then(|options| options.burn.as_ref()),
+                options
+                    .as_ref()
+                    .map(|options| options.coin_selection_strategy)
+                    .unwrap_or_default(),
             )
             .await?;
 
@@ -0,0 +1,189 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use packable::PackableExt;
+
+use crate::{
+    client::api::input_selection::Error as InputSelectionError,
+    types::block::output::{Output, OUTPUT_COUNT_MAX},
+    wallet::{
+        account::{types::Transaction, Account, TransactionOptions},
+        Error,
+    },
+};
+
+impl Account {
+    /// Send outputs like [`Account::send()`], but split them over multiple transactions if they don't fit into one,
+    /// because of the max output count, the max transaction size or because more than the max input count would be
+    /// needed. If a single output needs more than the max input count, the outputs of the account are consolidated
+    /// first. If a transaction can't be prepared because the funds are still locked in the remainder of the previous
+    /// one, its inclusion is awaited and the account synced before trying again. Custom and mandatory inputs from the
+    /// options are only used for the first transaction. Returns the created transactions in the order of the outputs.
+    /// If a transaction fails after others were already sent, [`Error::PartiallySent`] is returned with the sent
+    /// transactions, so the outputs of the failed chunk and the following ones can be sent again without the sent ones.
+    pub async fn send_chunked(
+        &self,
+        outputs: Vec<Output>,
+        options: impl Into<Option<TransactionOptions>> + Send,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        log::debug!("[TRANSACTION] send_chunked");
+        // Leave space for a remainder output
+        self.send_in_chunks(outputs, OUTPUT_COUNT_MAX as usize - 1, options.into())
+            .await
+    }
+
+    // Send the outputs in chunks of at most `chunk_size` outputs, see `send_chunked()`
+    pub(crate) async fn send_in_chunks(
+        &self,
+        outputs: Vec<Output>,
+        chunk_size: usize,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<Vec<Transaction>> {
+        let mut transactions = Vec::new();
+        match self.send_chunks(&outputs, chunk_size, options, &mut transactions).await {
+            Ok(()) => Ok(transactions),
+            // Without a sent transaction the error is returned as it is, like for a single transaction
+            Err(error) if transactions.is_empty() => Err(error),
+            Err(error) => Err(Error::PartiallySent {
+                failed_chunk: transactions.len(),
+                transactions,
+                error: Box::new(error),
+            }),
+        }
+    }
+
+    // Sends the chunks and adds the sent transactions to `transactions`
+    async fn send_chunks(
+        &self,
+        outputs: &[Output],
+        max_chunk_size: usize,
+        mut options: Option<TransactionOptions>,
+        transactions: &mut Vec<Transaction>,
+    ) -> crate::wallet::Result<()> {
+        let mut remaining_outputs = outputs;
+        let mut chunk_size = max_chunk_size;
+        // Set once the inclusion of the previous transaction was awaited for the current chunk
+        let mut awaited_inclusion = false;
+
+        while !remaining_outputs.is_empty() {
+            let chunk = &remaining_outputs[..chunk_size.min(remaining_outputs.len())];
+
+            match self.send(chunk.to_vec(), options.clone()).await {
+                Ok(transaction) => {
+                    transactions.push(transaction);
+                    remaining_outputs = &remaining_outputs[chunk.len()..];
+                    awaited_inclusion = false;
+                    chunk_size = next_chunk_size(chunk, remaining_outputs, chunk_size, max_chunk_size);
+                    // The custom and mandatory inputs are spent by the first transaction
+                    if let Some(options) = &mut options {
+                        options.custom_inputs = None;
+                        options.mandatory_inputs = None;
+                    }
+                }
+                // The transaction is too large, so try again with fewer outputs
+                Err(err) if chunk.len() > 1 && exceeds_transaction_limits(&err) => {
+                    chunk_size = chunk.len() / 2;
+                    log::debug!("[TRANSACTION] send_chunked: retrying with {chunk_size} outputs");
+                }
+                // A single output needs more inputs than fit into a transaction, so they're consolidated first
+                Err(err) if exceeds_input_count(&err) => {
+                    let consolidation = match self.consolidate_outputs(true, None).await {
+                        // Without at least two consolidated inputs the input count wouldn't shrink
+                        Ok(consolidation) if consolidation.inputs.len() > 1 => consolidation,
+                        Ok(_) | Err(Error::NoOutputsToConsolidate { .. }) => return Err(err),
+                        Err(consolidation_err) => return Err(consolidation_err),
+                    };
+                    log::debug!(
+                        "[TRANSACTION] send_chunked: consolidating {} inputs in {}",
+                        consolidation.inputs.len(),
+                        consolidation.transaction_id
+                    );
+                    self.retry_transaction_until_included(&consolidation.transaction_id, None, None)
+                        .await?;
+                    self.sync(None).await?;
+                }
+                Err(err) => match transactions.last() {
+                    // The funds or a required output could be in the outputs of the previous transaction, which are
+                    // only available once it's included
+                    Some(transaction) if !awaited_inclusion && lacks_available_inputs(&err) => {
+                        log::debug!(
+                            "[TRANSACTION] send_chunked: waiting for {} after error: {err}",
+                            transaction.transaction_id
+                        );
+                        self.retry_transaction_until_included(&transaction.transaction_id, None, None)
+                            .await?;
+                        self.sync(None).await?;
+                        awaited_inclusion = true;
+                    }
+                    _ => return Err(err),
+                },
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// Returns the size of the next chunk after `sent_chunk` was sent. A reduced chunk size is kept for outputs of a similar
+// size, so uniform outputs aren't tried again in a chunk that is known to be too large, but it grows in proportion if
+// the next outputs are smaller, e.g. because the sent chunk was only reduced for outputs with large metadata
+fn next_chunk_size(
+    sent_chunk: &[Output],
+    remaining_outputs: &[Output],
+    chunk_size: usize,
+    max_chunk_size: usize,
+) -> usize {
+    if chunk_size >= max_chunk_size || remaining_outputs.is_empty() {
+        return chunk_size;
+    }
+    let average_len = |outputs: &[Output]| outputs.iter().map(PackableExt::packed_len).sum::<usize>() / outputs.len();
+    let sent_len = average_len(sent_chunk);
+    let next_len = average_len(&remaining_outputs[..chunk_size.min(remaining_outputs.len())]).max(1);
+
+    (chunk_size * sent_len / next_len).clamp(chunk_size, max_chunk_size)
+}
+
+/// Returns true if the error is caused by a transaction needing too many inputs or outputs, or being too large for a
+/// block
+pub(crate) fn exceeds_transaction_limits(error: &Error) -> bool {
+    match error {
+        Error::Client(error) => matches!(
+            **error,
+            crate::client::Error::InputSelection(
+                InputSelectionError::InvalidInputCount(_) | InputSelectionError::InvalidOutputCount(_)
+            ) | crate::client::Error::InvalidRegularTransactionEssenceLength { .. }
+                | crate::client::Error::InvalidTransactionPayloadLength { .. }
+        ),
+        _ => false,
+    }
+}
+
+// Returns true if the error is caused by a transaction needing more than the max input count
+fn exceeds_input_count(error: &Error) -> bool {
+    match error {
+        Error::Client(error) => matches!(
+            **error,
+            crate::client::Error::InputSelection(InputSelectionError::InvalidInputCount(_))
+        ),
+        _ => false,
+    }
+}
+
+// Returns true if the error is caused by not enough funds or a required chain output, like an issuer NFT, being
+// available for a transaction
+fn lacks_available_inputs(error: &Error) -> bool {
+    match error {
+        Error::InsufficientFunds { .. } => true,
+        Error::Client(error) => matches!(
+            **error,
+            crate::client::Error::InputSelection(
+                InputSelectionError::InsufficientAmount { .. }
+                    | InputSelectionError::InsufficientNativeTokenAmount { .. }
+                    | InputSelectionError::NoAvailableInputsProvided
+                    | InputSelectionError::RequiredInputIsNotAvailable(_)
+                    | InputSelectionError::UnfulfillableRequirement(_)
+            )
+        ),
+        _ => false,
+    }
+}
@@ -25,6 +25,11 @@ impl Account {
     ) -> crate::wallet::Result<SignedTransactionData> {
         log::debug!("[TRANSACTION] sign_transaction_essence");
         log::debug!("[TRANSACTION] prepared_transaction_data {prepared_transaction_data:?}");
+        if self.read().await.watch_only {
+            // unlock outputs so they are available for a new transaction
+            self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+            return Err(crate::wallet::Error::WatchOnlyAccount("sign transactions"));
+        }
         #[cfg(feature = "events")]
         self.event_emitter.lock().await.emit(
             self.read().await.index,
@@ -0,0 +1,148 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use primitive_types::U256;
+
+use crate::{
+    client::api::{simulate_semantic, PreparedTransactionData, TransactionSummary},
+    types::block::{
+        address::{Address, AliasAddress, NftAddress},
+        output::{ChainId, Output},
+        semantic::ConflictReason,
+    },
+    wallet::account::{
+        operations::transaction::{RemainderValueStrategy, TransactionOptions},
+        types::{AccountBalance, NativeTokensBalance},
+        Account,
+    },
+};
+
+/// The predicted outcome of a transaction, see [`Account::simulate_transaction()`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TransactionSimulation {
+    /// The reason the transaction would be rejected for, [`ConflictReason::None`] if it would be accepted
+    pub conflict: ConflictReason,
+    /// The prepared transaction
+    pub prepared_transaction_data: PreparedTransactionData,
+    /// What the transaction does
+    pub summary: TransactionSummary,
+    /// The predicted balance of the account after the transaction got confirmed. Aliases and NFTs created by the
+    /// transaction aren't listed, since their ids depend on the transaction id, which is only known once it's signed.
+    pub balance: AccountBalance,
+}
+
+impl Account {
+    /// Simulates a transaction against the synced outputs of the account, without signing or submitting it. Input
+    /// selection is done like for [`Account::send()`], the resulting transaction is validated with placeholder
+    /// signatures. The secret manager isn't used, so [`RemainderValueStrategy::ChangeAddress`] is treated like
+    /// [`RemainderValueStrategy::ReuseAddress`].
+    pub async fn simulate_transaction(
+        &self,
+        outputs: Vec<Output>,
+        options: Option<TransactionOptions>,
+    ) -> crate::wallet::Result<TransactionSimulation> {
+        log::debug!("[TRANSACTION] simulate_transaction");
+
+        let options = options.map(|mut options| {
+            if matches!(options.remainder_value_strategy, RemainderValueStrategy::ChangeAddress) {
+                options.remainder_value_strategy = RemainderValueStrategy::ReuseAddress;
+            }
+            options
+        });
+
+        let burn = options.as_ref().and_then(|options| options.burn.clone());
+        let prepared_transaction_data = self.prepare_transaction(outputs, options).await?;
+        // Preparing the transaction locked the inputs, they are released again since it's never sent
+        self.unlock_inputs(&prepared_transaction_data.inputs_data).await?;
+
+        let current_time = self.client.get_time_checked().await?;
+        let conflict = simulate_semantic(&prepared_transaction_data, current_time)?;
+        let summary = TransactionSummary::new(&prepared_transaction_data, burn.as_ref(), Some(current_time))?;
+
+        let mut balance = self.balance().await?;
+        let mut own_addresses = self
+            .addresses()
+            .await?
+            .into_iter()
+            .map(|address| address.address.inner)
+            .collect::<HashSet<_>>();
+        own_addresses.extend(
+            balance
+                .aliases
+                .iter()
+                .map(|alias_id| Address::Alias(AliasAddress::new(*alias_id))),
+        );
+        own_addresses.extend(balance.nfts.iter().map(|nft_id| Address::Nft(NftAddress::new(*nft_id))));
+
+        apply_summary(&mut balance, &summary, &own_addresses);
+
+        Ok(TransactionSimulation {
+            conflict,
+            prepared_transaction_data,
+            summary,
+            balance,
+        })
+    }
+}
+
+// Applies the balance changes and chain transitions of the own addresses to the balance.
+fn apply_summary(balance: &mut AccountBalance, summary: &TransactionSummary, own_addresses: &HashSet<Address>) {
+    for balance_change in summary
+        .balance_changes
+        .iter()
+        .filter(|balance_change| own_addresses.contains(&balance_change.address))
+    {
+        let base_coin = balance_change.base_coin();
+        balance.base_coin.total = (balance.base_coin.total as i128 + base_coin).max(0) as u64;
+        balance.base_coin.available = (balance.base_coin.available as i128 + base_coin).max(0) as u64;
+
+        for (token_id, amount) in &balance_change.native_tokens_received {
+            match balance
+                .native_tokens
+                .iter_mut()
+                .find(|native_token| native_token.token_id == *token_id)
+            {
+                Some(native_token) => {
+                    native_token.total = native_token.total.saturating_add(*amount);
+                    native_token.available = native_token.available.saturating_add(*amount);
+                }
+                None => balance.native_tokens.push(NativeTokensBalance {
+                    token_id: *token_id,
+                    metadata: None,
+                    total: *amount,
+                    available: *amount,
+                }),
+            }
+        }
+        for (token_id, amount) in &balance_change.native_tokens_spent {
+            if let Some(native_token) = balance
+                .native_tokens
+                .iter_mut()
+                .find(|native_token| native_token.token_id == *token_id)
+            {
+                native_token.total = native_token.total.saturating_sub(*amount);
+                native_token.available = native_token.available.saturating_sub(*amount);
+            }
+        }
+    }
+    balance
+        .native_tokens
+        .retain(|native_token| native_token.total != U256::zero());
+
+    for transition in &summary.chain_transitions {
+        let from_own = transition.from.map_or(false, |from| own_addresses.contains(&from));
+        let to_own = transition.to.map_or(false, |to| own_addresses.contains(&to));
+
+        match transition.chain_id {
+            ChainId::Alias(alias_id) if from_own && !to_own => balance.aliases.retain(|id| *id != alias_id),
+            ChainId::Alias(alias_id) if to_own && !from_own && !alias_id.is_null() => balance.aliases.push(alias_id),
+            ChainId::Foundry(foundry_id) if from_own && !to_own => balance.foundries.retain(|id| *id != foundry_id),
+            ChainId::Foundry(foundry_id) if to_own && !from_own => balance.foundries.push(foundry_id),
+            ChainId::Nft(nft_id) if from_own && !to_own => balance.nfts.retain(|id| *id != nft_id),
+            ChainId::Nft(nft_id) if to_own && !from_own && !nft_id.is_null() => balance.nfts.push(nft_id),
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,132 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::slip10::Chain;
+
+use crate::{
+    client::{
+        api::{input_selection::is_alias_transition, verify_semantic, PreparedTransactionData, TransactionBundle},
+        Error,
+    },
+    types::block::{payload::transaction::TransactionEssence, semantic::ConflictReason},
+    utils::unix_timestamp_now,
+    wallet::account::{types::Transaction, Account},
+};
+
+impl Account {
+    /// Exports a prepared transaction as [`TransactionBundle`] with the protocol parameters of the network, so it can
+    /// be validated and signed by an offline signer.
+    pub async fn export_prepared_transaction(
+        &self,
+        prepared_transaction_data: PreparedTransactionData,
+    ) -> crate::wallet::Result<TransactionBundle> {
+        log::debug!("[TRANSACTION] export_prepared_transaction");
+
+        let protocol_parameters = self.client.get_protocol_parameters().await?;
+
+        Ok(TransactionBundle::new(prepared_transaction_data, protocol_parameters)?)
+    }
+
+    /// Validates a [`TransactionBundle`] and signs it with the secret manager of the account. No node is required, so
+    /// this can be done by an offline signer. Inputs without a BIP32 chain, as prepared by watch-only accounts, are
+    /// signed with the chain of the matching address of this account.
+    pub async fn sign_transaction_bundle(
+        &self,
+        mut bundle: TransactionBundle,
+    ) -> crate::wallet::Result<TransactionBundle> {
+        log::debug!("[TRANSACTION] sign_transaction_bundle");
+        {
+            let account_details = self.read().await;
+            if account_details.watch_only {
+                return Err(crate::wallet::Error::WatchOnlyAccount("sign transactions"));
+            }
+
+            let current_time = unix_timestamp_now().as_secs() as u32;
+            let prepared_transaction_data = bundle.prepared_transaction_data();
+            let TransactionEssence::Regular(essence) = &prepared_transaction_data.essence;
+            let mut chains = Vec::new();
+            for (input_index, input) in prepared_transaction_data.inputs_data.iter().enumerate() {
+                if input.chain.is_some() {
+                    continue;
+                }
+                // A governance transition has to be signed by the governor instead of the state controller
+                let alias_transition = is_alias_transition(input, essence.outputs()).map(|t| t.0);
+                let (required_address, _) =
+                    input
+                        .output
+                        .required_and_unlocked_address(current_time, input.output_id(), alias_transition)?;
+                if let Some(address) = account_details
+                    .public_addresses
+                    .iter()
+                    .chain(account_details.internal_addresses.iter())
+                    .find(|address| address.address.inner == required_address)
+                {
+                    chains.push((
+                        input_index,
+                        Chain::from_u32_hardened(vec![
+                            44,
+                            account_details.coin_type,
+                            account_details.index,
+                            address.internal as u32,
+                            address.key_index,
+                        ]),
+                    ));
+                }
+            }
+            for (input_index, chain) in chains {
+                bundle.set_input_chain(input_index, chain)?;
+            }
+        }
+
+        bundle.sign(&*self.secret_manager.read().await, None).await?;
+
+        Ok(bundle)
+    }
+
+    /// Imports a [`TransactionBundle`] that was signed by an offline signer, submits the transaction to a node and
+    /// stores it in the account. The bundle has to be for the network of the account and spend only unspent outputs
+    /// of the account, and its signatures have to unlock the inputs.
+    pub async fn import_signed_transaction(&self, bundle: TransactionBundle) -> crate::wallet::Result<Transaction> {
+        log::debug!("[TRANSACTION] import_signed_transaction");
+
+        let network_id = self.client.get_network_id().await?;
+        if bundle.network_id() != network_id {
+            return Err(Error::InvalidTransactionBundle(format!(
+                "network id {} doesn't match the network id {network_id} of the account",
+                bundle.network_id()
+            ))
+            .into());
+        }
+
+        {
+            let account_details = self.read().await;
+            if let Some(input) = bundle
+                .prepared_transaction_data()
+                .inputs_data
+                .iter()
+                .find(|input| !account_details.unspent_outputs.contains_key(input.output_id()))
+            {
+                return Err(Error::InvalidTransactionBundle(format!(
+                    "input {} isn't an unspent output of the account",
+                    input.output_id()
+                ))
+                .into());
+            }
+        }
+
+        let signed_transaction_data = bundle.signed_transaction_data()?;
+
+        // The signatures of the offline signer are verified against the inputs before the transaction is submitted
+        let conflict = verify_semantic(
+            &signed_transaction_data.inputs_data,
+            &signed_transaction_data.transaction_payload,
+            self.client.get_time_checked().await?,
+        )?;
+        if conflict != ConflictReason::None {
+            log::debug!("[TRANSACTION] import_signed_transaction conflict: {conflict:?}");
+            return Err(Error::TransactionSemantic(conflict).into());
+        }
+
+        self.submit_and_store_transaction(signed_transaction_data).await
+    }
+}
@@ -9,7 +9,11 @@ use serde::{Deserialize, Serialize};
 
 use crate::types::block::{
     dto::U256Dto,
-    output::{dto::TokenIdDto, feature::MetadataFeature, AliasId, FoundryId, NftId, OutputId, TokenId},
+    output::{
+        dto::TokenIdDto,
+        feature::{Irc30Metadata, MetadataFeature},
+        AliasId, FoundryId, NftId, OutputId, TokenId,
+    },
 };
 
 /// The balance of an account, returned from [`crate::wallet::account::Account::sync()`] and
@@ -218,6 +222,16 @@ impl Default for NativeTokensBalance {
     }
 }
 
+impl NativeTokensBalance {
+    /// Returns the [`Irc30Metadata`] of the token, with its name, symbol and decimals, if the foundry metadata follows
+    /// the standard.
+    pub fn irc_30_metadata(&self) -> Option<Irc30Metadata> {
+        self.metadata
+            .as_ref()
+            .and_then(|metadata| Irc30Metadata::try_from(metadata).ok())
+    }
+}
+
 impl std::ops::AddAssign for NativeTokensBalance {
     fn add_assign(&mut self, rhs: Self) {
         if self.metadata.is_none() {
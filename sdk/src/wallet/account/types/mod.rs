@@ -27,6 +27,7 @@ use crate::{
             address::{dto::AddressDto, Address},
             output::{
                 dto::{OutputDto, OutputMetadataDto},
+                feature::{Irc27Metadata, Irc30Metadata},
                 AliasTransition, Output, OutputId, OutputMetadata,
             },
             payload::transaction::{dto::TransactionPayloadDto, TransactionId, TransactionPayload},
@@ -75,13 +76,15 @@ impl OutputData {
                 .iter()
                 .find(|a| a.address.inner == unlock_address)
             {
-                Some(Chain::from_u32_hardened(vec![
-                    44,
-                    account.coin_type,
-                    account.index,
-                    address.internal as u32,
-                    address.key_index,
-                ]))
+                (!account.watch_only).then(|| {
+                    Chain::from_u32_hardened(vec![
+                        44,
+                        account.coin_type,
+                        account.index,
+                        address.internal as u32,
+                        address.key_index,
+                    ])
+                })
             } else {
                 return Ok(None);
             }
@@ -96,6 +99,28 @@ impl OutputData {
             chain,
         }))
     }
+
+    /// Returns the [`Irc27Metadata`] of an NFT output, if its immutable metadata follows the standard.
+    pub fn irc_27_metadata(&self) -> Option<Irc27Metadata> {
+        match &self.output {
+            Output::Nft(nft_output) => nft_output
+                .immutable_features()
+                .metadata()
+                .and_then(|metadata| Irc27Metadata::try_from(metadata).ok()),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`Irc30Metadata`] of a foundry output, if its immutable metadata follows the standard.
+    pub fn irc_30_metadata(&self) -> Option<Irc30Metadata> {
+        match &self.output {
+            Output::Foundry(foundry_output) => foundry_output
+                .immutable_features()
+                .metadata()
+                .and_then(|metadata| Irc30Metadata::try_from(metadata).ok()),
+            _ => None,
+        }
+    }
 }
 
 /// Dto for an output with metadata
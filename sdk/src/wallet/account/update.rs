@@ -3,6 +3,8 @@
 
 use std::collections::HashMap;
 
+#[cfg(feature = "storage")]
+use crate::wallet::storage::manager::AccountChanges;
 use crate::{
     client::Client,
     types::block::output::{dto::OutputMetadataDto, OutputId},
@@ -16,8 +18,10 @@ use crate::{
 use crate::{
     types::{api::core::response::OutputWithMetadataResponse, block::payload::transaction::dto::TransactionPayloadDto},
     wallet::{
-        account::types::OutputDataDto,
-        events::types::{NewOutputEvent, SpentOutputEvent, TransactionInclusionEvent, WalletEvent},
+        account::types::{OutputDataDto, TransactionDto},
+        events::types::{
+            NewOutputEvent, SpentOutputEvent, TransactionConflictingEvent, TransactionInclusionEvent, WalletEvent,
+        },
     },
 };
 
@@ -27,7 +31,7 @@ impl Account {
         let mut account_details = self.write().await;
         account_details.alias = alias.to_string();
         #[cfg(feature = "storage")]
-        self.save(Some(&account_details)).await?;
+        self.save_changes(&account_details, AccountChanges::default()).await?;
         Ok(())
     }
 
@@ -45,6 +49,13 @@ impl Account {
         let mut account_details = self.write().await;
         #[cfg(feature = "events")]
         let account_index = account_details.index;
+        #[cfg(feature = "storage")]
+        let mut changes = AccountChanges {
+            addresses: true,
+            ..Default::default()
+        };
+        #[cfg(feature = "events")]
+        let mut events = Vec::new();
 
         // update used field of the addresses
         for address_with_unspent_outputs in addresses_with_unspent_outputs.iter() {
@@ -102,6 +113,8 @@ impl Account {
                     account_details.unspent_outputs.remove(&output_id);
                     if let Some(output_data) = account_details.outputs.get_mut(&output_id) {
                         output_data.metadata = output_metadata_response;
+                        #[cfg(feature = "storage")]
+                        changes.outputs.insert(output_id);
                     }
                 } else {
                     // not spent, just not synced, skip
@@ -119,15 +132,12 @@ impl Account {
                     if let Some(output_data) = account_details.outputs.get_mut(&output_id) {
                         output_data.metadata.is_spent = true;
                         output_data.is_spent = true;
+                        #[cfg(feature = "storage")]
+                        changes.outputs.insert(output_id);
                         #[cfg(feature = "events")]
-                        {
-                            self.event_emitter.lock().await.emit(
-                                account_index,
-                                WalletEvent::SpentOutput(Box::new(SpentOutputEvent {
-                                    output: OutputDataDto::from(&*output_data),
-                                })),
-                            );
-                        }
+                        events.push(WalletEvent::SpentOutput(Box::new(SpentOutputEvent {
+                            output: OutputDataDto::from(&*output_data),
+                        })));
                     }
                 }
             }
@@ -136,19 +146,22 @@ impl Account {
         // Add new synced outputs
         for output_data in unspent_outputs {
             // Insert output, if it's unknown emit the NewOutputEvent
-            if account_details
+            match account_details
                 .outputs
                 .insert(output_data.output_id, output_data.clone())
-                .is_none()
             {
-                #[cfg(feature = "events")]
-                {
-                    let transaction = account_details
-                        .incoming_transactions
-                        .get(output_data.output_id.transaction_id());
-                    self.event_emitter.lock().await.emit(
-                        account_index,
-                        WalletEvent::NewOutput(Box::new(NewOutputEvent {
+                // A known output with refreshed metadata, so its stored record has to be updated
+                Some(_) => {
+                    #[cfg(feature = "storage")]
+                    changes.outputs.insert(output_data.output_id);
+                }
+                None => {
+                    #[cfg(feature = "events")]
+                    {
+                        let transaction = account_details
+                            .incoming_transactions
+                            .get(output_data.output_id.transaction_id());
+                        events.push(WalletEvent::NewOutput(Box::new(NewOutputEvent {
                             output: OutputDataDto::from(&output_data),
                             transaction: transaction.as_ref().map(|tx| TransactionPayloadDto::from(&tx.payload)),
                             transaction_inputs: transaction.as_ref().map(|tx| {
@@ -158,8 +171,8 @@ impl Account {
                                     .map(OutputWithMetadataResponse::from)
                                     .collect()
                             }),
-                        })),
-                    );
+                        })));
+                    }
                 }
             };
             if !output_data.is_spent {
@@ -175,7 +188,14 @@ impl Account {
                 "[SYNC] storing account {} with new synced data",
                 account_details.alias()
             );
-            self.save(Some(&account_details)).await?;
+            // The events are logged in the same batch as the changes that caused them
+            #[cfg(feature = "events")]
+            changes.events.clone_from(&events);
+            self.save_changes(&account_details, changes).await?;
+        }
+        #[cfg(feature = "events")]
+        for event in events {
+            self.emit(account_index, event).await;
         }
         Ok(())
     }
@@ -190,6 +210,20 @@ impl Account {
         log::debug!("[SYNC] Update account with new synced transactions");
 
         let mut account_details = self.write().await;
+        #[cfg(feature = "events")]
+        let account_index = account_details.index;
+        #[cfg(feature = "storage")]
+        #[cfg_attr(not(feature = "events"), allow(unused_mut))]
+        let mut changes = AccountChanges {
+            outputs: spent_output_ids.iter().copied().collect(),
+            transactions: updated_transactions
+                .iter()
+                .map(|transaction| transaction.transaction_id)
+                .collect(),
+            ..Default::default()
+        };
+        #[cfg(feature = "events")]
+        let mut events = Vec::new();
 
         for transaction in updated_transactions {
             match transaction.inclusion_state {
@@ -202,13 +236,17 @@ impl Account {
                     );
                     #[cfg(feature = "events")]
                     {
-                        self.event_emitter.lock().await.emit(
-                            account_details.index,
-                            WalletEvent::TransactionInclusion(TransactionInclusionEvent {
-                                transaction_id,
-                                inclusion_state: transaction.inclusion_state,
-                            }),
-                        );
+                        events.push(WalletEvent::TransactionInclusion(TransactionInclusionEvent {
+                            transaction_id,
+                            inclusion_state: transaction.inclusion_state,
+                        }));
+                        if transaction.inclusion_state == InclusionState::Conflicting {
+                            events.push(WalletEvent::TransactionConflicting(Box::new(
+                                TransactionConflictingEvent {
+                                    transaction: TransactionDto::from(&transaction),
+                                },
+                            )));
+                        }
                     }
                 }
                 _ => {}
@@ -241,7 +279,13 @@ impl Account {
                 "[SYNC] storing account {} with new synced transactions",
                 account_details.alias()
             );
-            self.save(Some(&account_details)).await?;
+            #[cfg(feature = "events")]
+            changes.events.clone_from(&events);
+            self.save_changes(&account_details, changes).await?;
+        }
+        #[cfg(feature = "events")]
+        for event in events {
+            self.emit(account_index, event).await;
         }
         Ok(())
     }
@@ -266,7 +310,14 @@ impl Account {
         #[cfg(feature = "storage")]
         {
             log::debug!("[update_account_addresses] storing account {}", account_details.index());
-            self.save(Some(&account_details)).await?;
+            self.save_changes(
+                &account_details,
+                AccountChanges {
+                    addresses: true,
+                    ..Default::default()
+                },
+            )
+            .await?;
         }
         Ok(())
     }
@@ -296,9 +347,98 @@ impl Account {
                 "[SYNC] storing account {} after updating it with new client options",
                 account_details.alias()
             );
-            self.save(Some(&account_details)).await?;
+            self.save_changes(
+                &account_details,
+                AccountChanges {
+                    addresses: true,
+                    ..Default::default()
+                },
+            )
+            .await?;
         }
 
         Ok(())
     }
 }
+
+#[cfg(all(test, feature = "storage", feature = "test-utils"))]
+mod tests {
+    use crate::{
+        client::{
+            constants::SHIMMER_COIN_TYPE,
+            mock_node::MockNode,
+            secret::{mnemonic::MnemonicSecretManager, SecretManager},
+        },
+        types::block::output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+        wallet::{account::SyncOptions, ClientOptions, Wallet},
+    };
+
+    const MNEMONIC: &str = "inhale gorilla deny three celery song category owner lottery rent author wealth penalty crawl hobby obtain glad warm early rain clutch slab august bleak";
+
+    #[tokio::test]
+    async fn updated_output_metadata_is_stored() {
+        let storage_path = "test-storage/updated_output_metadata_is_stored";
+        std::fs::remove_dir_all(storage_path).ok();
+
+        let node = MockNode::builder().finish().unwrap();
+        let wallet = Wallet::builder()
+            .with_secret_manager(SecretManager::Mnemonic(
+                MnemonicSecretManager::try_from_mnemonic(MNEMONIC).unwrap(),
+            ))
+            .with_client_options(ClientOptions::new().with_node(node.url().as_str()).unwrap())
+            .with_coin_type(SHIMMER_COIN_TYPE)
+            .with_storage_path(storage_path)
+            .finish()
+            .await
+            .unwrap();
+        let account = wallet.create_account().finish().await.unwrap();
+        node.add_outputs(vec![BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(
+                *account.addresses().await.unwrap()[0].address().inner(),
+            ))
+            .finish_output(node.protocol_parameters().token_supply())
+            .unwrap()])
+            .unwrap();
+        account.sync(None).await.unwrap();
+
+        // Update the metadata of the known output, like when it's received again with a newer ledger index
+        let mut output_data = account.unspent_outputs(None).await.unwrap().remove(0);
+        output_data.metadata.ledger_index += 1;
+        let addresses_with_unspent_outputs = account.read().await.addresses_with_unspent_outputs.clone();
+        account
+            .update_account(
+                addresses_with_unspent_outputs,
+                vec![output_data.clone()],
+                Default::default(),
+                &SyncOptions::default(),
+            )
+            .await
+            .unwrap();
+
+        // The reloaded account has the updated metadata
+        let reloaded_account = account
+            .storage_manager
+            .lock()
+            .await
+            .get_accounts()
+            .await
+            .unwrap()
+            .remove(0);
+        assert_eq!(
+            reloaded_account.outputs.get(&output_data.output_id).unwrap().metadata,
+            output_data.metadata
+        );
+        assert_eq!(
+            reloaded_account
+                .unspent_outputs
+                .get(&output_data.output_id)
+                .unwrap()
+                .metadata,
+            output_data.metadata
+        );
+        drop(account);
+        drop(wallet);
+
+        std::fs::remove_dir_all(storage_path).ok();
+    }
+}
@@ -738,12 +738,12 @@ export function createAccount(accountMeta: AccountMeta, messageHandler: MessageH
          * @param nftsOptions The options for minting nfts.
          * @param transactionOptions The options to define a `RemainderValueStrategy`
          * or custom inputs.
-         * @returns The minting transaction.
+         * @returns The minting transactions, multiple ones if the nfts don't fit into a single transaction.
          */
         async mintNfts(
             nftsOptions: NftOptions[],
             transactionOptions?: TransactionOptions,
-        ): Promise<Transaction> {
+        ): Promise<Transaction[]> {
             const response = await messageHandler.callAccountMethod(
                 accountMeta.index,
                 {
@@ -896,12 +896,12 @@ export function createAccount(accountMeta: AccountMeta, messageHandler: MessageH
          * @param addressesWithAmount Addresses with amounts.
          * @param transactionOptions The options to define a `RemainderValueStrategy`
          * or custom inputs.
-         * @returns The sent transaction.
+         * @returns The sent transactions, multiple ones if the outputs don't fit into a single transaction.
          */
         async sendAmount(
             addressesWithAmount: AddressWithAmount[],
             transactionOptions?: TransactionOptions,
-        ): Promise<Transaction> {
+        ): Promise<Transaction[]> {
             const response = await messageHandler.callAccountMethod(
                 accountMeta.index,
                 {
@@ -33,12 +33,14 @@ public class SendAmount {
         };
 
         // Send transaction.
-        Transaction transaction = account.sendAmount(new org.iota.types.account_methods.SendAmount()
+        Transaction[] transactions = account.sendAmount(new org.iota.types.account_methods.SendAmount()
                         .withAddressesWithAmount(outputs));
 
         // Print transaction.
-        System.out.println("Transaction: " + transaction.getTransactionId());
-        System.out.println("Block sent: " + Env.EXPLORER + "/block/" + transaction.getBlockId());
+        for (Transaction transaction : transactions) {
+            System.out.println("Transaction: " + transaction.getTransactionId());
+            System.out.println("Block sent: " + Env.EXPLORER + "/block/" + transaction.getBlockId());
+        }
 
         // In case you are done and don't need the wallet instance anymore you can destroy the instance to clean up memory.
         // For this, check out the ´DestroyWallet.java´ example.
@@ -33,13 +33,15 @@ public class SendMicroTransaction {
         };
 
         // Configure outputs
-        Transaction transaction = account.sendAmount(
+        Transaction[] transactions = account.sendAmount(
                 new org.iota.types.account_methods.SendAmount().withAddressesWithAmount(outputs)
                         .withOptions(new TransactionOptions().withAllowMicroAmount(true)));
 
         // Print transaction
-        System.out.println("Transaction: " + transaction.getTransactionId());
-        System.out.println("Block sent: " + Env.EXPLORER + "/block/" + transaction.getBlockId());
+        for (Transaction transaction : transactions) {
+            System.out.println("Transaction: " + transaction.getTransactionId());
+            System.out.println("Block sent: " + Env.EXPLORER + "/block/" + transaction.getBlockId());
+        }
 
         // In case you are done and don't need the wallet instance anymore you can
         // destroy the instance to clean up memory.
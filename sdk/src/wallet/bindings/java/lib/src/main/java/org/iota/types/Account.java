@@ -402,12 +402,18 @@ public class Account extends AbstractObject {
     }
 
     /**
-     * Mints NFTs.
+     * Mints NFTs, in multiple transactions if they don't fit into a single one.
      *
      * @param options The options.
      */
-    public Transaction mintNfts(MintNfts options) throws WalletException {
-        return CustomGson.get().fromJson(callAccountMethod(options), Transaction.class);
+    public Transaction[] mintNfts(MintNfts options) throws WalletException {
+        JsonArray responsePayload = (JsonArray) callAccountMethod(options);
+
+        Transaction[] transactions = new Transaction[responsePayload.size()];
+        for (int i = 0; i < responsePayload.size(); i++)
+            transactions[i] = CustomGson.get().fromJson(responsePayload.get(i).getAsJsonObject(), Transaction.class);
+
+        return transactions;
     }
 
     /**
@@ -465,12 +471,18 @@ public class Account extends AbstractObject {
     }
 
     /**
-     * Sends an amount.
+     * Sends an amount, in multiple transactions if the outputs don't fit into a single one.
      *
      * @param options The options.
      */
-    public Transaction sendAmount(SendAmount options) throws WalletException {
-        return CustomGson.get().fromJson(callAccountMethod(options), Transaction.class);
+    public Transaction[] sendAmount(SendAmount options) throws WalletException {
+        JsonArray responsePayload = (JsonArray) callAccountMethod(options);
+
+        Transaction[] transactions = new Transaction[responsePayload.size()];
+        for (int i = 0; i < responsePayload.size(); i++)
+            transactions[i] = CustomGson.get().fromJson(responsePayload.get(i).getAsJsonObject(), Transaction.class);
+
+        return transactions;
     }
 
     /**
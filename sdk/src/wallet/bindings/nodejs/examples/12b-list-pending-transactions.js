@@ -12,7 +12,7 @@ async function run() {
         const { address } = bob.meta.publicAddresses[0];
         const amount = '1000000';
 
-        const response = await account.sendAmount([
+        const transactions = await account.sendAmount([
             {
                 address,
                 amount,
@@ -20,9 +20,11 @@ async function run() {
         ]);
         const pendingTransactions = await account.pendingTransactions()
         console.log('Listing Pending Transactions:', pendingTransactions[0]?.payload);
-        console.log(
-            `Check your block on ${process.env.EXPLORER_URL}/block/${response.blockId}`,
-        );
+        for (const transaction of transactions) {
+            console.log(
+                `Check your block on ${process.env.EXPLORER_URL}/block/${transaction.blockId}`,
+            );
+        }
     } catch (error) {
         console.log('Error: ', error);
     }
@@ -11,7 +11,7 @@ async function run() {
 
         await account.sync();
 
-        const response = await account.mintNfts([
+        const transactions = await account.mintNfts([
             {
                 // Hello in bytes
                 immutableMetadata: '0x48656c6c6f',
@@ -19,11 +19,13 @@ async function run() {
             }
         ]);
 
-        console.log(response);
+        console.log(transactions);
 
-        console.log(
-            `Check your block on ${process.env.EXPLORER_URL}/block/${response.blockId}`,
-        );
+        for (const transaction of transactions) {
+            console.log(
+                `Check your block on ${process.env.EXPLORER_URL}/block/${transaction.blockId}`,
+            );
+        }
     } catch (error) {
         console.log('Error: ', error);
     }
@@ -16,7 +16,7 @@ async function run() {
             'rms1qrrv7flg6lz5cssvzv2lsdt8c673khad060l4quev6q09tkm9mgtupgf0h0';
         const amount = '1';
 
-        const response = await account.sendAmount(
+        const transactions = await account.sendAmount(
             [{
                 address,
                 amount,
@@ -26,9 +26,11 @@ async function run() {
             }
         );
 
-        console.log(
-            `Check your block on ${process.env.EXPLORER_URL}/block/${response.blockId}`,
-        );
+        for (const transaction of transactions) {
+            console.log(
+                `Check your block on ${process.env.EXPLORER_URL}/block/${transaction.blockId}`,
+            );
+        }
     } catch (error) {
         console.log('Error: ', error);
     }
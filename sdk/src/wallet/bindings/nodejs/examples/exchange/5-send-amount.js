@@ -16,7 +16,7 @@ async function run() {
         const account = await manager.getAccount('Alice');
         console.log('Account:', account);
 
-        const response = await account.sendAmount([
+        const transactions = await account.sendAmount([
             {
                 //TODO: Replace with the address of your choice!
                 address: 'rms1qrrv7flg6lz5cssvzv2lsdt8c673khad060l4quev6q09tkm9mgtupgf0h0',
@@ -24,11 +24,13 @@ async function run() {
             },
         ]);
 
-        console.log(response);
+        console.log(transactions);
 
-        console.log(
-            `Check your block on https://explorer.testnet.shimmer.network/testnet/block/${response.blockId}`,
-        );
+        for (const transaction of transactions) {
+            console.log(
+                `Check your block on https://explorer.testnet.shimmer.network/testnet/block/${transaction.blockId}`,
+            );
+        }
     } catch (error) {
         console.log('Error: ', error);
     }
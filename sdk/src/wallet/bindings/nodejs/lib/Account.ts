@@ -746,12 +746,12 @@ export class Account {
      * @param nftsOptions The options for minting nfts.
      * @param transactionOptions The options to define a `RemainderValueStrategy`
      * or custom inputs.
-     * @returns The minting transaction.
+     * @returns The minting transactions, multiple ones if the nfts don't fit into a single transaction.
      */
     async mintNfts(
         nftsOptions: NftOptions[],
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction[]> {
         const response = await this.messageHandler.callAccountMethod(
             this.meta.index,
             {
@@ -904,12 +904,12 @@ export class Account {
      * @param addressesWithAmount Addresses with amounts.
      * @param transactionOptions The options to define a `RemainderValueStrategy`
      * or custom inputs.
-     * @returns The sent transaction.
+     * @returns The sent transactions, multiple ones if the outputs don't fit into a single transaction.
      */
     async sendAmount(
         addressesWithAmount: AddressWithAmount[],
         transactionOptions?: TransactionOptions,
-    ): Promise<Transaction> {
+    ): Promise<Transaction[]> {
         const response = await this.messageHandler.callAccountMethod(
             this.meta.index,
             {
@@ -22,6 +22,9 @@ pub enum Error {
     /// Address not found in account
     #[error("address {0} not found in account")]
     AddressNotFoundInAccount(String),
+    /// Alias not found in unspent outputs
+    #[error("alias not found in unspent outputs")]
+    AliasNotFoundInUnspentOutputs,
     /// Errors during backup creation or restoring
     #[error("backup failed {0}")]
     Backup(&'static str),
@@ -61,6 +64,12 @@ pub enum Error {
     /// Invalid output kind.
     #[error("invalid output kind: {0}")]
     InvalidOutputKind(String),
+    /// Invalid refund deadline
+    #[error("invalid refund deadline {deadline}, it needs to be after the current time {current_time}")]
+    InvalidRefundDeadline { deadline: u32, current_time: u32 },
+    /// Invalid vesting schedule
+    #[error("invalid vesting schedule: {0}")]
+    InvalidVestingSchedule(String),
     /// IO error. (storage, backup, restore)
     #[error("`{0}`")]
     Io(#[from] std::io::Error),
@@ -97,6 +106,16 @@ pub enum Error {
         /// The consolidation threshold.
         consolidation_threshold: usize,
     },
+    /// Sending outputs in multiple transactions failed after some of them were sent
+    #[error("sending chunk {failed_chunk} failed after {} transactions were sent: {error}", transactions.len())]
+    PartiallySent {
+        /// The transactions that were sent before the failed chunk.
+        transactions: Vec<crate::wallet::account::types::Transaction>,
+        /// The index of the failed chunk.
+        failed_chunk: usize,
+        /// The error of the failed chunk.
+        error: Box<Error>,
+    },
     /// Storage access error.
     #[error("error accessing storage: {0}")]
     Storage(String),
@@ -109,6 +128,9 @@ pub enum Error {
     /// Transaction not found
     #[error("transaction {0} not found")]
     TransactionNotFound(TransactionId),
+    /// Watch-only accounts can't use the secret manager
+    #[error("can't {0} with a watch-only account")]
+    WatchOnlyAccount(&'static str),
 }
 
 // Serialize type with Display error
@@ -164,9 +186,23 @@ impl From<crate::client::secret::ledger_nano::Error> for Error {
     }
 }
 
+#[cfg(feature = "mqtt")]
+impl From<crate::client::node_api::mqtt::Error> for Error {
+    fn from(error: crate::client::node_api::mqtt::Error) -> Self {
+        Self::Client(Box::new(crate::client::Error::Mqtt(error)))
+    }
+}
+
 #[cfg(feature = "rocksdb")]
 impl From<rocksdb::Error> for Error {
     fn from(error: rocksdb::Error) -> Self {
         Self::Storage(error.to_string())
     }
 }
+
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for Error {
+    fn from(error: rusqlite::Error) -> Self {
+        Self::Storage(error.to_string())
+    }
+}
@@ -4,84 +4,150 @@
 pub mod types;
 
 use std::{
-    collections::HashMap,
+    collections::HashSet,
     fmt::{Debug, Formatter, Result},
+    pin::Pin,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc,
+    },
+    task::{Context, Poll},
 };
 
-use self::types::{Event, WalletEvent, WalletEventType};
+use futures::{future::AbortHandle, Future, Stream, StreamExt};
+use tokio::sync::broadcast::{self, error::RecvError};
 
-type Handler<T> = Box<dyn Fn(&T) + Send + Sync + 'static>;
+use self::types::{Event, EventFilter, WalletEvent, WalletEventType};
+
+/// The number of events that are buffered for an [`EventStream`], before it lags behind and misses events.
+pub const EVENT_STREAM_CAPACITY: usize = 1024;
+
+type Handler<T> = Arc<dyn Fn(&T) + Send + Sync + 'static>;
+
+// A listener registered with `EventEmitter::on()`, its handler is invoked by a task that drains an event stream of the
+// event types of the listener
+struct Listener {
+    event_types: Vec<WalletEventType>,
+    handler: Handler<Event>,
+    abort_handle: AbortHandle,
+}
+
+impl Listener {
+    fn spawn(emitter: &EventEmitter, event_types: Vec<WalletEventType>, handler: Handler<Event>) -> Self {
+        // Subscribed before the task is spawned, so events that are emitted before the task runs aren't missed
+        let mut events = emitter.subscribe(EventFilter::new().with_event_types(event_types.clone()));
+        let task_handler = handler.clone();
+        let abort_handle = spawn_listener_task(async move {
+            while let Some(event) = events.next().await {
+                task_handler(&event);
+            }
+        });
+
+        Self {
+            event_types,
+            handler,
+            abort_handle,
+        }
+    }
+}
+
+// Spawns the task of a listener, the wasm bindings don't run a Tokio runtime, so the task is spawned on the current
+// thread there
+fn spawn_listener_task(future: impl Future<Output = ()> + Send + 'static) -> AbortHandle {
+    let (future, abort_handle) = futures::future::abortable(future);
+    #[cfg(not(target_family = "wasm"))]
+    tokio::spawn(future);
+    #[cfg(target_family = "wasm")]
+    wasm_bindgen_futures::spawn_local(async move {
+        future.await.ok();
+    });
+    abort_handle
+}
 
 pub struct EventEmitter {
-    handlers: HashMap<WalletEventType, Vec<Handler<Event>>>,
+    sender: broadcast::Sender<Event>,
+    listeners: Vec<Listener>,
 }
 
 impl EventEmitter {
     /// Creates a new instance of `EventEmitter`.
     pub fn new() -> Self {
+        let (sender, _) = broadcast::channel(EVENT_STREAM_CAPACITY);
         Self {
-            handlers: HashMap::new(),
+            sender,
+            listeners: Vec::new(),
         }
     }
 
+    /// Returns a stream of the events that match the `filter`, emitted from now on.
+    pub fn subscribe(&self, filter: EventFilter) -> EventStream {
+        EventStream::new(self.sender.subscribe(), filter)
+    }
+
     /// Registers function `handler` as a listener for a `WalletEventType`. There may be
     /// multiple listeners for a single event.
-    pub fn on<F>(&mut self, events: Vec<WalletEventType>, handler: F)
+    ///
+    /// The handler is invoked by a task that drains an [`EventStream`], so like the stream it misses events if it
+    /// lags behind. Outside of wasm the task is spawned on the Tokio runtime, so it's only reachable through the async
+    /// [`Wallet::listen()`](crate::wallet::Wallet::listen).
+    pub(crate) fn on<F>(&mut self, events: Vec<WalletEventType>, handler: F)
     where
         F: Fn(&Event) + 'static + Clone + Send + Sync,
     {
         // if no event is provided the handler is registered for all event types
-        if events.is_empty() {
+        let events = if events.is_empty() {
             // we could use a crate like strum or a macro to iterate over all values, but not sure if it's worth it
-            for event_type in &[
+            vec![
                 WalletEventType::NewOutput,
                 WalletEventType::SpentOutput,
                 WalletEventType::TransactionInclusion,
                 WalletEventType::TransactionProgress,
                 WalletEventType::ConsolidationRequired,
+                WalletEventType::BalanceChanged,
+                WalletEventType::OutputClaimable,
+                WalletEventType::OutputExpiring,
+                WalletEventType::TransactionConflicting,
                 #[cfg(feature = "ledger_nano")]
                 WalletEventType::LedgerAddressGeneration,
-            ] {
-                let event_handlers = self.handlers.entry(*event_type).or_insert_with(Vec::new);
-                event_handlers.push(Box::new(handler.clone()));
-            }
-        }
-        for event in events.into_iter() {
-            let event_handlers = self.handlers.entry(event).or_insert_with(Vec::new);
-            event_handlers.push(Box::new(handler.clone()));
-        }
+            ]
+        } else {
+            events
+        };
+        let listener = Listener::spawn(self, events, Arc::new(handler));
+        self.listeners.push(listener);
     }
 
     /// Removes handlers for each given `WalletEventType`.
-    /// If no `WalletEventType` is given, handlers will be removed for all event types.
-    pub fn clear(&mut self, events: Vec<WalletEventType>) {
-        // if no event is provided handlers are removed for all event types
-        if events.is_empty() {
-            self.handlers.clear();
-        }
-        for event in events {
-            self.handlers.remove(&event);
+    /// If no `WalletEventType` is given, handlers will be removed for all event types. Listeners that keep some of
+    /// their event types are spawned again, so like [`EventEmitter::on()`] it's only reachable through the async
+    /// [`Wallet::clear_listeners()`](crate::wallet::Wallet::clear_listeners).
+    pub(crate) fn clear(&mut self, events: Vec<WalletEventType>) {
+        let mut listeners = Vec::with_capacity(self.listeners.len());
+        for listener in std::mem::take(&mut self.listeners) {
+            // if no event is provided handlers are removed for all event types
+            let event_types = listener
+                .event_types
+                .iter()
+                .copied()
+                .filter(|event_type| !events.is_empty() && !events.contains(event_type))
+                .collect::<Vec<_>>();
+            if event_types.len() == listener.event_types.len() {
+                listeners.push(listener);
+                continue;
+            }
+            listener.abort_handle.abort();
+            // The handler keeps listening to its remaining event types
+            if !event_types.is_empty() {
+                listeners.push(Listener::spawn(self, event_types, listener.handler));
+            }
         }
+        self.listeners = listeners;
     }
 
-    /// Invokes all listeners of `event`, passing a reference to `payload` as an
-    /// argument to each of them.
+    /// Sends the event to all event streams, the listeners receive it from their event streams.
     pub fn emit(&self, account_index: u32, event: WalletEvent) {
-        let event_type = match &event {
-            WalletEvent::NewOutput(_) => WalletEventType::NewOutput,
-            WalletEvent::SpentOutput(_) => WalletEventType::SpentOutput,
-            WalletEvent::TransactionInclusion(_) => WalletEventType::TransactionInclusion,
-            WalletEvent::TransactionProgress(_) => WalletEventType::TransactionProgress,
-            WalletEvent::ConsolidationRequired => WalletEventType::ConsolidationRequired,
-            #[cfg(feature = "ledger_nano")]
-            WalletEvent::LedgerAddressGeneration(_) => WalletEventType::LedgerAddressGeneration,
-        };
-        let event = Event { account_index, event };
-        if let Some(handlers) = self.handlers.get(&event_type) {
-            for handler in handlers {
-                handler(&event);
-            }
-        }
+        // Sending only fails if there are no event streams
+        self.sender.send(Event { account_index, event }).ok();
     }
 }
 
@@ -91,16 +157,86 @@ impl Default for EventEmitter {
     }
 }
 
+impl Drop for EventEmitter {
+    fn drop(&mut self) {
+        for listener in &self.listeners {
+            listener.abort_handle.abort();
+        }
+    }
+}
+
 impl Debug for EventEmitter {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(
             f,
             "event_types_with_handlers: {:?}",
-            self.handlers.keys().collect::<Vec<&WalletEventType>>()
+            self.listeners
+                .iter()
+                .flat_map(|listener| &listener.event_types)
+                .collect::<HashSet<&WalletEventType>>()
         )
     }
 }
 
+/// A stream of wallet events that match an [`EventFilter`].
+/// If the stream isn't polled fast enough, the oldest events are skipped and counted in
+/// [`EventStream::missed_events()`].
+pub struct EventStream {
+    inner: Pin<Box<dyn Stream<Item = Event> + Send>>,
+    missed_events: Arc<AtomicU64>,
+}
+
+impl EventStream {
+    fn new(receiver: broadcast::Receiver<Event>, filter: EventFilter) -> Self {
+        let missed_events = Arc::new(AtomicU64::new(0));
+        let inner = futures::stream::unfold(
+            (receiver, filter, missed_events.clone()),
+            |(mut receiver, filter, missed_events)| async move {
+                loop {
+                    match receiver.recv().await {
+                        Ok(event) => {
+                            if filter.matches(&event) {
+                                return Some((event, (receiver, filter, missed_events)));
+                            }
+                        }
+                        Err(RecvError::Lagged(count)) => {
+                            log::warn!("[EventStream] lagged behind, missed {count} events");
+                            missed_events.fetch_add(count, Ordering::Relaxed);
+                        }
+                        // The wallet was dropped
+                        Err(RecvError::Closed) => return None,
+                    }
+                }
+            },
+        );
+
+        Self {
+            inner: Box::pin(inner),
+            missed_events,
+        }
+    }
+
+    /// Returns the number of events that were skipped because the stream lagged behind, this includes events that
+    /// wouldn't have matched the filter.
+    pub fn missed_events(&self) -> u64 {
+        self.missed_events.load(Ordering::Relaxed)
+    }
+}
+
+impl Stream for EventStream {
+    type Item = Event;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        self.inner.as_mut().poll_next(cx)
+    }
+}
+
+impl Debug for EventStream {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "EventStream {{ missed_events: {} }}", self.missed_events())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{
@@ -109,16 +245,30 @@ mod tests {
             atomic::{AtomicUsize, Ordering},
             Arc,
         },
+        time::Duration,
     };
 
+    use futures::{FutureExt, StreamExt};
+
     use super::{
-        types::{TransactionInclusionEvent, TransactionProgressEvent, WalletEvent, WalletEventType},
-        EventEmitter,
+        types::{EventFilter, TransactionInclusionEvent, TransactionProgressEvent, WalletEvent, WalletEventType},
+        EventEmitter, EVENT_STREAM_CAPACITY,
     };
     use crate::{types::block::payload::transaction::TransactionId, wallet::account::types::InclusionState};
 
-    #[test]
-    fn events() {
+    // Waits until the listener tasks invoked their handlers for the emitted events
+    async fn assert_event_count(event_counter: &AtomicUsize, expected: usize) {
+        for _ in 0..100 {
+            if event_counter.load(Ordering::SeqCst) >= expected {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert_eq!(expected, event_counter.load(Ordering::SeqCst));
+    }
+
+    #[tokio::test]
+    async fn events() {
         let mut emitter = EventEmitter::new();
         let event_counter = Arc::new(AtomicUsize::new(0));
 
@@ -162,14 +312,19 @@ mod tests {
             }),
         );
 
-        assert_eq!(3, event_counter.load(Ordering::SeqCst));
+        assert_event_count(&event_counter, 3).await;
 
         // remove handlers of single event
         emitter.clear(vec![WalletEventType::ConsolidationRequired]);
         // emit event of removed type
         emitter.emit(0, WalletEvent::ConsolidationRequired);
+        // the handler still listens to the other event types
+        emitter.emit(
+            0,
+            WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs),
+        );
 
-        assert_eq!(3, event_counter.load(Ordering::SeqCst));
+        assert_event_count(&event_counter, 4).await;
 
         // remove handlers of all events
         emitter.clear(vec![]);
@@ -188,7 +343,7 @@ mod tests {
                 inclusion_state: InclusionState::Confirmed,
             }),
         );
-        assert_eq!(3, event_counter.load(Ordering::SeqCst));
+        assert_event_count(&event_counter, 4).await;
 
         // listen to a single event
         let event_counter_clone = Arc::clone(&event_counter);
@@ -197,9 +352,61 @@ mod tests {
             event_counter_clone.fetch_add(1, Ordering::SeqCst);
         });
 
-        for _ in 0..1_000_000 {
+        for _ in 0..EVENT_STREAM_CAPACITY {
             emitter.emit(0, WalletEvent::ConsolidationRequired);
         }
-        assert_eq!(1_000_003, event_counter.load(Ordering::SeqCst));
+        assert_event_count(&event_counter, EVENT_STREAM_CAPACITY + 4).await;
+    }
+
+    #[test]
+    fn event_stream() {
+        let emitter = EventEmitter::new();
+        let mut all_events = emitter.subscribe(EventFilter::new());
+        let mut filtered_events = emitter.subscribe(
+            EventFilter::new()
+                .with_account_indexes([1])
+                .with_event_types([WalletEventType::ConsolidationRequired]),
+        );
+
+        emitter.emit(0, WalletEvent::ConsolidationRequired);
+        emitter.emit(
+            1,
+            WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs),
+        );
+        emitter.emit(1, WalletEvent::ConsolidationRequired);
+
+        for (account_index, event) in [
+            (0, WalletEvent::ConsolidationRequired),
+            (
+                1,
+                WalletEvent::TransactionProgress(TransactionProgressEvent::SelectingInputs),
+            ),
+            (1, WalletEvent::ConsolidationRequired),
+        ] {
+            let received = all_events.next().now_or_never().flatten().unwrap();
+            assert_eq!(received.account_index, account_index);
+            assert_eq!(received.event, event);
+        }
+        assert!(all_events.next().now_or_never().is_none());
+
+        let received = filtered_events.next().now_or_never().flatten().unwrap();
+        assert_eq!(received.account_index, 1);
+        assert_eq!(received.event, WalletEvent::ConsolidationRequired);
+        assert!(filtered_events.next().now_or_never().is_none());
+
+        // A stream that isn't polled skips the oldest events
+        for _ in 0..EVENT_STREAM_CAPACITY + 5 {
+            emitter.emit(0, WalletEvent::ConsolidationRequired);
+        }
+        let mut received = 0;
+        while all_events.next().now_or_never().flatten().is_some() {
+            received += 1;
+        }
+        assert_eq!(received, EVENT_STREAM_CAPACITY);
+        assert_eq!(all_events.missed_events(), 5);
+
+        // The stream ends when the emitter is dropped
+        drop(emitter);
+        assert_eq!(all_events.next().now_or_never(), Some(None));
     }
 }
@@ -13,7 +13,7 @@ use crate::{
             payload::transaction::{dto::TransactionPayloadDto, TransactionId},
         },
     },
-    wallet::account::types::{InclusionState, OutputDataDto},
+    wallet::account::types::{AccountBalanceDto, InclusionState, OutputDataDto, TransactionDto},
 };
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -24,26 +24,44 @@ pub struct Event {
     pub event: WalletEvent,
 }
 
+/// An event stored in the event log of the wallet.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventLogEntry {
+    /// The sequence number of the event, increasing by one for every logged event, starting at 1.
+    pub sequence_number: u64,
+    /// The event.
+    pub event: Event,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub enum WalletEvent {
+    BalanceChanged(Box<BalanceChangedEvent>),
     ConsolidationRequired,
     #[cfg(feature = "ledger_nano")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     LedgerAddressGeneration(AddressData),
     NewOutput(Box<NewOutputEvent>),
+    OutputClaimable(Box<OutputClaimableEvent>),
+    OutputExpiring(Box<OutputExpiringEvent>),
     SpentOutput(Box<SpentOutputEvent>),
+    TransactionConflicting(Box<TransactionConflictingEvent>),
     TransactionInclusion(TransactionInclusionEvent),
     TransactionProgress(TransactionProgressEvent),
 }
 
 #[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
 pub enum WalletEventType {
+    BalanceChanged,
     ConsolidationRequired,
     #[cfg(feature = "ledger_nano")]
     #[cfg_attr(docsrs, doc(cfg(feature = "ledger_nano")))]
     LedgerAddressGeneration,
     NewOutput,
+    OutputClaimable,
+    OutputExpiring,
     SpentOutput,
+    TransactionConflicting,
     TransactionInclusion,
     TransactionProgress,
 }
@@ -53,11 +71,15 @@ impl TryFrom<&str> for WalletEventType {
 
     fn try_from(value: &str) -> Result<Self, Self::Error> {
         let event_type = match value {
+            "BalanceChanged" => Self::BalanceChanged,
             "ConsolidationRequired" => Self::ConsolidationRequired,
             #[cfg(feature = "ledger_nano")]
             "LedgerAddressGeneration" => Self::LedgerAddressGeneration,
             "NewOutput" => Self::NewOutput,
+            "OutputClaimable" => Self::OutputClaimable,
+            "OutputExpiring" => Self::OutputExpiring,
             "SpentOutput" => Self::SpentOutput,
+            "TransactionConflicting" => Self::TransactionConflicting,
             "TransactionInclusion" => Self::TransactionInclusion,
             "TransactionProgress" => Self::TransactionProgress,
             _ => return Err(format!("invalid event type {value}")),
@@ -66,6 +88,70 @@ impl TryFrom<&str> for WalletEventType {
     }
 }
 
+impl From<&WalletEvent> for WalletEventType {
+    fn from(event: &WalletEvent) -> Self {
+        match event {
+            WalletEvent::BalanceChanged(_) => Self::BalanceChanged,
+            WalletEvent::ConsolidationRequired => Self::ConsolidationRequired,
+            #[cfg(feature = "ledger_nano")]
+            WalletEvent::LedgerAddressGeneration(_) => Self::LedgerAddressGeneration,
+            WalletEvent::NewOutput(_) => Self::NewOutput,
+            WalletEvent::OutputClaimable(_) => Self::OutputClaimable,
+            WalletEvent::OutputExpiring(_) => Self::OutputExpiring,
+            WalletEvent::SpentOutput(_) => Self::SpentOutput,
+            WalletEvent::TransactionConflicting(_) => Self::TransactionConflicting,
+            WalletEvent::TransactionInclusion(_) => Self::TransactionInclusion,
+            WalletEvent::TransactionProgress(_) => Self::TransactionProgress,
+        }
+    }
+}
+
+/// Filter for wallet events, an empty list matches all account indexes or event types.
+#[derive(Clone, Debug, Default, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventFilter {
+    /// The indexes of the accounts to receive events for.
+    #[serde(default)]
+    pub account_indexes: Vec<u32>,
+    /// The types of events to receive.
+    #[serde(default)]
+    pub event_types: Vec<WalletEventType>,
+}
+
+impl EventFilter {
+    /// Creates a new `EventFilter` that matches all events.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only match events of the given accounts.
+    pub fn with_account_indexes(mut self, account_indexes: impl IntoIterator<Item = u32>) -> Self {
+        self.account_indexes = account_indexes.into_iter().collect();
+        self
+    }
+
+    /// Only match events of the given types.
+    pub fn with_event_types(mut self, event_types: impl IntoIterator<Item = WalletEventType>) -> Self {
+        self.event_types = event_types.into_iter().collect();
+        self
+    }
+
+    /// Checks if an event matches the filter.
+    pub fn matches(&self, event: &Event) -> bool {
+        (self.account_indexes.is_empty() || self.account_indexes.contains(&event.account_index))
+            && (self.event_types.is_empty() || self.event_types.contains(&WalletEventType::from(&event.event)))
+    }
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BalanceChangedEvent {
+    /// The balance before the change.
+    pub previous_balance: AccountBalanceDto,
+    /// The new balance.
+    pub balance: AccountBalanceDto,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct NewOutputEvent {
@@ -79,12 +165,33 @@ pub struct NewOutputEvent {
     pub transaction_inputs: Option<Vec<OutputWithMetadataResponse>>,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct OutputClaimableEvent {
+    /// The output that can be claimed now, because its timelock or expiration passed.
+    pub output: OutputDataDto,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OutputExpiringEvent {
+    /// The claimable output that expires soon.
+    pub output: OutputDataDto,
+    /// Unix timestamp in seconds after which the output can only be unlocked by the expiration return address.
+    pub expiration_time: u32,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
 pub struct SpentOutputEvent {
     /// The spent output.
     pub output: OutputDataDto,
 }
 
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct TransactionConflictingEvent {
+    /// The pending transaction that became conflicting.
+    pub transaction: TransactionDto,
+}
+
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct TransactionInclusionEvent {
@@ -19,7 +19,7 @@ use crate::{
     wallet::{
         account::{
             types::{AccountAddress, AddressWithUnspentOutputs, TransactionDto},
-            AccountDetails, OutputDataDto,
+            Account, AccountDetails, OutputDataDto,
         },
         AddressWithAmount,
     },
@@ -112,6 +112,25 @@ pub struct AccountDetailsDto {
     pub native_token_foundries: HashMap<FoundryId, FoundryOutputDto>,
 }
 
+impl AccountDetailsDto {
+    /// Creates the dto of an account, including the historical transactions and spent outputs that are only loaded
+    /// from the storage when requested.
+    pub async fn from_account(account: &Account) -> crate::wallet::Result<Self> {
+        let transactions = account.transactions().await?;
+        let outputs = account.outputs(None).await?;
+        let mut account_details_dto = Self::from(&*account.read().await);
+        account_details_dto.outputs = outputs
+            .iter()
+            .map(|output_data| (output_data.output_id, OutputDataDto::from(output_data)))
+            .collect();
+        account_details_dto.transactions = transactions
+            .iter()
+            .map(|transaction| (transaction.transaction_id, TransactionDto::from(transaction)))
+            .collect();
+        Ok(account_details_dto)
+    }
+}
+
 impl From<&AccountDetails> for AccountDetailsDto {
     fn from(value: &AccountDetails) -> Self {
         Self {
@@ -197,8 +197,7 @@ impl WalletMessageHandler {
                         .await?;
                     let mut account_dtos = Vec::new();
                     for account in accounts {
-                        let account = account.read().await;
-                        account_dtos.push(AccountDetailsDto::from(&*account));
+                        account_dtos.push(AccountDetailsDto::from_account(&account).await?);
                     }
                     Ok(Response::Accounts(account_dtos))
                 })
@@ -595,7 +594,7 @@ impl WalletMessageHandler {
                 Ok(Response::OutputIds(output_ids))
             }
             AccountMethod::GetOutput { output_id } => {
-                let output_data = account.get_output(&output_id).await;
+                let output_data = account.get_output(&output_id).await?;
                 Ok(Response::OutputData(
                     output_data.as_ref().map(OutputDataDto::from).map(Box::new),
                 ))
@@ -606,7 +605,7 @@ impl WalletMessageHandler {
                 Ok(Response::Output(OutputDto::from(&output)))
             }
             AccountMethod::GetTransaction { transaction_id } => {
-                let transaction = account.get_transaction(&transaction_id).await;
+                let transaction = account.get_transaction(&transaction_id).await?;
                 Ok(Response::Transaction(
                     transaction.as_ref().map(TransactionDto::from).map(Box::new),
                 ))
@@ -739,7 +738,7 @@ impl WalletMessageHandler {
             }
             AccountMethod::MintNfts { nfts_options, options } => {
                 convert_async_panics(|| async {
-                    let transaction = account
+                    let transactions = account
                         .mint_nfts(
                             nfts_options
                                 .iter()
@@ -748,7 +747,9 @@ impl WalletMessageHandler {
                             options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
                         )
                         .await?;
-                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                    Ok(Response::Transactions(
+                        transactions.iter().map(TransactionDto::from).collect(),
+                    ))
                 })
                 .await
             }
@@ -826,7 +827,7 @@ impl WalletMessageHandler {
                 options,
             } => {
                 convert_async_panics(|| async {
-                    let transaction = account
+                    let transactions = account
                         .send_amount(
                             addresses_with_amount
                                 .iter()
@@ -835,7 +836,9 @@ impl WalletMessageHandler {
                             options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
                         )
                         .await?;
-                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                    Ok(Response::Transactions(
+                        transactions.iter().map(TransactionDto::from).collect(),
+                    ))
                 })
                 .await
             }
@@ -869,6 +872,74 @@ impl WalletMessageHandler {
                 })
                 .await
             }
+            AccountMethod::UpdateAliasState {
+                alias_id,
+                state_metadata,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let transaction = account
+                        .update_alias_state(
+                            AliasId::try_from(&alias_id)?,
+                            state_metadata.clone(),
+                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                        )
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            AccountMethod::SetAliasStateController {
+                alias_id,
+                address,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let transaction = account
+                        .set_alias_state_controller(
+                            AliasId::try_from(&alias_id)?,
+                            &address,
+                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                        )
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            AccountMethod::SetAliasGovernor {
+                alias_id,
+                address,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let transaction = account
+                        .set_alias_governor(
+                            AliasId::try_from(&alias_id)?,
+                            &address,
+                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                        )
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
+            AccountMethod::TransferAlias {
+                alias_id,
+                address,
+                options,
+            } => {
+                convert_async_panics(|| async {
+                    let transaction = account
+                        .transfer_alias(
+                            AliasId::try_from(&alias_id)?,
+                            &address,
+                            options.as_ref().map(TransactionOptions::try_from_dto).transpose()?,
+                        )
+                        .await?;
+                    Ok(Response::SentTransaction(TransactionDto::from(&transaction)))
+                })
+                .await
+            }
             AccountMethod::SetAlias { alias } => {
                 convert_async_panics(|| async {
                     account.set_alias(&alias).await?;
@@ -1053,26 +1124,21 @@ impl WalletMessageHandler {
         }
 
         match builder.finish().await {
-            Ok(account) => {
-                let account = account.read().await;
-                Ok(Response::Account(AccountDetailsDto::from(&*account)))
-            }
+            Ok(account) => Ok(Response::Account(AccountDetailsDto::from_account(&account).await?)),
             Err(e) => Err(e),
         }
     }
 
     async fn get_account(&self, account_id: &AccountIdentifier) -> Result<Response> {
         let account = self.wallet.get_account(account_id.clone()).await?;
-        let account = account.read().await;
-        Ok(Response::Account(AccountDetailsDto::from(&*account)))
+        Ok(Response::Account(AccountDetailsDto::from_account(&account).await?))
     }
 
     async fn get_accounts(&self) -> Result<Response> {
         let accounts = self.wallet.get_accounts().await?;
         let mut account_dtos = Vec::new();
         for account in accounts {
-            let account = account.read().await;
-            account_dtos.push(AccountDetailsDto::from(&*account));
+            account_dtos.push(AccountDetailsDto::from_account(&account).await?);
         }
         Ok(Response::Accounts(account_dtos))
     }
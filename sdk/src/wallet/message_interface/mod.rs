@@ -44,6 +44,12 @@ where
             SecretManagerDto::LedgerNano(is_simulator) => s.serialize_str(&format!("ledgerNano({is_simulator})")),
             SecretManagerDto::Mnemonic(_) => s.serialize_str("mnemonic(<omitted>)"),
             SecretManagerDto::Placeholder => s.serialize_str("placeholder"),
+            SecretManagerDto::Remote(remote) => {
+                let mut remote_dto = remote.clone();
+                // Remove bearer token
+                remote_dto.bearer_token = None;
+                s.serialize_str(&format!("{remote_dto:?}"))
+            }
             #[cfg(feature = "stronghold")]
             SecretManagerDto::Stronghold(stronghold) => {
                 let mut stronghold_dto = stronghold.clone();
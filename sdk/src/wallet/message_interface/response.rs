@@ -83,7 +83,9 @@ pub enum Response {
     Transaction(Option<Box<TransactionDto>>),
     /// Response for
     /// [`Transactions`](crate::wallet::message_interface::AccountMethod::Transactions),
-    /// [`PendingTransactions`](crate::wallet::message_interface::AccountMethod::PendingTransactions)
+    /// [`PendingTransactions`](crate::wallet::message_interface::AccountMethod::PendingTransactions),
+    /// [`MintNfts`](crate::wallet::message_interface::AccountMethod::MintNfts),
+    /// [`SendAmount`](crate::wallet::message_interface::AccountMethod::SendAmount)
     Transactions(Vec<TransactionDto>),
     /// Response for
     /// [`SignTransactionEssence`](crate::wallet::message_interface::AccountMethod::SignTransactionEssence)
@@ -111,9 +113,6 @@ pub enum Response {
     /// [`ConsolidateOutputs`](crate::wallet::message_interface::AccountMethod::ConsolidateOutputs)
     /// [`ClaimOutputs`](crate::wallet::message_interface::AccountMethod::ClaimOutputs)
     /// [`CreateAliasOutput`](crate::wallet::message_interface::AccountMethod::CreateAliasOutput)
-    /// [`SendAmount`](crate::wallet::message_interface::AccountMethod::SendAmount),
-    /// [`MintNfts`](crate::wallet::message_interface::AccountMethod::MintNfts),
-    /// [`SendAmount`](crate::wallet::message_interface::AccountMethod::SendAmount),
     /// [`SendNativeTokens`](crate::wallet::message_interface::AccountMethod::SendNativeTokens),
     /// [`SendNft`](crate::wallet::message_interface::AccountMethod::SendNft),
     /// [`SendOutputs`](crate::wallet::message_interface::AccountMethod::SendOutputs)
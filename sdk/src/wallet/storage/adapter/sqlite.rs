@@ -0,0 +1,88 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashMap, path::Path, sync::Arc};
+
+use rusqlite::{params, Connection, OptionalExtension};
+use tokio::sync::Mutex;
+
+use super::StorageAdapter;
+
+/// The storage id.
+pub const STORAGE_ID: &str = "Sqlite";
+
+/// Key value storage adapter backed by a single SQLite table, so the records can be inspected with standard tooling.
+#[derive(Debug)]
+pub struct SqliteStorageAdapter {
+    db: Arc<Mutex<Connection>>,
+}
+
+impl SqliteStorageAdapter {
+    /// Initialises the storage adapter.
+    pub fn new(path: impl AsRef<Path>) -> crate::wallet::Result<Self> {
+        if let Some(parent) = path.as_ref().parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let db = Connection::open(path)?;
+        db.execute_batch(
+            "PRAGMA journal_mode = WAL;
+            CREATE TABLE IF NOT EXISTS records (key TEXT PRIMARY KEY NOT NULL, value TEXT NOT NULL);",
+        )?;
+        Ok(Self {
+            db: Arc::new(Mutex::new(db)),
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageAdapter for SqliteStorageAdapter {
+    fn id(&self) -> &'static str {
+        STORAGE_ID
+    }
+
+    /// Gets the record associated with the given key from the storage.
+    async fn get(&self, key: &str) -> crate::wallet::Result<Option<String>> {
+        Ok(self
+            .db
+            .lock()
+            .await
+            .query_row("SELECT value FROM records WHERE key = ?1", params![key], |row| {
+                row.get(0)
+            })
+            .optional()?)
+    }
+
+    /// Saves or updates a record on the storage.
+    async fn set(&mut self, key: &str, record: String) -> crate::wallet::Result<()> {
+        self.db.lock().await.execute(
+            "INSERT INTO records (key, value) VALUES (?1, ?2) ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+            params![key, record],
+        )?;
+        Ok(())
+    }
+
+    /// Batch writes records to the storage.
+    async fn batch_set(&mut self, records: HashMap<String, String>) -> crate::wallet::Result<()> {
+        let mut db = self.db.lock().await;
+        let batch = db.transaction()?;
+        {
+            let mut statement = batch.prepare_cached(
+                "INSERT INTO records (key, value) VALUES (?1, ?2) ON CONFLICT(key) DO UPDATE SET value = excluded.value",
+            )?;
+            for (key, value) in records {
+                statement.execute(params![key, value])?;
+            }
+        }
+        batch.commit()?;
+        Ok(())
+    }
+
+    /// Removes a record from the storage.
+    async fn remove(&mut self, key: &str) -> crate::wallet::Result<()> {
+        self.db
+            .lock()
+            .await
+            .execute("DELETE FROM records WHERE key = ?1", params![key])?;
+        Ok(())
+    }
+}
@@ -8,10 +8,20 @@ pub const DEFAULT_STORAGE_PATH: &str = "./storage";
 #[cfg(feature = "rocksdb")]
 pub(crate) const ROCKSDB_FOLDERNAME: &str = "walletdb";
 
+/// The default SQLite storage path.
+#[cfg(feature = "sqlite")]
+pub(crate) const SQLITE_FILENAME: &str = "walletdb.sqlite";
+
+/// The file extension that selects the SQLite storage.
+#[cfg(feature = "sqlite")]
+pub(crate) const SQLITE_FILE_EXTENSION: &str = "sqlite";
+
 pub const fn default_storage_path() -> &'static str {
     #[cfg(feature = "rocksdb")]
     return ROCKSDB_FOLDERNAME;
-    #[cfg(not(feature = "rocksdb"))]
+    #[cfg(all(feature = "sqlite", not(feature = "rocksdb")))]
+    return SQLITE_FILENAME;
+    #[cfg(not(any(feature = "rocksdb", feature = "sqlite")))]
     DEFAULT_STORAGE_PATH
 }
 
@@ -23,9 +33,25 @@ pub(crate) const ACCOUNTS_INDEXATION_KEY: &str = "iota-wallet-accounts";
 pub(crate) const ACCOUNT_INDEXATION_KEY: &str = "iota-wallet-account-";
 
 pub(crate) const ACCOUNT_SYNC_OPTIONS: &str = "sync-options";
-
-pub(crate) const DATABASE_SCHEMA_VERSION: u8 = 1;
+pub(crate) const ACCOUNT_PAYMENT_QUEUE_KEY: &str = "payment-queue";
+pub(crate) const ACCOUNT_ADDRESSES_KEY: &str = "addresses";
+pub(crate) const ACCOUNT_OUTPUTS_KEY: &str = "outputs";
+pub(crate) const ACCOUNT_OUTPUT_KEY: &str = "output-";
+pub(crate) const ACCOUNT_TRANSACTIONS_KEY: &str = "transactions";
+pub(crate) const ACCOUNT_TRANSACTION_KEY: &str = "transaction-";
+pub(crate) const ACCOUNT_INCOMING_TRANSACTIONS_KEY: &str = "incoming-transactions";
+pub(crate) const ACCOUNT_INCOMING_TRANSACTION_KEY: &str = "incoming-transaction-";
+
+pub(crate) const DATABASE_SCHEMA_VERSION: u8 = 2;
 pub(crate) const DATABASE_SCHEMA_VERSION_KEY: &str = "database-schema-version";
+pub(crate) const DATABASE_BACKUP_KEY: &str = "database-backup-v";
+
+#[cfg(feature = "events")]
+pub(crate) const EVENT_LOG_SEQUENCE_NUMBER_KEY: &str = "event-log-sequence-number";
+#[cfg(feature = "events")]
+pub(crate) const EVENT_LOG_ENTRY_KEY: &str = "event-log-";
+#[cfg(feature = "events")]
+pub(crate) const EVENT_LOG_PRUNED_SEQUENCE_NUMBER_KEY: &str = "event-log-pruned-sequence-number";
 
 #[cfg(feature = "participation")]
 pub(crate) const PARTICIPATION_EVENTS: &str = "participation-events";
@@ -0,0 +1,98 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use super::manager::StorageManager;
+use crate::wallet::{
+    events::types::{Event, EventLogEntry, WalletEvent},
+    storage::constants::{EVENT_LOG_ENTRY_KEY, EVENT_LOG_PRUNED_SEQUENCE_NUMBER_KEY, EVENT_LOG_SEQUENCE_NUMBER_KEY},
+};
+
+fn event_log_entry_key(sequence_number: u64) -> String {
+    format!("{EVENT_LOG_ENTRY_KEY}{sequence_number}")
+}
+
+impl StorageManager {
+    /// Adds the records of the events that are logged, if the event log is enabled, to `records`, so they're written
+    /// in the same batch as the account changes of the sync that emitted them. Returns the latest sequence number,
+    /// which has to be set as `event_log_sequence_number` once the records are written.
+    pub(crate) fn add_event_log_records(
+        &self,
+        account_index: u32,
+        events: &[WalletEvent],
+        records: &mut HashMap<String, String>,
+    ) -> crate::wallet::Result<u64> {
+        let mut sequence_number = self.event_log_sequence_number;
+        if !self.event_log {
+            return Ok(sequence_number);
+        }
+
+        for event in events {
+            if !matches!(
+                event,
+                WalletEvent::NewOutput(_)
+                    | WalletEvent::SpentOutput(_)
+                    | WalletEvent::TransactionConflicting(_)
+                    | WalletEvent::TransactionInclusion(_)
+            ) {
+                continue;
+            }
+            sequence_number = sequence_number.saturating_add(1);
+            log::debug!("log_event {sequence_number}");
+
+            let entry = EventLogEntry {
+                sequence_number,
+                event: Event {
+                    account_index,
+                    event: event.clone(),
+                },
+            };
+            records.insert(event_log_entry_key(sequence_number), serde_json::to_string(&entry)?);
+        }
+        // The entries and the latest sequence number are written together, so no entry gets overwritten
+        if sequence_number != self.event_log_sequence_number {
+            records.insert(
+                EVENT_LOG_SEQUENCE_NUMBER_KEY.to_string(),
+                serde_json::to_string(&sequence_number)?,
+            );
+        }
+
+        Ok(sequence_number)
+    }
+
+    /// Reads the events of the event log with a sequence number greater than `sequence_number`.
+    pub(crate) async fn events_since(&self, sequence_number: u64) -> crate::wallet::Result<Vec<EventLogEntry>> {
+        let mut entries = Vec::new();
+        let first_sequence_number = sequence_number
+            .max(self.event_log_pruned_sequence_number)
+            .saturating_add(1);
+        for sequence_number in first_sequence_number..=self.event_log_sequence_number {
+            if let Some(entry) = self.storage.get(&event_log_entry_key(sequence_number)).await? {
+                entries.push(entry);
+            }
+        }
+        Ok(entries)
+    }
+
+    /// Removes the events of the event log with a sequence number up to and including `sequence_number`.
+    pub(crate) async fn prune_events(&mut self, sequence_number: u64) -> crate::wallet::Result<()> {
+        let sequence_number = sequence_number.min(self.event_log_sequence_number);
+        if sequence_number <= self.event_log_pruned_sequence_number {
+            return Ok(());
+        }
+        log::debug!("prune_events {sequence_number}");
+
+        for pruned_sequence_number in self.event_log_pruned_sequence_number.saturating_add(1)..=sequence_number {
+            self.storage
+                .remove(&event_log_entry_key(pruned_sequence_number))
+                .await?;
+        }
+        self.storage
+            .set(EVENT_LOG_PRUNED_SEQUENCE_NUMBER_KEY, sequence_number)
+            .await?;
+        self.event_log_pruned_sequence_number = sequence_number;
+
+        Ok(())
+    }
+}
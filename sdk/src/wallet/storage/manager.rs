@@ -1,16 +1,33 @@
 // Copyright 2021 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::sync::Arc;
+#[cfg(feature = "sqlite")]
+use std::path::PathBuf;
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+    sync::Arc,
+};
 
 use serde::{Deserialize, Serialize};
 use tokio::sync::RwLock;
 
+#[cfg(feature = "events")]
+use crate::wallet::events::types::WalletEvent;
 use crate::{
     client::secret::{SecretManager, SecretManagerDto},
+    types::block::{
+        input::Input,
+        output::{FoundryId, FoundryOutput, OutputId},
+        payload::transaction::{TransactionEssence, TransactionId},
+    },
     wallet::{
-        account::{AccountDetails, SyncOptions},
-        storage::{constants::*, Storage, StorageAdapter},
+        account::{
+            operations::transaction::high_level::payment_queue::PaymentQueue,
+            types::{AccountAddress, AddressWithUnspentOutputs, OutputData, Transaction},
+            AccountDetails, RefundablePayment, SyncOptions,
+        },
+        storage::{constants::*, migration, Storage, StorageAdapter},
         WalletBuilder,
     },
 };
@@ -21,6 +38,9 @@ pub(crate) enum ManagerStorage {
     /// RocksDB storage.
     #[cfg(feature = "rocksdb")]
     Rocksdb,
+    /// SQLite storage.
+    #[cfg(feature = "sqlite")]
+    Sqlite,
     /// Storage backed by a Map in memory.
     Memory,
     /// Wasm storage.
@@ -32,19 +52,137 @@ impl Default for ManagerStorage {
     fn default() -> Self {
         #[cfg(feature = "rocksdb")]
         return Self::Rocksdb;
+        #[cfg(all(feature = "sqlite", not(feature = "rocksdb")))]
+        return Self::Sqlite;
         #[cfg(target_family = "wasm")]
         return Self::Wasm;
-        #[cfg(not(any(feature = "rocksdb", target_family = "wasm")))]
+        #[cfg(not(any(feature = "rocksdb", feature = "sqlite", target_family = "wasm")))]
         Self::Memory
     }
 }
 
+impl ManagerStorage {
+    /// Selects the storage for a storage path: a path to a `.sqlite` or `.db` file selects the SQLite storage, any
+    /// other path the default storage.
+    #[cfg_attr(not(feature = "sqlite"), allow(unused_variables))]
+    pub(crate) fn from_path(path: &Path) -> Self {
+        #[cfg(feature = "sqlite")]
+        if is_sqlite_file(path) {
+            return Self::Sqlite;
+        }
+        Self::default()
+    }
+}
+
+#[cfg(feature = "sqlite")]
+fn is_sqlite_file(path: &Path) -> bool {
+    matches!(
+        path.extension().and_then(|extension| extension.to_str()),
+        Some(SQLITE_FILE_EXTENSION | "db")
+    )
+}
+
+/// Returns the SQLite database file for a storage path, a directory gets the default file name appended.
+#[cfg(feature = "sqlite")]
+pub(crate) fn sqlite_file_path(path: &Path) -> PathBuf {
+    if is_sqlite_file(path) {
+        path.to_path_buf()
+    } else {
+        path.join(SQLITE_FILENAME)
+    }
+}
+
 /// Storage manager
 #[derive(Debug)]
 pub struct StorageManager {
     pub(crate) storage: Storage,
     // account indexes for accounts in the database
-    account_indexes: Vec<u32>,
+    pub(crate) account_indexes: Vec<u32>,
+    // ids of the outputs and transactions that are stored as separate records for each account
+    account_record_ids: HashMap<u32, AccountRecordIds>,
+    // if events from syncing are stored in the event log
+    #[cfg(feature = "events")]
+    pub(crate) event_log: bool,
+    // sequence number of the latest event in the event log
+    #[cfg(feature = "events")]
+    pub(crate) event_log_sequence_number: u64,
+    // sequence number up to which the events were removed from the event log
+    #[cfg(feature = "events")]
+    pub(crate) event_log_pruned_sequence_number: u64,
+}
+
+/// The ids of the records stored separately for an account.
+#[derive(Debug, Default)]
+struct AccountRecordIds {
+    outputs: HashSet<OutputId>,
+    transactions: HashSet<TransactionId>,
+    incoming_transactions: HashSet<TransactionId>,
+}
+
+/// The part of an [`AccountDetails`] that is stored as a single record, outputs, transactions and addresses are stored
+/// as separate records.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountRecord {
+    index: u32,
+    coin_type: u32,
+    alias: String,
+    addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
+    unspent_outputs: HashSet<OutputId>,
+    locked_outputs: HashSet<OutputId>,
+    pending_transactions: HashSet<TransactionId>,
+    inaccessible_incoming_transactions: HashSet<TransactionId>,
+    native_token_foundries: HashMap<FoundryId, FoundryOutput>,
+    #[serde(default)]
+    watch_only: bool,
+    #[serde(default)]
+    refundable_payments: HashMap<OutputId, RefundablePayment>,
+}
+
+impl From<&AccountDetails> for AccountRecord {
+    fn from(account: &AccountDetails) -> Self {
+        Self {
+            index: account.index,
+            coin_type: account.coin_type,
+            alias: account.alias.clone(),
+            addresses_with_unspent_outputs: account.addresses_with_unspent_outputs.clone(),
+            unspent_outputs: account.unspent_outputs.keys().copied().collect(),
+            locked_outputs: account.locked_outputs.clone(),
+            pending_transactions: account.pending_transactions.clone(),
+            inaccessible_incoming_transactions: account.inaccessible_incoming_transactions.clone(),
+            native_token_foundries: account.native_token_foundries.clone(),
+            watch_only: account.watch_only,
+            refundable_payments: account.refundable_payments.clone(),
+        }
+    }
+}
+
+/// The addresses of an account.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountAddressesRecord {
+    public_addresses: Vec<AccountAddress>,
+    internal_addresses: Vec<AccountAddress>,
+}
+
+/// Records of an account that were changed and need to be written to the storage. New outputs and transactions are
+/// detected by the [`StorageManager`], so only already stored ones that were modified need to be added.
+#[derive(Debug, Default)]
+pub(crate) struct AccountChanges {
+    pub(crate) outputs: HashSet<OutputId>,
+    pub(crate) transactions: HashSet<TransactionId>,
+    pub(crate) addresses: bool,
+    // events caused by the changes, which are added to the event log together with them
+    #[cfg(feature = "events")]
+    pub(crate) events: Vec<WalletEvent>,
+}
+
+pub(crate) fn account_key(account_index: u32) -> String {
+    format!("{ACCOUNT_INDEXATION_KEY}{account_index}")
+}
+
+fn account_record_key(account_index: u32, record: &str) -> String {
+    format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{record}")
 }
 
 impl StorageManager {
@@ -52,30 +190,34 @@ impl StorageManager {
         encryption_key: Option<[u8; 32]>,
         storage: Box<dyn StorageAdapter + Send + Sync + 'static>,
     ) -> crate::wallet::Result<Self> {
-        let mut storage = Storage {
+        let storage = Storage {
             inner: storage,
             encryption_key,
         };
-        // Get the db version or set it
-        if let Some(db_schema_version) = storage.get::<u8>(DATABASE_SCHEMA_VERSION_KEY).await? {
-            if db_schema_version != DATABASE_SCHEMA_VERSION {
-                return Err(crate::wallet::Error::Storage(format!(
-                    "unsupported database schema version {db_schema_version}"
-                )));
-            }
-        } else {
-            storage
-                .set(DATABASE_SCHEMA_VERSION_KEY, DATABASE_SCHEMA_VERSION)
-                .await?;
-        };
 
         let account_indexes = storage.get(ACCOUNTS_INDEXATION_KEY).await?.unwrap_or_default();
+        #[cfg(feature = "events")]
+        let event_log_sequence_number = storage.get(EVENT_LOG_SEQUENCE_NUMBER_KEY).await?.unwrap_or_default();
+        #[cfg(feature = "events")]
+        let event_log_pruned_sequence_number = storage
+            .get(EVENT_LOG_PRUNED_SEQUENCE_NUMBER_KEY)
+            .await?
+            .unwrap_or_default();
 
-        let storage_manager = Self {
+        let mut storage_manager = Self {
             storage,
             account_indexes,
+            account_record_ids: HashMap::new(),
+            #[cfg(feature = "events")]
+            event_log: false,
+            #[cfg(feature = "events")]
+            event_log_sequence_number,
+            #[cfg(feature = "events")]
+            event_log_pruned_sequence_number,
         };
 
+        migration::migrate(&mut storage_manager).await?;
+
         Ok(storage_manager)
     }
 
@@ -98,14 +240,24 @@ impl StorageManager {
 
         if let Some(secret_manager) = &wallet_builder.secret_manager {
             let secret_manager = secret_manager.read().await;
-            let secret_manager_dto = SecretManagerDto::from(&*secret_manager);
-            // Only store secret_managers that aren't SecretManagerDto::Mnemonic, because there the Seed can't be
-            // serialized, so we can't create the SecretManager again
-            match secret_manager_dto {
-                SecretManagerDto::Mnemonic(_) => {}
-                _ => {
-                    self.storage.set(SECRET_MANAGER_KEY, secret_manager_dto).await?;
+            match &*secret_manager {
+                // The bearer token isn't stored and a previously stored secret manager must not be restored instead,
+                // the remote secret manager has to be provided again when the wallet is restored
+                SecretManager::Remote(remote) if remote.has_bearer_token() => {
+                    self.storage.remove(SECRET_MANAGER_KEY).await?;
+                }
+                // A custom secret manager can't be created from a DTO, it has to be provided again as well
+                SecretManager::Custom(_) => {
+                    self.storage.remove(SECRET_MANAGER_KEY).await?;
                 }
+                secret_manager => match SecretManagerDto::try_from(secret_manager)? {
+                    // Only store secret_managers that aren't SecretManagerDto::Mnemonic, because there the Seed can't
+                    // be serialized, so we can't create the SecretManager again
+                    SecretManagerDto::Mnemonic(_) => {}
+                    secret_manager_dto => {
+                        self.storage.set(SECRET_MANAGER_KEY, secret_manager_dto).await?;
+                    }
+                },
             }
         }
         Ok(())
@@ -135,6 +287,9 @@ impl StorageManager {
         }
     }
 
+    /// Loads the accounts, historical transactions and spent outputs are not loaded, only pending transactions and the
+    /// unspent and locked outputs and the ones of pending transactions. They can be read with
+    /// [`StorageManager::get_transactions()`] and [`StorageManager::get_outputs()`] when needed.
     pub async fn get_accounts(&mut self) -> crate::wallet::Result<Vec<AccountDetails>> {
         if let Some(account_indexes) = self.storage.get(ACCOUNTS_INDEXATION_KEY).await? {
             if self.account_indexes.is_empty() {
@@ -148,34 +303,353 @@ impl StorageManager {
         for account_index in self.account_indexes.clone() {
             // PANIC: we assume that ACCOUNTS_INDEXATION_KEY and the different indexes are set together and
             // ACCOUNTS_INDEXATION_KEY has already been checked.
-            accounts.push(
-                self.get(&format!("{ACCOUNT_INDEXATION_KEY}{account_index}"))
-                    .await?
-                    .unwrap(),
+            let record: AccountRecord = self.storage.get(&account_key(account_index)).await?.unwrap();
+            let addresses: AccountAddressesRecord = self
+                .storage
+                .get(&account_record_key(account_index, ACCOUNT_ADDRESSES_KEY))
+                .await?
+                .ok_or_else(|| {
+                    crate::wallet::Error::Storage(format!("missing addresses of account {account_index}"))
+                })?;
+
+            let record_ids = self.read_account_record_ids(account_index).await?;
+
+            let mut transactions = HashMap::new();
+            for transaction_id in &record.pending_transactions {
+                if let Some(transaction) = self.get_transaction(account_index, transaction_id).await? {
+                    transactions.insert(*transaction_id, transaction);
+                }
+            }
+
+            // The inputs and outputs of pending transactions are needed to sync their inclusion state, even if they're
+            // spent
+            let mut output_ids = record
+                .unspent_outputs
+                .union(&record.locked_outputs)
+                .copied()
+                .collect::<HashSet<_>>();
+            for transaction in transactions.values() {
+                let TransactionEssence::Regular(essence) = transaction.payload.essence();
+                output_ids.extend(essence.inputs().iter().filter_map(|input| match input {
+                    Input::Utxo(input) => Some(*input.output_id()),
+                    Input::Treasury(_) => None,
+                }));
+            }
+            output_ids.extend(
+                record_ids
+                    .outputs
+                    .iter()
+                    .filter(|output_id| record.pending_transactions.contains(output_id.transaction_id())),
             );
+
+            let mut outputs = HashMap::new();
+            for output_id in &output_ids {
+                if let Some(output_data) = self.get_output(account_index, output_id).await? {
+                    outputs.insert(*output_id, output_data);
+                }
+            }
+            let unspent_outputs = record
+                .unspent_outputs
+                .iter()
+                .filter_map(|output_id| {
+                    outputs
+                        .get(output_id)
+                        .map(|output_data| (*output_id, output_data.clone()))
+                })
+                .collect();
+
+            let mut incoming_transactions = HashMap::new();
+            for transaction_id in &record_ids.incoming_transactions {
+                if let Some(transaction) = self
+                    .storage
+                    .get::<Transaction>(&account_record_key(
+                        account_index,
+                        &format!("{ACCOUNT_INCOMING_TRANSACTION_KEY}{transaction_id}"),
+                    ))
+                    .await?
+                {
+                    incoming_transactions.insert(*transaction_id, transaction);
+                }
+            }
+
+            self.account_record_ids.insert(account_index, record_ids);
+
+            accounts.push(AccountDetails {
+                index: record.index,
+                coin_type: record.coin_type,
+                alias: record.alias,
+                public_addresses: addresses.public_addresses,
+                internal_addresses: addresses.internal_addresses,
+                addresses_with_unspent_outputs: record.addresses_with_unspent_outputs,
+                outputs,
+                locked_outputs: record.locked_outputs,
+                unspent_outputs,
+                transactions,
+                pending_transactions: record.pending_transactions,
+                incoming_transactions,
+                inaccessible_incoming_transactions: record.inaccessible_incoming_transactions,
+                native_token_foundries: record.native_token_foundries,
+                watch_only: record.watch_only,
+                refundable_payments: record.refundable_payments,
+            });
         }
 
         Ok(accounts)
     }
 
+    async fn read_account_record_ids(&self, account_index: u32) -> crate::wallet::Result<AccountRecordIds> {
+        Ok(AccountRecordIds {
+            outputs: self
+                .storage
+                .get(&account_record_key(account_index, ACCOUNT_OUTPUTS_KEY))
+                .await?
+                .unwrap_or_default(),
+            transactions: self
+                .storage
+                .get(&account_record_key(account_index, ACCOUNT_TRANSACTIONS_KEY))
+                .await?
+                .unwrap_or_default(),
+            incoming_transactions: self
+                .storage
+                .get(&account_record_key(account_index, ACCOUNT_INCOMING_TRANSACTIONS_KEY))
+                .await?
+                .unwrap_or_default(),
+        })
+    }
+
+    /// Writes all records of an account.
     pub async fn save_account(&mut self, account: &AccountDetails) -> crate::wallet::Result<()> {
+        let changes = AccountChanges {
+            outputs: account.outputs.keys().copied().collect(),
+            transactions: account.transactions.keys().copied().collect(),
+            addresses: true,
+            ..Default::default()
+        };
+        self.save_account_changes(account, changes).await
+    }
+
+    /// Writes the account record, the changed records and the records of new outputs and transactions.
+    pub(crate) async fn save_account_changes(
+        &mut self,
+        account: &AccountDetails,
+        changes: AccountChanges,
+    ) -> crate::wallet::Result<()> {
+        let account_index = account.index;
+
         // Only add account index if not already present
-        if !self.account_indexes.contains(account.index()) {
-            self.account_indexes.push(*account.index());
+        if !self.account_indexes.contains(&account_index) {
+            self.account_indexes.push(account_index);
+            self.storage
+                .set(ACCOUNTS_INDEXATION_KEY, self.account_indexes.clone())
+                .await?;
+        }
+
+        if !self.account_record_ids.contains_key(&account_index) {
+            let record_ids = self.read_account_record_ids(account_index).await?;
+            self.account_record_ids.insert(account_index, record_ids);
+        }
+        // PANIC: the ids were inserted above.
+        let record_ids = self.account_record_ids.get_mut(&account_index).unwrap();
+
+        let mut records = HashMap::new();
+        records.insert(
+            account_key(account_index),
+            serde_json::to_string(&AccountRecord::from(account))?,
+        );
+
+        if changes.addresses {
+            records.insert(
+                account_record_key(account_index, ACCOUNT_ADDRESSES_KEY),
+                serde_json::to_string(&AccountAddressesRecord {
+                    public_addresses: account.public_addresses.clone(),
+                    internal_addresses: account.internal_addresses.clone(),
+                })?,
+            );
+        }
+
+        let new_output_ids = account
+            .outputs
+            .keys()
+            .filter(|output_id| !record_ids.outputs.contains(output_id))
+            .copied()
+            .collect::<Vec<_>>();
+        for output_id in changes.outputs.iter().chain(&new_output_ids) {
+            if let Some(output_data) = account.outputs.get(output_id) {
+                records.insert(
+                    account_record_key(account_index, &format!("{ACCOUNT_OUTPUT_KEY}{output_id}")),
+                    serde_json::to_string(output_data)?,
+                );
+            }
+        }
+        if !new_output_ids.is_empty() {
+            record_ids.outputs.extend(new_output_ids);
+            records.insert(
+                account_record_key(account_index, ACCOUNT_OUTPUTS_KEY),
+                serde_json::to_string(&record_ids.outputs)?,
+            );
+        }
+
+        let new_transaction_ids = account
+            .transactions
+            .keys()
+            .filter(|transaction_id| !record_ids.transactions.contains(transaction_id))
+            .copied()
+            .collect::<Vec<_>>();
+        for transaction_id in changes.transactions.iter().chain(&new_transaction_ids) {
+            if let Some(transaction) = account.transactions.get(transaction_id) {
+                records.insert(
+                    account_record_key(account_index, &format!("{ACCOUNT_TRANSACTION_KEY}{transaction_id}")),
+                    serde_json::to_string(transaction)?,
+                );
+            }
+        }
+        if !new_transaction_ids.is_empty() {
+            record_ids.transactions.extend(new_transaction_ids);
+            records.insert(
+                account_record_key(account_index, ACCOUNT_TRANSACTIONS_KEY),
+                serde_json::to_string(&record_ids.transactions)?,
+            );
+        }
+
+        // Incoming transactions don't change once they're stored
+        let new_incoming_transaction_ids = account
+            .incoming_transactions
+            .keys()
+            .filter(|transaction_id| !record_ids.incoming_transactions.contains(transaction_id))
+            .copied()
+            .collect::<Vec<_>>();
+        for transaction_id in &new_incoming_transaction_ids {
+            if let Some(transaction) = account.incoming_transactions.get(transaction_id) {
+                records.insert(
+                    account_record_key(
+                        account_index,
+                        &format!("{ACCOUNT_INCOMING_TRANSACTION_KEY}{transaction_id}"),
+                    ),
+                    serde_json::to_string(transaction)?,
+                );
+            }
+        }
+        if !new_incoming_transaction_ids.is_empty() {
+            record_ids.incoming_transactions.extend(new_incoming_transaction_ids);
+            records.insert(
+                account_record_key(account_index, ACCOUNT_INCOMING_TRANSACTIONS_KEY),
+                serde_json::to_string(&record_ids.incoming_transactions)?,
+            );
         }
 
+        #[cfg(feature = "events")]
+        let event_log_sequence_number = self.add_event_log_records(account_index, &changes.events, &mut records)?;
+
+        self.storage.batch_set(records).await?;
+
+        #[cfg(feature = "events")]
+        {
+            self.event_log_sequence_number = event_log_sequence_number;
+        }
+
+        Ok(())
+    }
+
+    /// Reads a stored output of the account.
+    pub(crate) async fn get_output(
+        &self,
+        account_index: u32,
+        output_id: &OutputId,
+    ) -> crate::wallet::Result<Option<OutputData>> {
         self.storage
-            .set(ACCOUNTS_INDEXATION_KEY, self.account_indexes.clone())
-            .await?;
+            .get(&account_record_key(
+                account_index,
+                &format!("{ACCOUNT_OUTPUT_KEY}{output_id}"),
+            ))
+            .await
+    }
+
+    /// Reads all stored outputs of the account.
+    pub(crate) async fn get_outputs(&self, account_index: u32) -> crate::wallet::Result<Vec<OutputData>> {
+        let mut outputs = Vec::new();
+        if let Some(record_ids) = self.account_record_ids.get(&account_index) {
+            for output_id in &record_ids.outputs {
+                if let Some(output_data) = self.get_output(account_index, output_id).await? {
+                    outputs.push(output_data);
+                }
+            }
+        }
+        Ok(outputs)
+    }
+
+    /// Returns whether a transaction is stored for the account, also if it wasn't loaded.
+    pub(crate) fn contains_transaction(&self, account_index: u32, transaction_id: &TransactionId) -> bool {
+        self.account_record_ids
+            .get(&account_index)
+            .is_some_and(|record_ids| record_ids.transactions.contains(transaction_id))
+    }
+
+    /// Reads a stored transaction of the account.
+    pub(crate) async fn get_transaction(
+        &self,
+        account_index: u32,
+        transaction_id: &TransactionId,
+    ) -> crate::wallet::Result<Option<Transaction>> {
         self.storage
-            .set(&format!("{ACCOUNT_INDEXATION_KEY}{}", account.index()), account)
+            .get(&account_record_key(
+                account_index,
+                &format!("{ACCOUNT_TRANSACTION_KEY}{transaction_id}"),
+            ))
             .await
     }
 
+    /// Reads all stored transactions of the account.
+    pub(crate) async fn get_transactions(&self, account_index: u32) -> crate::wallet::Result<Vec<Transaction>> {
+        let mut transactions = Vec::new();
+        if let Some(record_ids) = self.account_record_ids.get(&account_index) {
+            for transaction_id in &record_ids.transactions {
+                if let Some(transaction) = self.get_transaction(account_index, transaction_id).await? {
+                    transactions.push(transaction);
+                }
+            }
+        }
+        Ok(transactions)
+    }
+
     pub async fn remove_account(&mut self, account_index: u32) -> crate::wallet::Result<()> {
-        self.storage
-            .remove(&format!("{ACCOUNT_INDEXATION_KEY}{account_index}"))
-            .await?;
+        let record_ids = match self.account_record_ids.remove(&account_index) {
+            Some(record_ids) => record_ids,
+            None => self.read_account_record_ids(account_index).await?,
+        };
+        for output_id in record_ids.outputs {
+            self.storage
+                .remove(&account_record_key(
+                    account_index,
+                    &format!("{ACCOUNT_OUTPUT_KEY}{output_id}"),
+                ))
+                .await?;
+        }
+        for transaction_id in record_ids.transactions {
+            self.storage
+                .remove(&account_record_key(
+                    account_index,
+                    &format!("{ACCOUNT_TRANSACTION_KEY}{transaction_id}"),
+                ))
+                .await?;
+        }
+        for transaction_id in record_ids.incoming_transactions {
+            self.storage
+                .remove(&account_record_key(
+                    account_index,
+                    &format!("{ACCOUNT_INCOMING_TRANSACTION_KEY}{transaction_id}"),
+                ))
+                .await?;
+        }
+        for record in [
+            ACCOUNT_ADDRESSES_KEY,
+            ACCOUNT_OUTPUTS_KEY,
+            ACCOUNT_TRANSACTIONS_KEY,
+            ACCOUNT_INCOMING_TRANSACTIONS_KEY,
+            ACCOUNT_PAYMENT_QUEUE_KEY,
+        ] {
+            self.storage.remove(&account_record_key(account_index, record)).await?;
+        }
+        self.storage.remove(&account_key(account_index)).await?;
         self.account_indexes.retain(|a| a != &account_index);
         self.storage
             .set(ACCOUNTS_INDEXATION_KEY, self.account_indexes.clone())
@@ -187,12 +661,211 @@ impl StorageManager {
         account_index: u32,
         sync_options: &SyncOptions,
     ) -> crate::wallet::Result<()> {
-        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_OPTIONS}");
+        let key = account_record_key(account_index, ACCOUNT_SYNC_OPTIONS);
         self.storage.set(&key, sync_options.clone()).await
     }
 
     pub async fn get_default_sync_options(&self, account_index: u32) -> crate::wallet::Result<Option<SyncOptions>> {
-        let key = format!("{ACCOUNT_INDEXATION_KEY}{account_index}-{ACCOUNT_SYNC_OPTIONS}");
+        let key = account_record_key(account_index, ACCOUNT_SYNC_OPTIONS);
+        self.storage.get(&key).await
+    }
+
+    pub(crate) async fn set_payment_queue(
+        &mut self,
+        account_index: u32,
+        payment_queue: &PaymentQueue,
+    ) -> crate::wallet::Result<()> {
+        let key = account_record_key(account_index, ACCOUNT_PAYMENT_QUEUE_KEY);
+        self.storage.set(&key, payment_queue).await
+    }
+
+    pub(crate) async fn get_payment_queue(&self, account_index: u32) -> crate::wallet::Result<Option<PaymentQueue>> {
+        let key = account_record_key(account_index, ACCOUNT_PAYMENT_QUEUE_KEY);
         self.storage.get(&key).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::storage::{adapter::memory::Memory, migration::tests::storage_v1};
+
+    const ENCRYPTION_KEY: [u8; 32] = [1; 32];
+
+    // Saves the account of the v1 fixture to an encrypted storage and checks that its records are stored encrypted and
+    // loaded again
+    async fn save_encrypted_account(storage_manager: &mut StorageManager) -> AccountDetails {
+        let account = StorageManager::new(None, Box::new(storage_v1().await))
+            .await
+            .unwrap()
+            .get_accounts()
+            .await
+            .unwrap()
+            .remove(0);
+        assert!(storage_manager.is_encrypted());
+        storage_manager.save_account(&account).await.unwrap();
+
+        for key in [
+            account_key(0),
+            account_record_key(0, ACCOUNT_ADDRESSES_KEY),
+            account_record_key(0, ACCOUNT_OUTPUTS_KEY),
+        ] {
+            let record = storage_manager.storage.inner.get(&key).await.unwrap().unwrap();
+            assert!(
+                serde_json::from_str::<Vec<u8>>(&record).is_ok(),
+                "{key} isn't encrypted"
+            );
+        }
+        assert_loaded_account(storage_manager, &account).await;
+
+        account
+    }
+
+    async fn assert_loaded_account(storage_manager: &mut StorageManager, account: &AccountDetails) {
+        let loaded_account = storage_manager.get_accounts().await.unwrap().remove(0);
+        assert_eq!(loaded_account.public_addresses, account.public_addresses);
+        assert_eq!(loaded_account.outputs, account.outputs);
+        assert_eq!(loaded_account.unspent_outputs, account.unspent_outputs);
+        assert_eq!(loaded_account.pending_transactions, account.pending_transactions);
+        assert_eq!(loaded_account.incoming_transactions, account.incoming_transactions);
+    }
+
+    #[tokio::test]
+    async fn encrypted_records_memory() {
+        let mut storage_manager = StorageManager::new(Some(ENCRYPTION_KEY), Box::<Memory>::default())
+            .await
+            .unwrap();
+        save_encrypted_account(&mut storage_manager).await;
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[tokio::test]
+    async fn encrypted_records_sqlite() {
+        use crate::wallet::storage::adapter::sqlite::SqliteStorageAdapter;
+
+        let storage_path = "test-storage/encrypted_records_sqlite";
+        std::fs::remove_dir_all(storage_path).ok();
+        let db_path = format!("{storage_path}/wallet.sqlite");
+
+        let mut storage_manager = StorageManager::new(
+            Some(ENCRYPTION_KEY),
+            Box::new(SqliteStorageAdapter::new(&db_path).unwrap()),
+        )
+        .await
+        .unwrap();
+        let account = save_encrypted_account(&mut storage_manager).await;
+        drop(storage_manager);
+
+        // The encrypted records are persisted
+        let mut storage_manager = StorageManager::new(
+            Some(ENCRYPTION_KEY),
+            Box::new(SqliteStorageAdapter::new(&db_path).unwrap()),
+        )
+        .await
+        .unwrap();
+        assert_loaded_account(&mut storage_manager, &account).await;
+        drop(storage_manager);
+
+        std::fs::remove_dir_all(storage_path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn save_account_changes() {
+        let mut storage_manager = StorageManager::new(None, Box::new(storage_v1().await)).await.unwrap();
+        let mut account = storage_manager.get_accounts().await.unwrap().remove(0);
+        let output_id = *account.outputs.keys().next().unwrap();
+        let transaction_id = *account.pending_transactions.iter().next().unwrap();
+
+        // Unchanged records are not written again
+        account.outputs.get_mut(&output_id).unwrap().is_spent = true;
+        account.pending_transactions.clear();
+        storage_manager
+            .save_account_changes(&account, AccountChanges::default())
+            .await
+            .unwrap();
+        let loaded_account = storage_manager.get_accounts().await.unwrap().remove(0);
+        assert!(!loaded_account.outputs[&output_id].is_spent);
+
+        storage_manager
+            .save_account_changes(
+                &account,
+                AccountChanges {
+                    outputs: [output_id].into(),
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        let loaded_account = storage_manager.get_accounts().await.unwrap().remove(0);
+        assert!(loaded_account.outputs[&output_id].is_spent);
+
+        // Transactions that aren't pending anymore are only loaded when requested
+        assert!(loaded_account.transactions.is_empty());
+        assert!(storage_manager.contains_transaction(0, &transaction_id));
+        assert_eq!(storage_manager.get_transactions(0).await.unwrap().len(), 1);
+
+        // Spent outputs that aren't used by a pending transaction are only loaded when requested
+        let mut account = loaded_account;
+        account.unspent_outputs.remove(&output_id);
+        account.locked_outputs.remove(&output_id);
+        storage_manager
+            .save_account_changes(&account, AccountChanges::default())
+            .await
+            .unwrap();
+        let loaded_account = storage_manager.get_accounts().await.unwrap().remove(0);
+        assert!(loaded_account.outputs.is_empty());
+        let output_data = storage_manager.get_output(0, &output_id).await.unwrap().unwrap();
+        assert!(output_data.is_spent);
+        assert_eq!(storage_manager.get_outputs(0).await.unwrap().len(), 1);
+    }
+
+    #[cfg(feature = "events")]
+    #[tokio::test]
+    async fn save_account_changes_with_events() {
+        let mut storage_manager = StorageManager::new(None, Box::new(storage_v1().await)).await.unwrap();
+        let account = storage_manager.get_accounts().await.unwrap().remove(0);
+        storage_manager.event_log = true;
+        storage_manager.event_log_sequence_number = u64::MAX - 1;
+
+        // Only events from syncing are logged, in the same batch as the account changes
+        storage_manager
+            .save_account_changes(
+                &account,
+                AccountChanges {
+                    events: vec![WalletEvent::ConsolidationRequired],
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+        assert_eq!(storage_manager.event_log_sequence_number, u64::MAX - 1);
+
+        let event = WalletEvent::SpentOutput(Box::new(crate::wallet::events::types::SpentOutputEvent {
+            output: crate::wallet::account::types::OutputDataDto::from(account.outputs.values().next().unwrap()),
+        }));
+        storage_manager
+            .save_account_changes(
+                &account,
+                AccountChanges {
+                    events: vec![event.clone(), event],
+                    ..Default::default()
+                },
+            )
+            .await
+            .unwrap();
+
+        // The sequence number saturates instead of overflowing
+        assert_eq!(storage_manager.event_log_sequence_number, u64::MAX);
+        assert_eq!(
+            storage_manager
+                .storage
+                .get::<u64>(EVENT_LOG_SEQUENCE_NUMBER_KEY)
+                .await
+                .unwrap(),
+            Some(u64::MAX)
+        );
+        let entries = storage_manager.events_since(u64::MAX - 1).await.unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].sequence_number, u64::MAX);
+    }
+}
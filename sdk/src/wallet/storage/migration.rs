@@ -0,0 +1,343 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::{HashMap, HashSet};
+
+use serde::Deserialize;
+
+use crate::{
+    types::block::{
+        output::{FoundryId, FoundryOutput, OutputId},
+        payload::transaction::TransactionId,
+    },
+    wallet::{
+        account::{
+            deserialize_or_convert,
+            types::{
+                address::{AccountAddress, AddressWithUnspentOutputs},
+                OutputData, Transaction,
+            },
+            AccountDetails,
+        },
+        storage::{
+            constants::{
+                ACCOUNTS_INDEXATION_KEY, DATABASE_BACKUP_KEY, DATABASE_SCHEMA_VERSION, DATABASE_SCHEMA_VERSION_KEY,
+            },
+            manager::{account_key, StorageManager},
+        },
+    },
+};
+
+/// A step that migrates the records of the storage from one schema version to the next one.
+///
+/// Migrations are run in order on open and can be interrupted at any point, so running a migration again on records
+/// that were already (partially) migrated must give the same result.
+#[async_trait::async_trait]
+trait Migration {
+    /// The schema version the migration is applied to, the storage is at the next version afterwards.
+    fn version(&self) -> u8;
+
+    /// The keys of the records that are modified by the migration, they are backed up before the migration runs.
+    fn records(&self, account_indexes: &[u32]) -> Vec<String>;
+
+    async fn migrate(&self, storage_manager: &mut StorageManager) -> crate::wallet::Result<()>;
+}
+
+/// The registered migrations, ordered by the schema version they are applied to.
+const MIGRATIONS: &[&(dyn Migration + Send + Sync)] = &[&SplitAccountRecords];
+
+/// Migrates the storage to [`DATABASE_SCHEMA_VERSION`], a new storage only gets the schema version set.
+pub(crate) async fn migrate(storage_manager: &mut StorageManager) -> crate::wallet::Result<()> {
+    let Some(version) = storage_manager.storage.get::<u8>(DATABASE_SCHEMA_VERSION_KEY).await? else {
+        return storage_manager
+            .storage
+            .set(DATABASE_SCHEMA_VERSION_KEY, DATABASE_SCHEMA_VERSION)
+            .await;
+    };
+
+    if version > DATABASE_SCHEMA_VERSION {
+        return Err(crate::wallet::Error::Storage(format!(
+            "unsupported database schema version {version}"
+        )));
+    }
+
+    for migration in MIGRATIONS.iter().filter(|migration| migration.version() >= version) {
+        let from_version = migration.version();
+        log::debug!("[migrate] database schema version {from_version}");
+        let records = migration.records(&storage_manager.account_indexes);
+        backup(storage_manager, from_version, records).await?;
+        migration.migrate(storage_manager).await?;
+
+        storage_manager
+            .storage
+            .set(DATABASE_SCHEMA_VERSION_KEY, from_version + 1)
+            .await?;
+    }
+
+    Ok(())
+}
+
+fn backup_key(version: u8) -> String {
+    format!("{DATABASE_BACKUP_KEY}{version}")
+}
+
+fn backup_record_key(version: u8, key: &str) -> String {
+    format!("{DATABASE_BACKUP_KEY}{version}-{key}")
+}
+
+// Copies the records as they are stored, so encrypted records stay encrypted. A backup from an interrupted migration
+// isn't overwritten, because the records might already be partially migrated.
+async fn backup(storage_manager: &mut StorageManager, version: u8, records: Vec<String>) -> crate::wallet::Result<()> {
+    let storage = &mut storage_manager.storage.inner;
+    if storage.get(&backup_key(version)).await?.is_some() {
+        return Ok(());
+    }
+
+    let mut backup = HashMap::new();
+    let mut backup_keys = Vec::new();
+    for key in [
+        DATABASE_SCHEMA_VERSION_KEY.to_string(),
+        ACCOUNTS_INDEXATION_KEY.to_string(),
+    ]
+    .into_iter()
+    .chain(records)
+    {
+        if let Some(record) = storage.get(&key).await? {
+            backup.insert(backup_record_key(version, &key), record);
+            backup_keys.push(key);
+        }
+    }
+    backup.insert(backup_key(version), serde_json::to_string(&backup_keys)?);
+
+    storage.batch_set(backup).await
+}
+
+/// Schema version 1 stored each account as a single record, which is split into separate records for the outputs,
+/// transactions and addresses.
+struct SplitAccountRecords;
+
+#[async_trait::async_trait]
+impl Migration for SplitAccountRecords {
+    fn version(&self) -> u8 {
+        1
+    }
+
+    fn records(&self, account_indexes: &[u32]) -> Vec<String> {
+        account_indexes.iter().map(|index| account_key(*index)).collect()
+    }
+
+    async fn migrate(&self, storage_manager: &mut StorageManager) -> crate::wallet::Result<()> {
+        for account_index in storage_manager.account_indexes.clone() {
+            let record = match storage_manager
+                .storage
+                .get::<serde_json::Value>(&account_key(account_index))
+                .await?
+            {
+                Some(record) => record,
+                None => continue,
+            };
+            // Accounts that were already split by a previous, interrupted run don't contain their addresses anymore
+            if record.get("publicAddresses").is_none() {
+                continue;
+            }
+            let account = AccountDetails::from(serde_json::from_value::<AccountDetailsV1>(record)?);
+            storage_manager.save_account(&account).await?;
+        }
+        Ok(())
+    }
+}
+
+/// An account record of schema version 1, kept as it was, so later changes of [`AccountDetails`] don't change how the
+/// records are read.
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AccountDetailsV1 {
+    index: u32,
+    coin_type: u32,
+    alias: String,
+    public_addresses: Vec<AccountAddress>,
+    internal_addresses: Vec<AccountAddress>,
+    addresses_with_unspent_outputs: Vec<AddressWithUnspentOutputs>,
+    outputs: HashMap<OutputId, OutputData>,
+    locked_outputs: HashSet<OutputId>,
+    unspent_outputs: HashMap<OutputId, OutputData>,
+    transactions: HashMap<TransactionId, Transaction>,
+    pending_transactions: HashSet<TransactionId>,
+    #[serde(deserialize_with = "deserialize_or_convert")]
+    incoming_transactions: HashMap<TransactionId, Transaction>,
+    #[serde(default)]
+    inaccessible_incoming_transactions: HashSet<TransactionId>,
+    #[serde(default)]
+    native_token_foundries: HashMap<FoundryId, FoundryOutput>,
+}
+
+impl From<AccountDetailsV1> for AccountDetails {
+    fn from(account: AccountDetailsV1) -> Self {
+        Self {
+            index: account.index,
+            coin_type: account.coin_type,
+            alias: account.alias,
+            public_addresses: account.public_addresses,
+            internal_addresses: account.internal_addresses,
+            addresses_with_unspent_outputs: account.addresses_with_unspent_outputs,
+            outputs: account.outputs,
+            locked_outputs: account.locked_outputs,
+            unspent_outputs: account.unspent_outputs,
+            transactions: account.transactions,
+            pending_transactions: account.pending_transactions,
+            incoming_transactions: account.incoming_transactions,
+            inaccessible_incoming_transactions: account.inaccessible_incoming_transactions,
+            native_token_foundries: account.native_token_foundries,
+            watch_only: false,
+            refundable_payments: HashMap::new(),
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::wallet::storage::{adapter::memory::Memory, StorageAdapter};
+
+    // A database with an account with outputs, a pending transaction and an incoming transaction, stored with schema
+    // version 1
+    const STORAGE_V1: &str = include_str!("../../../tests/wallet/fixtures/storage_v1.json");
+
+    fn records_v1() -> HashMap<String, String> {
+        serde_json::from_str::<HashMap<String, serde_json::Value>>(STORAGE_V1)
+            .unwrap()
+            .into_iter()
+            .map(|(key, record)| (key, record.to_string()))
+            .collect()
+    }
+
+    pub(crate) async fn storage_v1() -> Memory {
+        let mut storage = Memory::default();
+        storage.batch_set(records_v1()).await.unwrap();
+        storage
+    }
+
+    #[test]
+    fn migrations_are_ordered() {
+        for (i, migration) in MIGRATIONS.iter().enumerate() {
+            assert_eq!(migration.version(), MIGRATIONS[0].version() + i as u8);
+        }
+        assert_eq!(
+            MIGRATIONS.last().map(|migration| migration.version() + 1),
+            Some(DATABASE_SCHEMA_VERSION)
+        );
+    }
+
+    #[tokio::test]
+    async fn new_storage() {
+        let storage_manager = StorageManager::new(None, Box::<Memory>::default()).await.unwrap();
+
+        assert_eq!(
+            storage_manager.get::<u8>(DATABASE_SCHEMA_VERSION_KEY).await.unwrap(),
+            Some(DATABASE_SCHEMA_VERSION)
+        );
+        assert_eq!(storage_manager.storage.inner.get(&backup_key(1)).await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn migrate_v1() {
+        let account_v1 = records_v1().remove(&account_key(0)).unwrap();
+        let account = AccountDetails::from(serde_json::from_str::<AccountDetailsV1>(&account_v1).unwrap());
+        let mut storage_manager = StorageManager::new(None, Box::new(storage_v1().await)).await.unwrap();
+
+        assert_eq!(
+            storage_manager.get::<u8>(DATABASE_SCHEMA_VERSION_KEY).await.unwrap(),
+            Some(DATABASE_SCHEMA_VERSION)
+        );
+        assert_eq!(storage_manager.get_accounts().await.unwrap(), vec![account]);
+
+        // The previous records are kept as they were
+        let storage = &storage_manager.storage.inner;
+        assert_eq!(
+            storage
+                .get(&backup_record_key(1, &account_key(0)))
+                .await
+                .unwrap()
+                .as_deref(),
+            Some(account_v1.as_str())
+        );
+        assert_eq!(
+            storage
+                .get(&backup_record_key(1, DATABASE_SCHEMA_VERSION_KEY))
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("1")
+        );
+    }
+
+    #[tokio::test]
+    async fn migrate_v1_interrupted() {
+        let account_v1 = records_v1().remove(&account_key(0)).unwrap();
+        let account = AccountDetails::from(serde_json::from_str::<AccountDetailsV1>(&account_v1).unwrap());
+        let mut storage_manager = StorageManager::new(None, Box::new(storage_v1().await)).await.unwrap();
+
+        // The records were migrated, but the schema version wasn't updated
+        storage_manager
+            .storage
+            .set(DATABASE_SCHEMA_VERSION_KEY, 1)
+            .await
+            .unwrap();
+        let storage = std::mem::replace(&mut storage_manager.storage.inner, Box::new(Memory::default()));
+        let mut storage_manager = StorageManager::new(None, storage).await.unwrap();
+
+        assert_eq!(
+            storage_manager.get::<u8>(DATABASE_SCHEMA_VERSION_KEY).await.unwrap(),
+            Some(DATABASE_SCHEMA_VERSION)
+        );
+        assert_eq!(storage_manager.get_accounts().await.unwrap(), vec![account]);
+        // The backup of the first run is kept
+        assert_eq!(
+            storage_manager
+                .storage
+                .inner
+                .get(&backup_record_key(1, &account_key(0)))
+                .await
+                .unwrap()
+                .as_deref(),
+            Some(account_v1.as_str())
+        );
+    }
+
+    #[tokio::test]
+    async fn migrate_v1_invalid_account() {
+        let mut storage = storage_v1().await;
+        let mut account_v1 = serde_json::from_str::<serde_json::Value>(&records_v1()[&account_key(0)]).unwrap();
+        account_v1["outputs"] = serde_json::Value::Bool(true);
+        storage.set(&account_key(0), account_v1.to_string()).await.unwrap();
+
+        // The migration fails without updating the schema version, so it runs again on the next open
+        let mut storage_manager = StorageManager::new(None, Box::<Memory>::default()).await.unwrap();
+        storage_manager.storage.inner = Box::new(storage);
+        storage_manager.account_indexes = vec![0];
+        storage_manager
+            .storage
+            .set(DATABASE_SCHEMA_VERSION_KEY, 1)
+            .await
+            .unwrap();
+        assert!(migrate(&mut storage_manager).await.is_err());
+        assert_eq!(
+            storage_manager.get::<u8>(DATABASE_SCHEMA_VERSION_KEY).await.unwrap(),
+            Some(1)
+        );
+    }
+
+    #[tokio::test]
+    async fn unsupported_version() {
+        let mut storage = Memory::default();
+        storage
+            .set(DATABASE_SCHEMA_VERSION_KEY, (DATABASE_SCHEMA_VERSION + 1).to_string())
+            .await
+            .unwrap();
+
+        assert!(matches!(
+            StorageManager::new(None, Box::new(storage)).await,
+            Err(crate::wallet::Error::Storage(_))
+        ));
+    }
+}
@@ -5,8 +5,14 @@
 pub mod adapter;
 /// Storage constants.
 pub mod constants;
+/// Storage functions related to the event log.
+#[cfg(feature = "events")]
+#[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+mod event_log;
 /// Storage manager.
 pub mod manager;
+/// Storage schema migrations.
+mod migration;
 /// Storage functions related to participation.
 #[cfg(feature = "participation")]
 #[cfg_attr(docsrs, doc(cfg(feature = "participation")))]
@@ -34,9 +40,10 @@ impl Storage {
         match self.inner.get(key).await? {
             Some(record) => {
                 if let Some(key) = &self.encryption_key {
-                    if serde_json::from_str::<Vec<u8>>(&record).is_ok() {
+                    // Encrypted records are stored as a JSON array of the ciphertext bytes
+                    if let Ok(encrypted_record) = serde_json::from_str::<Vec<u8>>(&record) {
                         Ok(Some(serde_json::from_str(&String::from_utf8_lossy(
-                            &chacha::aead_decrypt(key, record.as_bytes())?,
+                            &chacha::aead_decrypt(key, &encrypted_record)?,
                         ))?))
                     } else {
                         Ok(Some(serde_json::from_str(&record)?))
@@ -64,7 +71,6 @@ impl Storage {
             .await
     }
 
-    #[allow(dead_code)]
     async fn batch_set(&mut self, records: HashMap<String, String>) -> crate::wallet::Result<()> {
         self.inner
             .batch_set(if let Some(key) = &self.encryption_key {
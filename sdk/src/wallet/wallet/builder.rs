@@ -14,12 +14,11 @@ use tokio::sync::RwLock;
 
 #[cfg(feature = "events")]
 use crate::wallet::events::EventEmitter;
-#[cfg(all(feature = "storage", not(feature = "rocksdb")))]
-use crate::wallet::storage::adapter::memory::Memory;
 #[cfg(feature = "storage")]
 use crate::wallet::{
     account::AccountDetails,
     storage::{
+        adapter::{memory::Memory, StorageAdapter},
         constants::default_storage_path,
         manager::{ManagerStorage, StorageManager},
     },
@@ -36,6 +35,8 @@ pub struct WalletBuilder {
     coin_type: Option<u32>,
     #[cfg(feature = "storage")]
     storage_options: Option<StorageOptions>,
+    #[cfg(all(feature = "events", feature = "storage"))]
+    event_log: Option<bool>,
     #[serde(default, skip_serializing, skip_deserializing)]
     pub(crate) secret_manager: Option<Arc<RwLock<SecretManager>>>,
 }
@@ -53,11 +54,12 @@ pub(crate) struct StorageOptions {
 #[cfg(feature = "storage")]
 impl Default for StorageOptions {
     fn default() -> Self {
+        let storage_path = PathBuf::from(default_storage_path());
         Self {
-            storage_path: default_storage_path().into(),
+            manager_store: ManagerStorage::from_path(&storage_path),
+            storage_path,
             storage_file_name: None,
             storage_encryption_key: None,
-            manager_store: ManagerStorage::default(),
         }
     }
 }
@@ -96,17 +98,29 @@ impl WalletBuilder {
         self
     }
 
-    /// Set the storage path to be used.
+    /// Set the storage path to be used. With the `sqlite` feature, a path to a `.sqlite` or `.db` file selects the
+    /// SQLite storage.
     #[cfg(feature = "storage")]
     #[cfg_attr(docsrs, doc(cfg(feature = "storage")))]
     pub fn with_storage_path(mut self, path: &str) -> Self {
+        let storage_path = PathBuf::from(path);
         self.storage_options = Some(StorageOptions {
-            storage_path: path.into(),
+            manager_store: ManagerStorage::from_path(&storage_path),
+            storage_path,
             ..Default::default()
         });
         self
     }
 
+    /// Set if the events emitted from syncing are stored in the event log, so they can be read again with
+    /// [`Wallet::events_since()`]. Disabled by default.
+    #[cfg(all(feature = "events", feature = "storage"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "events", feature = "storage"))))]
+    pub fn with_event_log(mut self, enabled: impl Into<Option<bool>>) -> Self {
+        self.event_log = enabled.into();
+        self
+    }
+
     /// Builds the wallet
     #[allow(unreachable_code, unused_mut)]
     pub async fn finish(mut self) -> crate::wallet::Result<Wallet> {
@@ -115,9 +129,19 @@ impl WalletBuilder {
         #[cfg(feature = "storage")]
         let storage_options = self.storage_options.clone().unwrap_or_default();
         #[cfg(feature = "storage")]
+        let storage_exists = match storage_options.manager_store {
+            // The storage path can be an existing directory without the database file
+            #[cfg(feature = "sqlite")]
+            ManagerStorage::Sqlite => {
+                crate::wallet::storage::manager::sqlite_file_path(&storage_options.storage_path).exists()
+            }
+            #[allow(unreachable_patterns)]
+            _ => storage_options.storage_path.exists(),
+        };
+        #[cfg(feature = "storage")]
         // Check if the db exists and if not, return an error if one parameter is missing, because otherwise the db
         // would be created with an empty parameter which just leads to errors later
-        if !storage_options.storage_path.is_dir() {
+        if !storage_exists {
             if self.client_options.is_none() {
                 return Err(crate::wallet::Error::MissingParameter("client_options"));
             }
@@ -128,20 +152,22 @@ impl WalletBuilder {
                 return Err(crate::wallet::Error::MissingParameter("secret_manager"));
             }
         }
-        #[cfg(all(feature = "rocksdb", feature = "storage"))]
-        let storage =
-            crate::wallet::storage::adapter::rocksdb::RocksdbStorageAdapter::new(storage_options.storage_path.clone())?;
-        #[cfg(all(not(feature = "rocksdb"), feature = "storage"))]
-        let storage = Memory::default();
+        #[cfg(feature = "storage")]
+        let storage: Box<dyn StorageAdapter + Send + Sync> = match storage_options.manager_store {
+            #[cfg(feature = "rocksdb")]
+            ManagerStorage::Rocksdb => Box::new(crate::wallet::storage::adapter::rocksdb::RocksdbStorageAdapter::new(
+                storage_options.storage_path.clone(),
+            )?),
+            #[cfg(feature = "sqlite")]
+            ManagerStorage::Sqlite => Box::new(crate::wallet::storage::adapter::sqlite::SqliteStorageAdapter::new(
+                crate::wallet::storage::manager::sqlite_file_path(&storage_options.storage_path),
+            )?),
+            #[allow(unreachable_patterns)]
+            _ => Box::new(Memory::default()),
+        };
 
         #[cfg(feature = "storage")]
-        let mut storage_manager = Arc::new(tokio::sync::Mutex::new(
-            StorageManager::new(
-                None,
-                Box::new(storage) as Box<dyn crate::wallet::storage::adapter::StorageAdapter + Send + Sync>,
-            )
-            .await?,
-        ));
+        let mut storage_manager = Arc::new(tokio::sync::Mutex::new(StorageManager::new(None, storage).await?));
 
         #[cfg(feature = "storage")]
         let read_manager_builder = storage_manager.lock().await.get_wallet_data().await?;
@@ -172,6 +198,11 @@ impl WalletBuilder {
             self.secret_manager.replace(secret_manager);
         }
 
+        #[cfg(all(feature = "events", feature = "storage"))]
+        if self.event_log.is_none() {
+            self.event_log = read_manager_builder.as_ref().and_then(|data| data.event_log);
+        }
+
         if self.coin_type.is_none() {
             let coin_type =
                 read_manager_builder
@@ -187,6 +218,10 @@ impl WalletBuilder {
         // Store wallet data in storage
         #[cfg(feature = "storage")]
         storage_manager.lock().await.save_wallet_data(&self).await?;
+        #[cfg(all(feature = "events", feature = "storage"))]
+        {
+            storage_manager.lock().await.event_log = self.event_log.unwrap_or_default();
+        }
 
         let client = self
             .client_options
@@ -233,6 +268,8 @@ impl WalletBuilder {
         Ok(Wallet {
             accounts: Arc::new(RwLock::new(accounts)),
             background_syncing_status: Arc::new(AtomicUsize::new(0)),
+            #[cfg(feature = "mqtt")]
+            mqtt_syncing_status: Arc::new(AtomicUsize::new(0)),
             client_options: Arc::new(RwLock::new(
                 self.client_options
                     .ok_or(crate::wallet::Error::MissingParameter("client_options"))?,
@@ -258,6 +295,8 @@ impl WalletBuilder {
             client_options: Some(wallet.client_options.read().await.clone()),
             coin_type: Some(wallet.coin_type.load(Ordering::Relaxed)),
             storage_options: Some(wallet.storage_options.clone()),
+            #[cfg(feature = "events")]
+            event_log: Some(wallet.storage_manager.lock().await.event_log),
             secret_manager: Some(wallet.secret_manager.clone()),
         }
     }
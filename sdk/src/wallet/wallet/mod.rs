@@ -14,10 +14,12 @@ use tokio::sync::RwLock;
 #[cfg(feature = "storage")]
 use self::builder::StorageOptions;
 pub use self::builder::WalletBuilder;
+#[cfg(all(feature = "events", feature = "storage"))]
+use crate::wallet::events::types::EventLogEntry;
 #[cfg(feature = "events")]
 use crate::wallet::events::{
-    types::{Event, WalletEventType},
-    EventEmitter,
+    types::{Event, EventFilter, WalletEventType},
+    EventEmitter, EventStream,
 };
 #[cfg(feature = "storage")]
 use crate::wallet::storage::manager::StorageManager;
@@ -37,6 +39,9 @@ pub struct Wallet {
     pub(crate) accounts: Arc<RwLock<Vec<Account>>>,
     // 0 = not running, 1 = running, 2 = stopping
     pub(crate) background_syncing_status: Arc<AtomicUsize>,
+    // 0 = not running, 1 = running, 2 = stopping
+    #[cfg(feature = "mqtt")]
+    pub(crate) mqtt_syncing_status: Arc<AtomicUsize>,
     pub(crate) client_options: Arc<RwLock<ClientOptions>>,
     pub(crate) coin_type: Arc<AtomicU32>,
     pub(crate) secret_manager: Arc<RwLock<SecretManager>>,
@@ -150,7 +155,15 @@ impl Wallet {
         Ok(balance)
     }
 
-    /// Listen to wallet events, empty vec will listen to all events
+    /// Returns a stream of the wallet events that match the filter, starting with the next emitted event.
+    #[cfg(feature = "events")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
+    pub async fn event_stream(&self, filter: EventFilter) -> EventStream {
+        self.event_emitter.lock().await.subscribe(filter)
+    }
+
+    /// Listen to wallet events, empty vec will listen to all events. The handler is invoked by a task that drains a
+    /// [`Wallet::event_stream()`], so like the stream it misses events if it lags behind.
     #[cfg(feature = "events")]
     #[cfg_attr(docsrs, doc(cfg(feature = "events")))]
     pub async fn listen<F>(&self, events: Vec<WalletEventType>, handler: F)
@@ -169,6 +182,23 @@ impl Wallet {
         emitter.clear(events);
     }
 
+    /// Returns the events of the event log with a sequence number greater than `sequence_number`, so processing can
+    /// be resumed after the last handled event. Events are only logged if it's enabled with
+    /// [`WalletBuilder::with_event_log()`].
+    #[cfg(all(feature = "events", feature = "storage"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "events", feature = "storage"))))]
+    pub async fn events_since(&self, sequence_number: u64) -> crate::wallet::Result<Vec<EventLogEntry>> {
+        self.storage_manager.lock().await.events_since(sequence_number).await
+    }
+
+    /// Removes the events of the event log with a sequence number up to and including `sequence_number`, once they
+    /// were processed, so the event log doesn't keep growing.
+    #[cfg(all(feature = "events", feature = "storage"))]
+    #[cfg_attr(docsrs, doc(cfg(all(feature = "events", feature = "storage"))))]
+    pub async fn prune_events(&self, sequence_number: u64) -> crate::wallet::Result<()> {
+        self.storage_manager.lock().await.prune_events(sequence_number).await
+    }
+
     /// Generates a new random mnemonic.
     pub fn generate_mnemonic(&self) -> crate::wallet::Result<String> {
         Ok(Client::generate_mnemonic()?)
@@ -105,6 +105,16 @@ impl Wallet {
                     .await?
             }
             SecretManager::Placeholder(_) => return Err(crate::client::Error::PlaceholderSecretManager.into()),
+            secret_manager @ (SecretManager::Remote(_) | SecretManager::Custom(_)) => {
+                secret_manager
+                    .generate_addresses(
+                        self.coin_type.load(Ordering::Relaxed),
+                        account_index,
+                        address_index..address_index + 1,
+                        options,
+                    )
+                    .await?
+            }
         };
 
         Ok(*address
@@ -0,0 +1,205 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::{collections::HashSet, str::FromStr, sync::atomic::Ordering, time::Duration};
+
+use tokio::{
+    sync::{mpsc::unbounded_channel, RwLock},
+    time::sleep,
+};
+
+use crate::{
+    client::node_api::mqtt::{MqttEvent, MqttPayload, Topic, TopicEvent},
+    types::{api::core::response::OutputWithMetadataResponse, block::payload::transaction::TransactionId},
+    wallet::{
+        account::{operations::syncing::SyncOptions, Account},
+        Wallet,
+    },
+};
+
+impl Wallet {
+    /// Start syncing all accounts from the MQTT events of the node, instead of polling it in an interval like
+    /// [`Wallet::start_background_syncing()`]. The accounts are fully synced at the start and after every reconnection
+    /// to the broker, in between outputs on the account addresses and inclusions of pending transactions are applied
+    /// as they get published. Outputs owned by alias or NFT addresses are only updated by the full syncs.
+    pub async fn start_mqtt_syncing(&self, options: Option<SyncOptions>) -> crate::wallet::Result<()> {
+        log::debug!("[start_mqtt_syncing]");
+        let mqtt_syncing_status = self.mqtt_syncing_status.clone();
+        // stop existing process if running
+        if mqtt_syncing_status.load(Ordering::Relaxed) == 1 {
+            mqtt_syncing_status.store(2, Ordering::Relaxed);
+        };
+        while mqtt_syncing_status.load(Ordering::Relaxed) == 2 {
+            log::debug!("[mqtt_syncing]: waiting for the old process to stop");
+            sleep(Duration::from_secs(1)).await;
+        }
+
+        let client = self.get_client().await?;
+        mqtt_syncing_status.store(1, Ordering::Relaxed);
+        let accounts = self.accounts.clone();
+        let _mqtt_syncing = std::thread::spawn(move || {
+            #[cfg(not(target_family = "wasm"))]
+            let runtime = tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            #[cfg(target_family = "wasm")]
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .unwrap();
+            runtime.block_on(async {
+                let (event_sender, mut event_receiver) = unbounded_channel::<TopicEvent>();
+                let mut mqtt_event_receiver = client.mqtt_event_receiver();
+                let mut subscribed_topics = HashSet::new();
+                let mut connected_before = false;
+
+                sync_accounts(&accounts, &options).await;
+
+                while mqtt_syncing_status.load(Ordering::Relaxed) != 2 {
+                    // Follow new addresses and pending transactions, and stop following included transactions
+                    match mqtt_syncing_topics(&accounts).await {
+                        Ok(topics) => {
+                            let new_topics = topics.difference(&subscribed_topics).cloned().collect::<Vec<_>>();
+                            if !new_topics.is_empty() {
+                                let event_sender = event_sender.clone();
+                                match client
+                                    .subscribe(new_topics.clone(), move |event| {
+                                        event_sender.send(event.clone()).ok();
+                                    })
+                                    .await
+                                {
+                                    Ok(()) => subscribed_topics.extend(new_topics),
+                                    Err(err) => log::debug!("[mqtt_syncing] error: {}", err),
+                                }
+                            }
+                            let old_topics = subscribed_topics.difference(&topics).cloned().collect::<Vec<_>>();
+                            if !old_topics.is_empty() {
+                                match client.unsubscribe(old_topics.clone()).await {
+                                    Ok(()) => subscribed_topics.retain(|topic| !old_topics.contains(topic)),
+                                    Err(err) => log::debug!("[mqtt_syncing] error: {}", err),
+                                }
+                            }
+                        }
+                        Err(err) => log::debug!("[mqtt_syncing] error: {}", err),
+                    }
+
+                    tokio::select! {
+                        Some(event) = event_receiver.recv() => {
+                            handle_topic_event(&accounts, event, &options).await;
+                        }
+                        Ok(()) = mqtt_event_receiver.changed() => {
+                            let mqtt_event = mqtt_event_receiver.borrow().clone();
+                            // Events could have been missed while the broker was disconnected
+                            if mqtt_event == MqttEvent::Connected {
+                                if connected_before {
+                                    sync_accounts(&accounts, &options).await;
+                                }
+                                connected_before = true;
+                            }
+                        }
+                        // Check regularly if the process should stop
+                        _ = sleep(Duration::from_secs(1)) => {}
+                    }
+                }
+
+                if !subscribed_topics.is_empty() {
+                    if let Err(err) = client.unsubscribe(subscribed_topics.into_iter().collect()).await {
+                        log::debug!("[mqtt_syncing] error: {}", err);
+                    }
+                }
+                mqtt_syncing_status.store(0, Ordering::Relaxed);
+                log::debug!("[mqtt_syncing]: stopped");
+            });
+        });
+        Ok(())
+    }
+
+    /// Stop the MQTT syncing of the accounts
+    pub async fn stop_mqtt_syncing(&self) -> crate::wallet::Result<()> {
+        log::debug!("[stop_mqtt_syncing]");
+        // immediately return if not running
+        if self.mqtt_syncing_status.load(Ordering::Relaxed) == 0 {
+            return Ok(());
+        }
+        // send stop request
+        self.mqtt_syncing_status.store(2, Ordering::Relaxed);
+        // wait until it stopped
+        while self.mqtt_syncing_status.load(Ordering::Relaxed) != 0 {
+            #[cfg(target_family = "wasm")]
+            gloo_timers::future::TimeoutFuture::new(10).await;
+            #[cfg(not(target_family = "wasm"))]
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        }
+        Ok(())
+    }
+}
+
+async fn sync_accounts(accounts: &RwLock<Vec<Account>>, options: &Option<SyncOptions>) {
+    log::debug!("[mqtt_syncing]: syncing accounts");
+    for account in accounts.read().await.iter() {
+        if let Err(err) = account.sync(options.clone()).await {
+            log::debug!("[mqtt_syncing] error: {}", err);
+        }
+    }
+}
+
+async fn mqtt_syncing_topics(accounts: &RwLock<Vec<Account>>) -> crate::wallet::Result<HashSet<Topic>> {
+    let mut topics = HashSet::new();
+    for account in accounts.read().await.iter() {
+        topics.extend(account.mqtt_syncing_topics().await?);
+    }
+    Ok(topics)
+}
+
+async fn handle_topic_event(accounts: &RwLock<Vec<Account>>, event: TopicEvent, options: &Option<SyncOptions>) {
+    log::debug!("[mqtt_syncing]: received event for {}", event.topic);
+    if let Err(err) = try_handle_topic_event(accounts, event, options).await {
+        log::debug!("[mqtt_syncing] error: {}", err);
+    }
+}
+
+async fn try_handle_topic_event(
+    accounts: &RwLock<Vec<Account>>,
+    event: TopicEvent,
+    options: &Option<SyncOptions>,
+) -> crate::wallet::Result<()> {
+    if let Some(bech32_address) = event.topic.strip_prefix("outputs/unlock/address/") {
+        let bech32_address = bech32_address.trim_end_matches("/spent");
+        let MqttPayload::Json(payload) = event.payload else {
+            return Ok(());
+        };
+        let output_response: OutputWithMetadataResponse = serde_json::from_value(payload)?;
+
+        for account in accounts.read().await.iter() {
+            let options = match options {
+                Some(options) => options.clone(),
+                None => account.default_sync_options().await,
+            };
+            if account
+                .update_account_with_output_response(bech32_address, output_response.clone(), &options)
+                .await?
+            {
+                break;
+            }
+        }
+    } else if let Some(transaction_id) = event
+        .topic
+        .strip_prefix("transactions/")
+        .and_then(|topic| topic.strip_suffix("/included-block"))
+    {
+        let transaction_id = TransactionId::from_str(transaction_id)?;
+
+        for account in accounts.read().await.iter() {
+            if account.is_pending_transaction(&transaction_id).await {
+                // The transaction created outputs that aren't known yet, so the account needs a full sync
+                if account.sync_pending_transactions().await? {
+                    account.sync(options.clone()).await?;
+                }
+                break;
+            }
+        }
+    }
+
+    Ok(())
+}
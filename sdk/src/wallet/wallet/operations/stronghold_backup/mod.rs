@@ -23,7 +23,8 @@ impl Wallet {
         log::debug!("[backup] creating a stronghold backup");
         let mut secret_manager = self.secret_manager.write().await;
 
-        let secret_manager_dto = SecretManagerDto::from(&*secret_manager);
+        // A custom secret manager can't be stored in the backup
+        let secret_manager_dto = SecretManagerDto::try_from(&*secret_manager)?;
 
         match &mut *secret_manager {
             // Backup with existing stronghold
@@ -51,7 +51,14 @@ pub(crate) async fn store_data_to_stronghold(
 
     let mut serialized_accounts = Vec::new();
     for account in wallet.accounts.read().await.iter() {
-        serialized_accounts.push(serde_json::to_string(&*account.read().await)?);
+        // Include the historical transactions that aren't loaded from the storage
+        let transactions = account.transactions().await?;
+        let mut account_details = account.read().await.clone();
+        account_details.transactions = transactions
+            .into_iter()
+            .map(|transaction| (transaction.transaction_id, transaction))
+            .collect();
+        serialized_accounts.push(serde_json::to_string(&account_details)?);
     }
 
     stronghold
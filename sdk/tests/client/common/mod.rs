@@ -4,13 +4,17 @@
 #![allow(clippy::redundant_pub_crate, dead_code)]
 
 mod constants;
+mod remote_signer;
 
 use iota_sdk::client::{
     constants::SHIMMER_COIN_TYPE, node_api::indexer::query_parameters::QueryParameter, request_funds_from_faucet,
     secret::SecretManager, Client, Result,
 };
 
-pub use self::constants::{FAUCET_URL, NODE_LOCAL};
+pub use self::{
+    constants::{FAUCET_URL, NODE_LOCAL},
+    remote_signer::{spawn_faulty_remote_signer, spawn_remote_signer},
+};
 
 /// Sets up a Client with node health ignored.
 pub fn setup_client_with_node_health_ignored() -> Client {
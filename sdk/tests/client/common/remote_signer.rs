@@ -0,0 +1,117 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+//! Reference implementation of the signing service protocol used by the `RemoteSecretManager`, keeping the keys in a
+//! mnemonic secret manager.
+
+use std::{convert::Infallible, net::SocketAddr, sync::Arc};
+
+use crypto::keys::slip10::Chain;
+use hyper::{
+    header::AUTHORIZATION,
+    service::{make_service_fn, service_fn},
+    Body, Request, Response, Server, StatusCode,
+};
+use iota_sdk::{
+    client::secret::{
+        remote::{GenerateAddressesParams, SignEd25519Params},
+        SecretManage, SecretManager,
+    },
+    types::block::{address::dto::AddressDto, signature::dto::Ed25519SignatureDto},
+};
+use serde_json::{json, Value};
+use url::Url;
+
+/// Spawns a signing service for the given mnemonic on a free local port and returns its URL. If a bearer token is
+/// provided, requests without it are rejected.
+pub async fn spawn_remote_signer(mnemonic: &str, bearer_token: Option<&str>) -> Url {
+    spawn_signer(mnemonic, mnemonic, bearer_token).await
+}
+
+/// Spawns a faulty signing service that generates the addresses for `mnemonic`, but signs with the keys of
+/// `signing_mnemonic`.
+pub async fn spawn_faulty_remote_signer(mnemonic: &str, signing_mnemonic: &str) -> Url {
+    spawn_signer(mnemonic, signing_mnemonic, None).await
+}
+
+async fn spawn_signer(mnemonic: &str, signing_mnemonic: &str, bearer_token: Option<&str>) -> Url {
+    let secret_managers = Arc::new((
+        SecretManager::try_from_mnemonic(mnemonic).unwrap(),
+        SecretManager::try_from_mnemonic(signing_mnemonic).unwrap(),
+    ));
+    let authorization = bearer_token.map(|bearer_token| format!("Bearer {bearer_token}"));
+
+    let make_service = make_service_fn(move |_| {
+        let secret_managers = secret_managers.clone();
+        let authorization = authorization.clone();
+        async move {
+            Ok::<_, Infallible>(service_fn(move |request| {
+                handle_request(secret_managers.clone(), authorization.clone(), request)
+            }))
+        }
+    });
+
+    let server = Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0))).serve(make_service);
+    let url = format!("http://{}", server.local_addr()).parse().unwrap();
+    tokio::spawn(server);
+
+    url
+}
+
+async fn handle_request(
+    secret_managers: Arc<(SecretManager, SecretManager)>,
+    authorization: Option<String>,
+    request: Request<Body>,
+) -> Result<Response<Body>, Infallible> {
+    if authorization.is_some()
+        && request
+            .headers()
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            != authorization.as_deref()
+    {
+        let mut response = Response::new(Body::empty());
+        *response.status_mut() = StatusCode::UNAUTHORIZED;
+        return Ok(response);
+    }
+
+    let body = hyper::body::to_bytes(request.into_body()).await.unwrap();
+    let request: Value = serde_json::from_slice(&body).unwrap();
+
+    let result = match request["method"].as_str() {
+        Some("generateAddresses") => {
+            let params: GenerateAddressesParams = serde_json::from_value(request["params"].clone()).unwrap();
+            secret_managers
+                .0
+                .generate_addresses(
+                    params.coin_type,
+                    params.account_index,
+                    params.address_indexes,
+                    params.options,
+                )
+                .await
+                .map(|addresses| json!(addresses.iter().map(AddressDto::from).collect::<Vec<_>>()))
+                .map_err(|error| (-32000, error.to_string()))
+        }
+        Some("signEd25519") => {
+            let params: SignEd25519Params = serde_json::from_value(request["params"].clone()).unwrap();
+            let message: Vec<u8> = prefix_hex::decode(&params.message).unwrap();
+            secret_managers
+                .1
+                .sign_ed25519(&message, &Chain::from_u32(params.chain))
+                .await
+                .map(|signature| json!(Ed25519SignatureDto::from(&signature)))
+                .map_err(|error| (-32000, error.to_string()))
+        }
+        _ => Err((-32601, "method not found".to_string())),
+    };
+
+    let response = match result {
+        Ok(result) => json!({ "jsonrpc": "2.0", "id": request["id"], "result": result }),
+        Err((code, message)) => {
+            json!({ "jsonrpc": "2.0", "id": request["id"], "error": { "code": code, "message": message } })
+        }
+    };
+
+    Ok(Response::new(Body::from(response.to_string())))
+}
@@ -0,0 +1,243 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use iota_sdk::{
+    client::{
+        api::input_selection::{CoinSelectionStrategy, InputSelection, Selected},
+        secret::types::InputSigningData,
+    },
+    types::block::{address::Address, input::INPUT_COUNT_MAX, output::Output, protocol::protocol_parameters},
+};
+
+use crate::client::{
+    addresses, build_inputs, build_outputs, unsorted_eq, Build::Basic, BECH32_ADDRESS_ED25519_0,
+    BECH32_ADDRESS_ED25519_1, BECH32_ADDRESS_ED25519_2,
+};
+
+const STRATEGIES: [CoinSelectionStrategy; 5] = [
+    CoinSelectionStrategy::SmallestFirst,
+    CoinSelectionStrategy::LargestFirst,
+    CoinSelectionStrategy::MinimizeRemainder,
+    CoinSelectionStrategy::AvoidAddressLinking,
+    CoinSelectionStrategy::ConsolidateDust { threshold: 500_000 },
+];
+
+const ADDRESSES: [&str; 3] = [
+    BECH32_ADDRESS_ED25519_0,
+    BECH32_ADDRESS_ED25519_1,
+    BECH32_ADDRESS_ED25519_2,
+];
+
+// Small deterministic xorshift generator, so failing cases can be reproduced.
+struct Generator(u64);
+
+impl Generator {
+    fn next(&mut self, range: std::ops::Range<u64>) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        range.start + self.0 % (range.end - range.start)
+    }
+}
+
+fn select(inputs: &[InputSigningData], outputs: &[Output], strategy: CoinSelectionStrategy) -> Selected {
+    InputSelection::new(
+        inputs.to_vec(),
+        outputs.to_vec(),
+        addresses(ADDRESSES.to_vec()),
+        protocol_parameters(),
+    )
+    .coin_selection_strategy(strategy)
+    .select()
+    .unwrap_or_else(|error| panic!("{strategy:?} failed: {error:?}"))
+}
+
+fn assert_valid(selected: &Selected, inputs: &[InputSigningData], outputs: &[Output]) {
+    let input_ids = inputs.iter().map(|input| *input.output_id()).collect::<HashSet<_>>();
+    let selected_ids = selected
+        .inputs
+        .iter()
+        .map(|input| *input.output_id())
+        .collect::<HashSet<_>>();
+
+    assert_eq!(selected_ids.len(), selected.inputs.len());
+    assert!(selected_ids.is_subset(&input_ids));
+    assert!(selected.inputs.len() <= INPUT_COUNT_MAX as usize);
+    assert!(outputs.iter().all(|output| selected.outputs.contains(output)));
+    assert_eq!(
+        selected.inputs.iter().map(|input| input.output.amount()).sum::<u64>(),
+        selected.outputs.iter().map(|output| output.amount()).sum::<u64>()
+    );
+}
+
+#[test]
+fn all_strategies_select_valid_inputs() {
+    let mut generator = Generator(0x2545_f491_4f6c_dd1d);
+
+    for _ in 0..50 {
+        let inputs = build_inputs(
+            (0..generator.next(1..12))
+                .map(|_| {
+                    Basic(
+                        generator.next(3..50) * 100_000,
+                        ADDRESSES[generator.next(0..3) as usize],
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                        None,
+                    )
+                })
+                .collect(),
+        );
+        let total = inputs.iter().map(|input| input.output.amount()).sum::<u64>();
+        // Either spend everything or leave enough for a remainder output
+        let max = total / 100_000;
+        let amount = if max > 6 { generator.next(3..max - 2) } else { max } * 100_000;
+        let outputs = build_outputs(vec![Basic(
+            amount,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        )]);
+
+        for strategy in STRATEGIES {
+            assert_valid(&select(&inputs, &outputs, strategy), &inputs, &outputs);
+        }
+    }
+}
+
+#[test]
+fn largest_first() {
+    let inputs = build_inputs(vec![
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(5_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(2_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs(vec![Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = select(&inputs, &outputs, CoinSelectionStrategy::LargestFirst);
+
+    assert_valid(&selected, &inputs, &outputs);
+    assert!(unsorted_eq(&selected.inputs, &inputs[1..2]));
+
+    let selected = select(&inputs, &outputs, CoinSelectionStrategy::SmallestFirst);
+
+    assert!(unsorted_eq(&selected.inputs, &inputs[0..1]));
+}
+
+#[test]
+fn minimize_remainder() {
+    let inputs = build_inputs(vec![
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(2_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(3_500_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(4_500_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    // Only 3.5 + 4.5 matches exactly
+    let outputs = build_outputs(vec![Basic(
+        8_000_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = select(&inputs, &outputs, CoinSelectionStrategy::MinimizeRemainder);
+
+    assert_valid(&selected, &inputs, &outputs);
+    assert!(selected.remainder.is_none());
+    assert!(unsorted_eq(&selected.inputs, &inputs[2..4]));
+}
+
+#[test]
+fn avoid_address_linking() {
+    let inputs = build_inputs(vec![
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(1_500_000, BECH32_ADDRESS_ED25519_1, None, None, None, None, None, None),
+        Basic(2_000_000, BECH32_ADDRESS_ED25519_2, None, None, None, None, None, None),
+        Basic(1_500_000, BECH32_ADDRESS_ED25519_2, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs(vec![Basic(
+        3_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let selected = select(&inputs, &outputs, CoinSelectionStrategy::AvoidAddressLinking);
+
+    assert_valid(&selected, &inputs, &outputs);
+    // Only address 2 has enough funds on its own
+    let address = Address::try_from_bech32(BECH32_ADDRESS_ED25519_2).unwrap();
+    assert!(selected
+        .inputs
+        .iter()
+        .all(|input| input.output.as_basic().address() == &address));
+}
+
+#[test]
+fn consolidate_dust() {
+    let inputs = build_inputs(vec![
+        Basic(5_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(300_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(400_000, BECH32_ADDRESS_ED25519_1, None, None, None, None, None, None),
+        Basic(2_000_000, BECH32_ADDRESS_ED25519_1, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs(vec![Basic(
+        4_000_000,
+        BECH32_ADDRESS_ED25519_2,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let input_selection = |strategy| {
+        InputSelection::new(
+            inputs.clone(),
+            outputs.clone(),
+            addresses(ADDRESSES.to_vec()),
+            protocol_parameters(),
+        )
+        .required_inputs(HashSet::from([*inputs[0].output_id()]))
+        .coin_selection_strategy(strategy)
+        .select()
+        .unwrap()
+    };
+
+    // The required input already fulfills the amount requirement
+    let selected = input_selection(CoinSelectionStrategy::SmallestFirst);
+
+    assert!(unsorted_eq(&selected.inputs, &inputs[0..1]));
+
+    let selected = input_selection(CoinSelectionStrategy::ConsolidateDust { threshold: 500_000 });
+
+    assert_valid(&selected, &inputs, &outputs);
+    assert!(unsorted_eq(&selected.inputs, &inputs[0..3]));
+}
@@ -0,0 +1,338 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::slip10::Chain;
+use iota_sdk::{
+    client::{
+        api::{input_selection::InputSelection, PreparedTransactionData},
+        constants::{HD_WALLET_TYPE, SHIMMER_COIN_TYPE},
+        secret::{types::InputSigningData, SecretManage, SecretManageExt, SecretManager},
+        Result,
+    },
+    types::block::{
+        address::{Address, AliasAddress},
+        input::{Input, UtxoInput},
+        ledger::{Ledger, LedgerSnapshot},
+        output::{
+            unlock_condition::{
+                AddressUnlockCondition, GovernorAddressUnlockCondition, ImmutableAliasAddressUnlockCondition,
+                StateControllerAddressUnlockCondition,
+            },
+            AliasId, AliasOutputBuilder, BasicOutputBuilder, ChainId, FoundryId, FoundryOutputBuilder,
+            InputsCommitment, NativeToken, Output, OutputId, SimpleTokenScheme, TokenId, TokenScheme,
+        },
+        payload::{
+            transaction::{RegularTransactionEssence, TransactionEssence, TransactionId},
+            TransactionPayload,
+        },
+        protocol::protocol_parameters,
+        rand::block::rand_block_id,
+        semantic::ConflictReason,
+        Error,
+    },
+};
+use primitive_types::U256;
+
+const MNEMONIC: &str = "mirror add nothing long orphan hat this rough scare gallery fork twelve old shrug voyage job table obscure mimic holiday possible proud giraffe fan";
+const TIMESTAMP: u32 = 1_000;
+
+struct Setup {
+    ledger: Ledger,
+    secret_manager: SecretManager,
+    address: Address,
+}
+
+async fn setup(amount: u64) -> Result<Setup> {
+    let secret_manager = SecretManager::try_from_mnemonic(MNEMONIC)?;
+    let address = secret_manager
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0];
+    let mut ledger = Ledger::new(protocol_parameters());
+
+    ledger.begin_milestone(TIMESTAMP)?;
+    ledger.create_output(
+        OutputId::new(TransactionId::null(), 0)?,
+        basic_output(amount, address),
+        rand_block_id(),
+    )?;
+    ledger.confirm_milestone()?;
+
+    Ok(Setup {
+        ledger,
+        secret_manager,
+        address,
+    })
+}
+
+fn basic_output(amount: u64, address: Address) -> Output {
+    BasicOutputBuilder::new_with_amount(amount)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(protocol_parameters().token_supply())
+        .unwrap()
+}
+
+// Selects inputs for the outputs among the unspent outputs of the ledger and signs the transaction.
+async fn transaction(setup: &Setup, outputs: Vec<Output>) -> Result<TransactionPayload> {
+    let protocol_parameters = setup.ledger.protocol_parameters().clone();
+    let available_inputs = setup
+        .ledger
+        .unspent_outputs()
+        .map(|ledger_output| InputSigningData {
+            output: ledger_output.output().clone(),
+            output_metadata: ledger_output.metadata().clone(),
+            chain: Some(Chain::from_u32_hardened(vec![
+                HD_WALLET_TYPE,
+                SHIMMER_COIN_TYPE,
+                0,
+                0,
+                0,
+            ])),
+        })
+        .collect();
+
+    let selected = InputSelection::new(
+        available_inputs,
+        outputs,
+        vec![setup.address],
+        protocol_parameters.clone(),
+    )
+    .timestamp(TIMESTAMP)
+    .select()?;
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(selected.inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            selected
+                .inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect(),
+        )
+        .with_outputs(selected.outputs)
+        .finish(&protocol_parameters)?,
+    );
+    let prepared_transaction_data = PreparedTransactionData {
+        essence,
+        inputs_data: selected.inputs,
+        remainder: None,
+    };
+    let unlocks = setup
+        .secret_manager
+        .sign_transaction_essence(&prepared_transaction_data, Some(TIMESTAMP))
+        .await?;
+
+    Ok(TransactionPayload::new(prepared_transaction_data.essence, unlocks)?)
+}
+
+#[tokio::test]
+async fn ledger_applies_transactions_per_milestone() -> Result<()> {
+    let mut setup = setup(10_000_000).await?;
+    let genesis_output_id = OutputId::new(TransactionId::null(), 0)?;
+
+    let first = transaction(&setup, vec![basic_output(1_000_000, setup.address)]).await?;
+    let second = transaction(&setup, vec![basic_output(2_000_000, setup.address)]).await?;
+
+    assert_eq!(setup.ledger.begin_milestone(TIMESTAMP + 1)?, 2);
+    assert_eq!(
+        setup.ledger.apply_transaction(&first, rand_block_id())?,
+        ConflictReason::None
+    );
+    assert_eq!(
+        setup.ledger.apply_transaction(&second, rand_block_id())?,
+        ConflictReason::InputUtxoAlreadySpentInThisMilestone
+    );
+
+    let utxo_changes = setup.ledger.confirm_milestone()?.clone();
+
+    assert_eq!(utxo_changes.index, 2);
+    assert_eq!(utxo_changes.consumed_outputs, vec![genesis_output_id]);
+    assert_eq!(
+        utxo_changes.created_outputs,
+        vec![OutputId::new(first.id(), 0)?, OutputId::new(first.id(), 1)?]
+    );
+    assert_eq!(setup.ledger.utxo_changes(2), Some(&utxo_changes));
+    assert_eq!(setup.ledger.ledger_index(), 2);
+    assert_eq!(setup.ledger.unspent_outputs().count(), 2);
+
+    let metadata = setup.ledger.output(&genesis_output_id).unwrap().metadata();
+    assert!(metadata.is_spent());
+    assert_eq!(metadata.milestone_index_spent(), Some(2));
+    assert_eq!(metadata.transaction_id_spent(), Some(&first.id()));
+
+    setup.ledger.begin_milestone(TIMESTAMP + 2)?;
+    assert_eq!(
+        setup.ledger.apply_transaction(&second, rand_block_id())?,
+        ConflictReason::InputUtxoAlreadySpent
+    );
+    setup.ledger.confirm_milestone()?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ledger_tracks_alias_and_foundry_chains() -> Result<()> {
+    let mut setup = setup(10_000_000).await?;
+    let token_supply = protocol_parameters().token_supply();
+
+    // Create an alias
+    let alias_output = AliasOutputBuilder::new_with_amount(1_000_000, AliasId::null())
+        .add_unlock_condition(StateControllerAddressUnlockCondition::new(setup.address))
+        .add_unlock_condition(GovernorAddressUnlockCondition::new(setup.address))
+        .finish_output(token_supply)?;
+    let transaction_0 = transaction(&setup, vec![alias_output]).await?;
+
+    setup.ledger.begin_milestone(TIMESTAMP + 1)?;
+    assert_eq!(
+        setup.ledger.apply_transaction(&transaction_0, rand_block_id())?,
+        ConflictReason::None
+    );
+    setup.ledger.confirm_milestone()?;
+
+    let alias_output_id = OutputId::new(transaction_0.id(), 0)?;
+    let alias_id = AliasId::from(&alias_output_id);
+    let alias_chain = ChainId::from(alias_id);
+    assert_eq!(
+        setup.ledger.chain_output(&alias_chain).unwrap().metadata().output_id(),
+        &alias_output_id
+    );
+
+    // Skipping a state index is an invalid transition
+    let invalid_alias_output =
+        AliasOutputBuilder::from(setup.ledger.chain_output(&alias_chain).unwrap().output().as_alias())
+            .with_alias_id(alias_id)
+            .with_state_index(2)
+            .finish_output(token_supply)?;
+    let invalid_transaction = transaction(&setup, vec![invalid_alias_output]).await?;
+
+    // Mint tokens with a new foundry controlled by the alias
+    let foundry_id = FoundryId::build(&AliasAddress::from(alias_id), 1, SimpleTokenScheme::KIND);
+    let token_id = TokenId::from(foundry_id);
+    let next_alias_output =
+        AliasOutputBuilder::from(setup.ledger.chain_output(&alias_chain).unwrap().output().as_alias())
+            .with_alias_id(alias_id)
+            .with_state_index(1)
+            .with_foundry_counter(1)
+            .finish_output(token_supply)?;
+    let foundry_output = FoundryOutputBuilder::new_with_amount(
+        1_000_000,
+        1,
+        TokenScheme::Simple(SimpleTokenScheme::new(U256::from(100), U256::from(0), U256::from(100))?),
+    )
+    .add_unlock_condition(ImmutableAliasAddressUnlockCondition::new(AliasAddress::from(alias_id)))
+    .add_native_token(NativeToken::new(token_id, U256::from(100))?)
+    .finish_output(token_supply)?;
+    let transaction_1 = transaction(&setup, vec![next_alias_output, foundry_output]).await?;
+
+    setup.ledger.begin_milestone(TIMESTAMP + 2)?;
+    assert_eq!(
+        setup.ledger.apply_transaction(&invalid_transaction, rand_block_id())?,
+        ConflictReason::InvalidChainStateTransition
+    );
+    assert_eq!(
+        setup.ledger.apply_transaction(&transaction_1, rand_block_id())?,
+        ConflictReason::None
+    );
+    let utxo_changes = setup.ledger.confirm_milestone()?;
+    assert!(utxo_changes.consumed_outputs.contains(&alias_output_id));
+
+    let alias = setup.ledger.chain_output(&alias_chain).unwrap();
+    assert_eq!(alias.metadata().transaction_id(), &transaction_1.id());
+    assert_eq!(alias.output().as_alias().state_index(), 1);
+    let foundry = setup.ledger.chain_output(&ChainId::from(foundry_id)).unwrap();
+    assert_eq!(
+        foundry.output().native_tokens().unwrap().first().unwrap().token_id(),
+        &token_id
+    );
+    assert!(setup.ledger.output(&alias_output_id).unwrap().metadata().is_spent());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn ledger_snapshot_round_trip() -> Result<()> {
+    let mut setup = setup(10_000_000).await?;
+    let alias_output = AliasOutputBuilder::new_with_amount(1_000_000, AliasId::null())
+        .add_unlock_condition(StateControllerAddressUnlockCondition::new(setup.address))
+        .add_unlock_condition(GovernorAddressUnlockCondition::new(setup.address))
+        .finish_output(protocol_parameters().token_supply())?;
+    let transaction_0 = transaction(&setup, vec![alias_output]).await?;
+
+    setup.ledger.begin_milestone(TIMESTAMP + 1)?;
+    assert!(matches!(setup.ledger.snapshot(), Err(Error::LedgerMilestonePending)));
+    setup.ledger.apply_transaction(&transaction_0, rand_block_id())?;
+    setup.ledger.confirm_milestone()?;
+
+    let snapshot = setup.ledger.snapshot()?;
+    let json = serde_json::to_string(&snapshot)?;
+    let restored = serde_json::from_str::<LedgerSnapshot>(&json)?;
+    assert_eq!(restored, snapshot);
+
+    let alias_chain = ChainId::from(AliasId::from(&OutputId::new(transaction_0.id(), 0)?));
+    let original_ledger = std::mem::replace(&mut setup.ledger, Ledger::from_snapshot(restored));
+
+    assert_eq!(setup.ledger.ledger_index(), original_ledger.ledger_index());
+    assert_eq!(setup.ledger.milestone_timestamp(), TIMESTAMP + 1);
+    assert_eq!(setup.ledger.utxo_changes(2), original_ledger.utxo_changes(2));
+    assert_eq!(
+        setup.ledger.chain_output(&alias_chain),
+        original_ledger.chain_output(&alias_chain)
+    );
+
+    // The restored ledger keeps validating against the snapshotted state
+    let transaction_1 = transaction(&setup, vec![basic_output(1_000_000, setup.address)]).await?;
+    assert!(matches!(
+        setup.ledger.begin_milestone(TIMESTAMP + 1),
+        Err(Error::LedgerMilestoneTimestampNotIncreasing { .. })
+    ));
+    setup.ledger.begin_milestone(TIMESTAMP + 2)?;
+    assert_eq!(
+        setup.ledger.apply_transaction(&transaction_1, rand_block_id())?,
+        ConflictReason::None
+    );
+    assert_eq!(setup.ledger.confirm_milestone()?.index, 3);
+
+    Ok(())
+}
+
+#[test]
+fn ledger_errors() {
+    let mut ledger = Ledger::new(protocol_parameters());
+    let output_id = OutputId::new(TransactionId::null(), 0).unwrap();
+    let address = Address::try_from_bech32("rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy").unwrap();
+
+    assert!(matches!(
+        ledger.create_output(output_id, basic_output(1_000_000, address), rand_block_id()),
+        Err(Error::LedgerNoPendingMilestone)
+    ));
+    assert!(matches!(
+        ledger.confirm_milestone(),
+        Err(Error::LedgerNoPendingMilestone)
+    ));
+
+    ledger.begin_milestone(TIMESTAMP).unwrap();
+    assert!(matches!(
+        ledger.begin_milestone(TIMESTAMP + 1),
+        Err(Error::LedgerMilestonePending)
+    ));
+
+    ledger
+        .create_output(output_id, basic_output(1_000_000, address), rand_block_id())
+        .unwrap();
+    assert_eq!(
+        ledger
+            .create_output(output_id, basic_output(1_000_000, address), rand_block_id())
+            .unwrap_err(),
+        Error::LedgerOutputAlreadyExists(output_id)
+    );
+    assert!(matches!(
+        ledger.create_output(
+            OutputId::new(TransactionId::null(), 1).unwrap(),
+            basic_output(1, address),
+            rand_block_id()
+        ),
+        Err(Error::InsufficientStorageDepositAmount { .. })
+    ));
+}
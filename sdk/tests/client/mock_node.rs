@@ -0,0 +1,192 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::time::Duration;
+
+use iota_sdk::{
+    client::{
+        constants::SHIMMER_COIN_TYPE,
+        mock_node::MockNode,
+        node_api::indexer::query_parameters::QueryParameter,
+        secret::{SecretManage, SecretManager},
+        Client, Result,
+    },
+    types::{
+        api::core::dto::LedgerInclusionStateDto,
+        block::{
+            address::Address,
+            output::{
+                unlock_condition::AddressUnlockCondition, BasicOutputBuilder, NftId, NftOutputBuilder, Output, OutputId,
+            },
+            payload::Payload,
+            protocol::dto::ProtocolParametersDto,
+            semantic::ConflictReason,
+        },
+    },
+};
+
+const MNEMONIC: &str = "endorse answer radar about source reunion marriage tag sausage weekend frost daring base attack because joke dream slender leisure group reason prepare broken river";
+
+fn basic_output(amount: u64, address: Address) -> Output {
+    BasicOutputBuilder::new_with_amount(amount)
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(MockNode::default_protocol_parameters().token_supply())
+        .unwrap()
+}
+
+async fn setup(milestone_interval: Option<Duration>) -> Result<(MockNode, Client, SecretManager, Vec<Address>)> {
+    let secret_manager = SecretManager::try_from_mnemonic(MNEMONIC)?;
+    let addresses = secret_manager
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..2, None)
+        .await?;
+
+    let node = MockNode::builder()
+        .with_output(basic_output(10_000_000, addresses[0]))
+        .with_milestone_interval(milestone_interval)
+        .finish()?;
+    let client = Client::builder().with_node(node.url().as_str())?.finish()?;
+
+    Ok((node, client, secret_manager, addresses))
+}
+
+async fn send(client: &Client, secret_manager: &SecretManager, amount: u64, address: Address) -> Result<Payload> {
+    let block = client
+        .block()
+        .with_secret_manager(secret_manager)
+        .with_outputs(vec![basic_output(amount, address)])?
+        .finish()
+        .await?;
+
+    Ok(block.payload().unwrap().clone())
+}
+
+#[tokio::test]
+async fn mock_node_serves_genesis_outputs() -> Result<()> {
+    let (node, client, _, addresses) = setup(None).await?;
+
+    let info = client.get_info().await?.node_info;
+    assert_eq!(info.protocol, ProtocolParametersDto::from(&node.protocol_parameters()));
+    assert_eq!(info.status.latest_milestone.index, 1);
+
+    let output_ids = client
+        .basic_output_ids(vec![QueryParameter::Address(addresses[0].to_bech32("rms"))])
+        .await?
+        .items;
+    assert_eq!(output_ids.len(), 1);
+
+    let output = client.get_output(&output_ids[0]).await?;
+    let token_supply = client.get_token_supply().await?;
+    assert_eq!(Output::try_from_dto(&output.output, token_supply)?.amount(), 10_000_000);
+    assert!(!output.metadata.is_spent);
+
+    let funded = node.add_outputs(vec![basic_output(1_000_000, addresses[1])])?;
+    assert_eq!(
+        client
+            .basic_output_ids(vec![QueryParameter::Address(addresses[1].to_bech32("rms"))])
+            .await?
+            .items,
+        funded
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_node_applies_transactions_on_milestones() -> Result<()> {
+    let (node, client, secret_manager, addresses) = setup(None).await?;
+
+    let Payload::Transaction(transaction) = send(&client, &secret_manager, 1_000_000, addresses[1]).await? else {
+        panic!("expected a transaction payload");
+    };
+    let block_id = client.get_included_block(&transaction.id()).await;
+    assert!(block_id.is_err());
+
+    node.issue_milestone();
+
+    let block = client.get_included_block(&transaction.id()).await?;
+    let metadata = client.get_block_metadata(&block.id()).await?;
+    assert_eq!(metadata.ledger_inclusion_state, Some(LedgerInclusionStateDto::Included));
+    assert_eq!(metadata.referenced_by_milestone_index, Some(2));
+
+    let output_ids = client
+        .basic_output_ids(vec![QueryParameter::Address(addresses[1].to_bech32("rms"))])
+        .await?
+        .items;
+    assert_eq!(output_ids, vec![OutputId::new(transaction.id(), 0)?]);
+
+    let utxo_changes = client.get_utxo_changes_by_index(2).await?;
+    assert_eq!(utxo_changes.consumed_outputs.len(), 1);
+    assert_eq!(utxo_changes.created_outputs.len(), 2);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_node_rejects_double_spends() -> Result<()> {
+    let (node, client, secret_manager, addresses) = setup(None).await?;
+
+    // Both transactions select the genesis output, as the first one isn't confirmed yet
+    let first = send(&client, &secret_manager, 1_000_000, addresses[1]).await?;
+    let second = send(&client, &secret_manager, 2_000_000, addresses[1]).await?;
+    node.issue_milestone();
+
+    let (Payload::Transaction(first), Payload::Transaction(second)) = (first, second) else {
+        panic!("expected transaction payloads");
+    };
+    assert!(client.get_included_block(&first.id()).await.is_ok());
+    assert!(client.get_included_block(&second.id()).await.is_err());
+
+    let tips = client.get_tips().await?;
+    let metadata = client.get_block_metadata(&tips[0]).await?;
+    assert_eq!(metadata.milestone_index, Some(2));
+
+    let conflicting = client
+        .find_blocks(&client.get_milestone_by_index(2).await?.essence().parents().to_vec())
+        .await?
+        .into_iter()
+        .find(|block| block.payload() == Some(&Payload::Transaction(second.clone())))
+        .unwrap();
+    let metadata = client.get_block_metadata(&conflicting.id()).await?;
+    assert_eq!(
+        metadata.ledger_inclusion_state,
+        Some(LedgerInclusionStateDto::Conflicting)
+    );
+    assert_eq!(
+        metadata.conflict_reason,
+        Some(ConflictReason::InputUtxoAlreadySpentInThisMilestone as u8)
+    );
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_node_issues_milestones_periodically() -> Result<()> {
+    let (_node, client, secret_manager, addresses) = setup(Some(Duration::from_millis(500))).await?;
+
+    let block = client
+        .block()
+        .with_secret_manager(&secret_manager)
+        .with_outputs(vec![basic_output(1_000_000, addresses[1])])?
+        .finish()
+        .await?;
+    let included = client.retry_until_included(&block.id(), Some(1), Some(10)).await?;
+
+    assert_eq!(included[0].0, block.id());
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn mock_node_rejects_duplicate_minted_chains() -> Result<()> {
+    let (node, _, _, addresses) = setup(None).await?;
+
+    let nft_output = NftOutputBuilder::new_with_amount(1_000_000, NftId::from([1; 32]))
+        .add_unlock_condition(AddressUnlockCondition::new(addresses[1]))
+        .finish_output(node.protocol_parameters().token_supply())?;
+
+    assert!(node.add_outputs(vec![nft_output.clone(), nft_output.clone()]).is_err());
+    // The node is still usable afterwards
+    assert_eq!(node.add_outputs(vec![nft_output])?.len(), 1);
+
+    Ok(())
+}
@@ -6,13 +6,17 @@ mod client_builder;
 mod common;
 mod error;
 mod input_selection;
+mod ledger;
 mod message_interface;
 mod mnemonic;
+#[cfg(feature = "test-utils")]
+mod mock_node;
 #[cfg(feature = "mqtt")]
 mod mqtt;
 mod node_api;
 mod secret_manager;
 mod signing;
+mod transaction_summary;
 mod transactions;
 
 use std::{
@@ -1,10 +1,21 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::client::{
-    api::GetAddressesBuilder, constants::SHIMMER_TESTNET_BECH32_HRP, secret::SecretManager, Result,
+use std::ops::Range;
+
+use crypto::keys::slip10::Chain;
+use iota_sdk::{
+    client::{
+        api::GetAddressesBuilder,
+        constants::SHIMMER_TESTNET_BECH32_HRP,
+        secret::{GenerateAddressOptions, SecretManage, SecretManager, SecretManagerDto},
+        Error, Result,
+    },
+    types::block::{address::Address, signature::Ed25519Signature},
 };
 
+use crate::client::common::spawn_remote_signer;
+
 #[tokio::test]
 async fn mnemonic_secret_manager_dto() -> Result<()> {
     let dto = r#"{"mnemonic": "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast"}"#;
@@ -26,6 +37,83 @@ async fn mnemonic_secret_manager_dto() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn remote_secret_manager_dto() -> Result<()> {
+    let mnemonic = "acoustic trophy damage hint search taste love bicycle foster cradle brown govern endless depend situate athlete pudding blame question genius transfer van random vast";
+    let url = spawn_remote_signer(mnemonic, Some("some_token")).await;
+
+    let dto = format!(r#"{{"remote": {{"url": "{url}", "bearerToken": "some_token"}}}}"#);
+    let secret_manager: SecretManager = dto.parse()?;
+
+    let addresses = GetAddressesBuilder::new(&secret_manager)
+        .with_bech32_hrp(SHIMMER_TESTNET_BECH32_HRP)
+        .with_account_index(0)
+        .with_range(0..1)
+        .finish()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        addresses[0],
+        "rms1qzev36lk0gzld0k28fd2fauz26qqzh4hd4cwymlqlv96x7phjxcw6v3ea5a".to_string()
+    );
+
+    // Requests without the right token are rejected by the signing service
+    let dto = format!(r#"{{"remote": {{"url": "{url}", "bearerToken": "wrong_token"}}}}"#);
+    let secret_manager: SecretManager = dto.parse()?;
+
+    let error = GetAddressesBuilder::new(&secret_manager)
+        .with_range(0..1)
+        .finish()
+        .await
+        .unwrap_err();
+    assert!(matches!(error, Error::Reqwest(_)));
+
+    Ok(())
+}
+
+// A custom secret manager whose keys aren't available
+struct LockedSecretManager;
+
+#[async_trait::async_trait]
+impl SecretManage for LockedSecretManager {
+    type Error = Error;
+
+    async fn generate_addresses(
+        &self,
+        _coin_type: u32,
+        _account_index: u32,
+        _address_indexes: Range<u32>,
+        _options: Option<GenerateAddressOptions>,
+    ) -> Result<Vec<Address>> {
+        Err(Error::SecretManager("locked".into()))
+    }
+
+    async fn sign_ed25519(&self, _msg: &[u8], _chain: &Chain) -> Result<Ed25519Signature> {
+        Err(Error::SecretManager("locked".into()))
+    }
+}
+
+#[tokio::test]
+async fn custom_secret_manager_error() -> Result<()> {
+    let secret_manager = SecretManager::Custom(Box::new(LockedSecretManager));
+
+    let error = GetAddressesBuilder::new(&secret_manager)
+        .with_range(0..1)
+        .finish()
+        .await
+        .unwrap_err();
+    assert_eq!(error.to_string(), "secret manager error: locked");
+
+    // It can't be converted to a DTO to be stored
+    assert!(matches!(
+        SecretManagerDto::try_from(&secret_manager),
+        Err(Error::CustomSecretManagerNotStorable)
+    ));
+
+    Ok(())
+}
+
 #[cfg(feature = "stronghold")]
 #[tokio::test]
 async fn stronghold_secret_manager_dto() -> Result<()> {
@@ -4,6 +4,10 @@
 mod alias;
 mod basic;
 mod nft;
+mod partially_signed;
+mod remote;
+mod simulate;
+mod transaction_bundle;
 
 use std::str::FromStr;
 
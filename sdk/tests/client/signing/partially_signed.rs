@@ -0,0 +1,230 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use crypto::keys::slip10::Chain;
+use iota_sdk::{
+    client::{
+        api::{
+            verify_semantic, PartiallySignedTransactionData, PartiallySignedTransactionDataDto, PreparedTransactionData,
+        },
+        constants::{HD_WALLET_TYPE, SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+        secret::{SecretManage, SecretManager},
+        Client, Error, Result,
+    },
+    types::block::{
+        address::{Address, AliasAddress},
+        input::{Input, UtxoInput},
+        output::{AliasId, InputsCommitment},
+        payload::transaction::{RegularTransactionEssence, TransactionEssence},
+        protocol::protocol_parameters,
+        semantic::ConflictReason,
+        unlock::{AliasUnlock, SignatureUnlock},
+    },
+};
+
+use crate::client::{
+    build_inputs, build_outputs,
+    Build::{Alias, Basic},
+    ALIAS_ID_1,
+};
+
+#[tokio::test]
+async fn co_signed_alias_state_transition() -> Result<()> {
+    // The state controller and the governor of the alias use their own secret managers
+    let state_controller = SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+    let governor = SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+    let chain = Chain::from_u32_hardened(vec![HD_WALLET_TYPE, SHIMMER_COIN_TYPE, 0, 0, 0]);
+
+    let state_controller_address = &state_controller
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+    let governor_address =
+        &governor.generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None).await?[0].to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+    let alias_id = AliasId::from_str(ALIAS_ID_1)?;
+    let alias_address = &Address::Alias(AliasAddress::new(alias_id)).to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let inputs = build_inputs(vec![
+        Alias(
+            1_000_000,
+            alias_id,
+            0,
+            state_controller_address,
+            governor_address,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Basic(1_000_000, alias_address, None, None, None, None, None, None),
+        Basic(1_000_000, governor_address, None, None, None, None, None, None),
+    ]);
+
+    let outputs = build_outputs(vec![
+        Alias(
+            1_000_000,
+            alias_id,
+            1,
+            state_controller_address,
+            governor_address,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Basic(2_000_000, alias_address, None, None, None, None, None, None),
+    ]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect(),
+        )
+        .with_outputs(outputs)
+        .finish(&protocol_parameters)?,
+    );
+
+    let prepared_transaction_data = PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    };
+
+    let partially_signed = PartiallySignedTransactionData::new(prepared_transaction_data.clone());
+    assert_eq!(partially_signed.missing_unlocks(Some(0))?.len(), 3);
+
+    // Each co-signer signs their own copy
+    let mut signed_by_state_controller = partially_signed.clone();
+    signed_by_state_controller.sign(&state_controller, &chain).await?;
+    let missing_unlocks = signed_by_state_controller.missing_unlocks(Some(0))?;
+    assert_eq!(missing_unlocks.len(), 1);
+    assert_eq!(
+        missing_unlocks[0].0,
+        *prepared_transaction_data.inputs_data[2].output_id()
+    );
+
+    let mut signed_by_governor = partially_signed.clone();
+    signed_by_governor.sign(&governor, &chain).await?;
+    assert_eq!(signed_by_governor.missing_unlocks(Some(0))?.len(), 2);
+
+    // The containers are exchanged serialized
+    let dto = serde_json::to_string(&PartiallySignedTransactionDataDto::from(&signed_by_governor))?;
+    let signed_by_governor = PartiallySignedTransactionData::try_from_dto(
+        &serde_json::from_str::<PartiallySignedTransactionDataDto>(&dto)?,
+        &protocol_parameters,
+    )?;
+
+    let mut merged = signed_by_state_controller;
+    merged.merge(signed_by_governor)?;
+    assert_eq!(merged.signatures().len(), 2);
+    assert!(merged.missing_unlocks(Some(0))?.is_empty());
+
+    let signed_transaction_data = merged.finish(Some(0))?;
+    let unlocks = signed_transaction_data.transaction_payload.unlocks();
+    assert_eq!(unlocks.len(), 3);
+    assert_eq!((*unlocks).get(0).unwrap().kind(), SignatureUnlock::KIND);
+    assert_eq!((*unlocks).get(1).unwrap().kind(), AliasUnlock::KIND);
+    assert_eq!((*unlocks).get(2).unwrap().kind(), SignatureUnlock::KIND);
+
+    let conflict = verify_semantic(
+        &signed_transaction_data.inputs_data,
+        &signed_transaction_data.transaction_payload,
+        100,
+    )?;
+
+    if conflict != ConflictReason::None {
+        panic!("{conflict:?}, with {:#?}", signed_transaction_data.transaction_payload);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn partially_signed_errors() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+    let chain = Chain::from_u32_hardened(vec![HD_WALLET_TYPE, SHIMMER_COIN_TYPE, 0, 0, 0]);
+    let bech32_address = &secret_manager
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+
+    let protocol_parameters = protocol_parameters();
+
+    let prepared_transaction_data = |amount| {
+        let inputs = build_inputs(vec![Basic(
+            amount,
+            bech32_address,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(chain.clone()),
+        )]);
+        let outputs = build_outputs(vec![Basic(amount, bech32_address, None, None, None, None, None, None)]);
+        let essence = TransactionEssence::Regular(
+            RegularTransactionEssence::builder(
+                protocol_parameters.network_id(),
+                InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+            )
+            .with_inputs(
+                inputs
+                    .iter()
+                    .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                    .collect(),
+            )
+            .with_outputs(outputs)
+            .finish(&protocol_parameters)
+            .unwrap(),
+        );
+        PreparedTransactionData {
+            essence,
+            inputs_data: inputs,
+            remainder: None,
+        }
+    };
+
+    let mut partially_signed = PartiallySignedTransactionData::new(prepared_transaction_data(1_000_000));
+    let other = PartiallySignedTransactionData::new(prepared_transaction_data(2_000_000));
+
+    assert!(matches!(
+        partially_signed.clone().finish(Some(0)),
+        Err(Error::MissingUnlocks(output_ids)) if output_ids.len() == 1
+    ));
+    assert!(matches!(
+        partially_signed.merge(other),
+        Err(Error::TransactionEssenceMismatch)
+    ));
+
+    // Signatures of another essence are rejected, also when they're deserialized
+    let mut other = PartiallySignedTransactionData::new(prepared_transaction_data(2_000_000));
+    other.sign_inputs(&secret_manager).await?;
+    assert!(matches!(
+        partially_signed.add_signature(other.signatures()[0].clone()),
+        Err(Error::Block(_))
+    ));
+    let mut value = serde_json::to_value(&partially_signed).unwrap();
+    value["signatures"] = serde_json::to_value(other.signatures()).unwrap();
+    assert!(serde_json::from_value::<PartiallySignedTransactionData>(value).is_err());
+
+    // Inputs with a chain are signed directly
+    partially_signed.sign_inputs(&secret_manager).await?;
+    let value = serde_json::to_value(&partially_signed).unwrap();
+    assert_eq!(
+        serde_json::from_value::<PartiallySignedTransactionData>(value).unwrap(),
+        partially_signed
+    );
+    assert!(partially_signed.finish(Some(0)).is_ok());
+
+    Ok(())
+}
@@ -0,0 +1,137 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::slip10::Chain;
+use iota_sdk::{
+    client::{
+        api::{verify_semantic, PreparedTransactionData},
+        constants::{HD_WALLET_TYPE, SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+        secret::{remote::RemoteSecretManager, SecretManage, SecretManageExt, SecretManager},
+        Client, Error, Result,
+    },
+    types::block::{
+        input::{Input, UtxoInput},
+        output::InputsCommitment,
+        payload::{
+            transaction::{RegularTransactionEssence, TransactionEssence},
+            TransactionPayload,
+        },
+        protocol::protocol_parameters,
+        semantic::ConflictReason,
+        unlock::{ReferenceUnlock, SignatureUnlock},
+    },
+};
+
+use crate::client::{
+    build_inputs, build_outputs,
+    common::{spawn_faulty_remote_signer, spawn_remote_signer},
+    Build::Basic,
+};
+
+#[tokio::test]
+async fn remote_ed25519_unlocks() -> Result<()> {
+    let url = spawn_remote_signer(&Client::generate_mnemonic()?, None).await;
+    let secret_manager = SecretManager::Remote(RemoteSecretManager::new(url));
+
+    let bech32_address_0 = &secret_manager
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+    let prepared_transaction_data = prepared_transaction_data(bech32_address_0)?;
+
+    let unlocks = secret_manager
+        .sign_transaction_essence(&prepared_transaction_data, Some(0))
+        .await?;
+
+    assert_eq!(unlocks.len(), 2);
+    assert_eq!((*unlocks).get(0).unwrap().kind(), SignatureUnlock::KIND);
+    assert_eq!((*unlocks).get(1).unwrap().kind(), ReferenceUnlock::KIND);
+
+    let tx_payload = TransactionPayload::new(prepared_transaction_data.essence.clone(), unlocks)?;
+
+    let conflict = verify_semantic(&prepared_transaction_data.inputs_data, &tx_payload, 100)?;
+
+    if conflict != ConflictReason::None {
+        panic!("{conflict:?}, with {tx_payload:#?}");
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn remote_signature_verification() -> Result<()> {
+    let mnemonic = Client::generate_mnemonic()?;
+    let bech32_address_0 = &SecretManager::try_from_mnemonic(&mnemonic)?
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+    let prepared_transaction_data = prepared_transaction_data(bech32_address_0)?;
+
+    // The signature is valid for the message, but doesn't belong to the address of the inputs
+    let secret_manager = SecretManager::Remote(RemoteSecretManager::new(
+        spawn_faulty_remote_signer(&mnemonic, &Client::generate_mnemonic()?).await,
+    ));
+    let chain = Chain::from_u32_hardened(vec![HD_WALLET_TYPE, SHIMMER_COIN_TYPE, 0, 0, 0]);
+    secret_manager.sign_ed25519(&[0; 32], &chain).await?;
+    assert!(matches!(
+        secret_manager
+            .sign_transaction_essence(&prepared_transaction_data, Some(0))
+            .await,
+        Err(Error::RemoteSecretManager(_))
+    ));
+
+    Ok(())
+}
+
+// Two inputs of the address with the chain `44'/4219'/0'/0'/0'`, sent to the same address
+fn prepared_transaction_data(bech32_address_0: &str) -> Result<PreparedTransactionData> {
+    let chain = Chain::from_u32_hardened(vec![HD_WALLET_TYPE, SHIMMER_COIN_TYPE, 0, 0, 0]);
+
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs(vec![
+        Basic(
+            1_000_000,
+            bech32_address_0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(chain.clone()),
+        ),
+        Basic(1_000_000, bech32_address_0, None, None, None, None, None, Some(chain)),
+    ]);
+
+    let outputs = build_outputs(vec![Basic(
+        2_000_000,
+        bech32_address_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect(),
+        )
+        .with_outputs(outputs)
+        .finish(&protocol_parameters)?,
+    );
+
+    Ok(PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    })
+}
@@ -0,0 +1,149 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use iota_sdk::{
+    client::{
+        api::{simulate_semantic, PreparedTransactionData},
+        secret::types::InputSigningData,
+        Result,
+    },
+    types::block::{
+        input::{Input, UtxoInput},
+        output::{InputsCommitment, NftId, Output},
+        payload::transaction::{RegularTransactionEssence, TransactionEssence},
+        protocol::protocol_parameters,
+        semantic::ConflictReason,
+    },
+};
+
+use crate::client::{
+    build_inputs, build_outputs,
+    Build::{Basic, Nft},
+    BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1, BECH32_ADDRESS_ED25519_2, BECH32_ADDRESS_NFT_1, NFT_ID_1,
+};
+
+fn prepared_transaction_data(inputs: Vec<InputSigningData>, outputs: Vec<Output>) -> Result<PreparedTransactionData> {
+    let protocol_parameters = protocol_parameters();
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect(),
+        )
+        .with_outputs(outputs)
+        .finish(&protocol_parameters)?,
+    );
+
+    Ok(PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    })
+}
+
+#[test]
+fn simulate_without_secret_manager() -> Result<()> {
+    let nft_id = NftId::from_str(NFT_ID_1)?;
+
+    // Inputs of two addresses and one owned by an NFT, so all kinds of unlocks are needed
+    let inputs = build_inputs(vec![
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+        Nft(
+            1_000_000,
+            nft_id,
+            BECH32_ADDRESS_ED25519_1,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Basic(1_000_000, BECH32_ADDRESS_NFT_1, None, None, None, None, None, None),
+    ]);
+    let outputs = build_outputs(vec![
+        Basic(3_000_000, BECH32_ADDRESS_ED25519_2, None, None, None, None, None, None),
+        Nft(
+            1_000_000,
+            nft_id,
+            BECH32_ADDRESS_ED25519_2,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+
+    let conflict = simulate_semantic(&prepared_transaction_data(inputs, outputs)?, 100)?;
+    assert_eq!(conflict, ConflictReason::None);
+
+    Ok(())
+}
+
+#[test]
+fn simulate_conflicts() -> Result<()> {
+    let inputs = build_inputs(vec![Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let outputs = build_outputs(vec![Basic(
+        2_000_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let conflict = simulate_semantic(&prepared_transaction_data(inputs, outputs)?, 100)?;
+    assert_eq!(conflict, ConflictReason::CreatedConsumedAmountMismatch);
+
+    let inputs = build_inputs(vec![Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        None,
+        Some(200),
+        None,
+        None,
+    )]);
+    let outputs = build_outputs(vec![Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+    let prepared_transaction_data = prepared_transaction_data(inputs, outputs)?;
+    assert_eq!(
+        simulate_semantic(&prepared_transaction_data, 100)?,
+        ConflictReason::TimelockNotExpired
+    );
+    assert_eq!(
+        simulate_semantic(&prepared_transaction_data, 300)?,
+        ConflictReason::None
+    );
+
+    Ok(())
+}
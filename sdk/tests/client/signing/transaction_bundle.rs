@@ -0,0 +1,173 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use crypto::keys::slip10::Chain;
+use iota_sdk::{
+    client::{
+        api::{verify_semantic, PreparedTransactionData, TransactionBundle, TransactionBundleDto},
+        constants::{HD_WALLET_TYPE, SHIMMER_COIN_TYPE, SHIMMER_TESTNET_BECH32_HRP},
+        secret::{SecretManage, SecretManager},
+        Client, Error, Result,
+    },
+    types::block::{
+        input::{Input, UtxoInput},
+        output::InputsCommitment,
+        payload::transaction::{RegularTransactionEssence, TransactionEssence},
+        protocol::protocol_parameters,
+        semantic::ConflictReason,
+    },
+};
+
+use crate::client::{build_inputs, build_outputs, Build::Basic};
+
+async fn prepared_transaction_data(secret_manager: &SecretManager) -> Result<PreparedTransactionData> {
+    let bech32_address = &secret_manager
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0]
+        .to_bech32(SHIMMER_TESTNET_BECH32_HRP);
+    let protocol_parameters = protocol_parameters();
+
+    let inputs = build_inputs(vec![
+        Basic(
+            1_000_000,
+            bech32_address,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Chain::from_u32_hardened(vec![
+                HD_WALLET_TYPE,
+                SHIMMER_COIN_TYPE,
+                0,
+                0,
+                0,
+            ])),
+        ),
+        Basic(
+            2_000_000,
+            bech32_address,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(Chain::from_u32_hardened(vec![
+                HD_WALLET_TYPE,
+                SHIMMER_COIN_TYPE,
+                0,
+                0,
+                0,
+            ])),
+        ),
+    ]);
+    let outputs = build_outputs(vec![Basic(
+        3_000_000,
+        bech32_address,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect(),
+        )
+        .with_outputs(outputs)
+        .finish(&protocol_parameters)?,
+    );
+
+    Ok(PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder: None,
+    })
+}
+
+#[tokio::test]
+async fn transaction_bundle_offline_signing() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+    let prepared_transaction_data = prepared_transaction_data(&secret_manager).await?;
+
+    // Online: export the prepared transaction
+    let bundle = TransactionBundle::new(prepared_transaction_data, protocol_parameters())?;
+    assert!(!bundle.is_signed());
+    assert!(matches!(
+        bundle.signed_transaction_data(),
+        Err(Error::InvalidTransactionBundle(_))
+    ));
+    let bytes = bundle.to_bytes()?;
+
+    // Offline: validate and sign
+    let mut bundle = TransactionBundle::from_bytes(&bytes)?;
+    bundle.sign(&secret_manager, Some(100)).await?;
+    assert!(bundle.is_signed());
+    let json = bundle.to_json()?;
+
+    // Online: import the signed transaction
+    let bundle = TransactionBundle::from_json(&json)?;
+    let signed_transaction_data = bundle.signed_transaction_data()?;
+
+    let conflict = verify_semantic(
+        &signed_transaction_data.inputs_data,
+        &signed_transaction_data.transaction_payload,
+        100,
+    )?;
+
+    if conflict != ConflictReason::None {
+        panic!("{conflict:?}, with {:#?}", signed_transaction_data.transaction_payload);
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn transaction_bundle_validation() -> Result<()> {
+    let secret_manager = SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+    let prepared_transaction_data = prepared_transaction_data(&secret_manager).await?;
+    let bundle = TransactionBundle::new(prepared_transaction_data.clone(), protocol_parameters())?;
+    let dto = TransactionBundleDto::try_from(&bundle)?;
+
+    // Modified content
+    let mut modified = dto.clone();
+    modified.prepared_transaction_data.inputs_data.swap(0, 1);
+    assert!(matches!(
+        TransactionBundle::try_from_dto(&modified),
+        Err(Error::InvalidTransactionBundle(message)) if message == "checksum mismatch"
+    ));
+
+    // Unsupported version
+    let mut modified = dto.clone();
+    modified.version += 1;
+    assert!(matches!(
+        TransactionBundle::try_from_dto(&modified),
+        Err(Error::InvalidTransactionBundle(_))
+    ));
+
+    // Truncated binary bundle
+    let bytes = bundle.to_bytes()?;
+    assert!(matches!(
+        TransactionBundle::from_bytes(&bytes[..bytes.len() - 1]),
+        Err(Error::InvalidTransactionBundle(_))
+    ));
+
+    // Inputs data that doesn't match the inputs commitment
+    let mut tampered = prepared_transaction_data;
+    tampered.inputs_data[0].output = tampered.inputs_data[1].output.clone();
+    assert!(matches!(
+        TransactionBundle::new(tampered, protocol_parameters()),
+        Err(Error::InvalidTransactionBundle(_))
+    ));
+
+    Ok(())
+}
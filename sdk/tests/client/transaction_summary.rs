@@ -0,0 +1,427 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use iota_sdk::{
+    client::{
+        api::{input_selection::Burn, PreparedTransactionData, RemainderData, TransactionSummary},
+        secret::types::InputSigningData,
+        Result,
+    },
+    types::block::{
+        address::{Address, AliasAddress},
+        input::{Input, UtxoInput},
+        output::{
+            AliasId, BasicOutputBuilder, ChainId, FoundryOutputBuilder, InputsCommitment, NativeToken, NftId, Output,
+            SimpleTokenScheme, TokenId,
+        },
+        payload::transaction::{RegularTransactionEssence, TransactionEssence},
+        protocol::protocol_parameters,
+    },
+};
+use primitive_types::U256;
+
+use crate::client::{
+    build_inputs, build_outputs,
+    Build::{Alias, Basic, Foundry, Nft},
+    ALIAS_ID_1, BECH32_ADDRESS_ED25519_0, BECH32_ADDRESS_ED25519_1, BECH32_ADDRESS_ED25519_2, NFT_ID_0, NFT_ID_1,
+    TOKEN_ID_1,
+};
+
+fn prepared_transaction_data(
+    inputs: Vec<InputSigningData>,
+    outputs: Vec<Output>,
+    remainder: Option<RemainderData>,
+) -> Result<PreparedTransactionData> {
+    let protocol_parameters = protocol_parameters();
+    let essence = TransactionEssence::Regular(
+        RegularTransactionEssence::builder(
+            protocol_parameters.network_id(),
+            InputsCommitment::new(inputs.iter().map(|i| &i.output)),
+        )
+        .with_inputs(
+            inputs
+                .iter()
+                .map(|i| Input::Utxo(UtxoInput::from(*i.output_metadata.output_id())))
+                .collect(),
+        )
+        .with_outputs(outputs)
+        .finish(&protocol_parameters)?,
+    );
+
+    Ok(PreparedTransactionData {
+        essence,
+        inputs_data: inputs,
+        remainder,
+    })
+}
+
+#[test]
+fn summary_of_transfer() -> Result<()> {
+    let address_0 = Address::try_from_bech32(BECH32_ADDRESS_ED25519_0)?;
+    let address_1 = Address::try_from_bech32(BECH32_ADDRESS_ED25519_1)?;
+    let address_2 = Address::try_from_bech32(BECH32_ADDRESS_ED25519_2)?;
+    let token_id = TokenId::from_str(TOKEN_ID_1)?;
+    let nft_id = NftId::from_str(NFT_ID_1)?;
+
+    let inputs = build_inputs(vec![
+        Basic(
+            2_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 100)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Nft(
+            1_000_000,
+            nft_id,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        // Claiming this output returns the storage deposit to address 2
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            Some((BECH32_ADDRESS_ED25519_2, 500_000)),
+            None,
+            None,
+            None,
+        ),
+    ]);
+    let outputs = build_outputs(vec![
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_1,
+            Some(vec![(TOKEN_ID_1, 60)]),
+            None,
+            Some((BECH32_ADDRESS_ED25519_0, 300_000)),
+            None,
+            None,
+            None,
+        ),
+        Nft(
+            1_000_000,
+            nft_id,
+            BECH32_ADDRESS_ED25519_1,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Basic(500_000, BECH32_ADDRESS_ED25519_2, None, None, None, None, None, None),
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 30)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+    let remainder = RemainderData {
+        output: outputs[3].clone(),
+        chain: None,
+        address: address_0,
+    };
+
+    let summary = TransactionSummary::new(
+        &prepared_transaction_data(inputs, outputs, Some(remainder))?,
+        None,
+        Some(0),
+    )?;
+
+    let balance_change = |address| {
+        summary
+            .balance_changes
+            .iter()
+            .find(|balance_change| balance_change.address == address)
+            .unwrap()
+    };
+
+    let sender = balance_change(address_0);
+    assert_eq!(sender.base_coin_spent, 3_500_000);
+    assert_eq!(sender.base_coin_received, 1_000_000);
+    assert_eq!(sender.base_coin(), -2_500_000);
+    assert_eq!(sender.native_tokens_spent.get(&token_id), Some(&U256::from(100)));
+    assert_eq!(sender.native_tokens_received.get(&token_id), Some(&U256::from(30)));
+
+    let receiver = balance_change(address_1);
+    assert_eq!(receiver.base_coin(), 1_700_000);
+    assert_eq!(receiver.native_tokens_received.get(&token_id), Some(&U256::from(60)));
+
+    assert_eq!(balance_change(address_2).base_coin(), 500_000);
+
+    assert_eq!(summary.storage_deposits_locked, vec![(address_0, 300_000)]);
+    assert_eq!(summary.storage_deposits_returned, vec![(address_2, 500_000)]);
+
+    assert_eq!(summary.chain_transitions.len(), 1);
+    assert_eq!(summary.chain_transitions[0].chain_id, ChainId::from(nft_id));
+    assert_eq!(summary.chain_transitions[0].from, Some(address_0));
+    assert_eq!(summary.chain_transitions[0].to, Some(address_1));
+
+    assert_eq!(summary.remainder_address, Some(address_0));
+    assert_eq!(
+        summary
+            .outputs
+            .iter()
+            .filter(|output| output.is_remainder)
+            .map(|output| output.index)
+            .collect::<Vec<_>>(),
+        vec![3]
+    );
+    assert_eq!(summary.outputs[0].unlock_conditions.len(), 2);
+
+    assert!(summary.native_tokens_minted.is_empty());
+    assert!(summary.native_tokens_melted.is_empty());
+    assert_eq!(summary.native_tokens_burned.get(&token_id), Some(&U256::from(10)));
+
+    Ok(())
+}
+
+#[test]
+fn summary_of_chain_transitions() -> Result<()> {
+    let address_0 = Address::try_from_bech32(BECH32_ADDRESS_ED25519_0)?;
+    let alias_id = AliasId::from_str(ALIAS_ID_1)?;
+    let alias_address = Address::Alias(AliasAddress::new(alias_id));
+    let nft_id = NftId::from_str(NFT_ID_1)?;
+
+    let inputs = build_inputs(vec![
+        Alias(
+            2_000_000,
+            alias_id,
+            1,
+            BECH32_ADDRESS_ED25519_0,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Foundry(
+            1_000_000,
+            alias_id,
+            1,
+            SimpleTokenScheme::new(U256::from(100), U256::from(0), U256::from(1000))?,
+            None,
+        ),
+        Nft(
+            1_000_000,
+            nft_id,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+    let mut outputs = build_outputs(vec![
+        Alias(
+            2_000_000,
+            alias_id,
+            2,
+            BECH32_ADDRESS_ED25519_0,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Foundry(
+            1_000_000,
+            alias_id,
+            1,
+            SimpleTokenScheme::new(U256::from(150), U256::from(20), U256::from(1000))?,
+            None,
+        ),
+        Nft(
+            1_000_000,
+            NftId::from_str(NFT_ID_0)?,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+    let foundry_output = outputs[1].as_foundry();
+    let (foundry_id, token_id) = (foundry_output.id(), foundry_output.token_id());
+    // The minted tokens that aren't melted stay in the foundry
+    outputs[1] = FoundryOutputBuilder::from(foundry_output)
+        .add_native_token(NativeToken::new(token_id, U256::from(30))?)
+        .finish_output(protocol_parameters().token_supply())?;
+
+    let summary = TransactionSummary::new(&prepared_transaction_data(inputs, outputs, None)?, None, Some(0))?;
+
+    assert_eq!(summary.chain_transitions.len(), 4);
+    // The alias is transitioned, the foundry is owned by the alias
+    assert_eq!(summary.chain_transitions[0].chain_id, ChainId::from(alias_id));
+    assert_eq!(summary.chain_transitions[0].to, Some(address_0));
+    assert_eq!(summary.chain_transitions[1].chain_id, ChainId::from(foundry_id));
+    assert_eq!(summary.chain_transitions[1].to, Some(alias_address));
+    // The NFT is burned and a new one is minted
+    assert_eq!(summary.chain_transitions[2].chain_id, ChainId::from(nft_id));
+    assert!(summary.chain_transitions[2].is_destroyed());
+    assert!(summary.chain_transitions[3].chain_id.is_null());
+    assert!(summary.chain_transitions[3].is_created());
+    assert!(summary.remainder_address.is_none());
+
+    assert_eq!(summary.native_tokens_minted.get(&token_id), Some(&U256::from(50)));
+    assert_eq!(summary.native_tokens_melted.get(&token_id), Some(&U256::from(20)));
+    assert!(summary.native_tokens_burned.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn summary_of_burn() -> Result<()> {
+    let alias_id = AliasId::from_str(ALIAS_ID_1)?;
+    let token_id_1 = TokenId::from_str(TOKEN_ID_1)?;
+
+    let inputs = build_inputs(vec![
+        Alias(
+            2_000_000,
+            alias_id,
+            1,
+            BECH32_ADDRESS_ED25519_0,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Foundry(
+            1_000_000,
+            alias_id,
+            1,
+            SimpleTokenScheme::new(U256::from(100), U256::from(0), U256::from(1000))?,
+            None,
+        ),
+        Basic(
+            1_000_000,
+            BECH32_ADDRESS_ED25519_0,
+            Some(vec![(TOKEN_ID_1, 10)]),
+            None,
+            None,
+            None,
+            None,
+            None,
+        ),
+    ]);
+    let outputs = build_outputs(vec![
+        Alias(
+            2_000_000,
+            alias_id,
+            2,
+            BECH32_ADDRESS_ED25519_0,
+            BECH32_ADDRESS_ED25519_0,
+            None,
+            None,
+            None,
+            None,
+        ),
+        Foundry(
+            1_000_000,
+            alias_id,
+            1,
+            SimpleTokenScheme::new(U256::from(150), U256::from(0), U256::from(1000))?,
+            None,
+        ),
+        Basic(1_000_000, BECH32_ADDRESS_ED25519_0, None, None, None, None, None, None),
+    ]);
+    let minted_token_id = outputs[1].as_foundry().token_id();
+    // Only a part of the minted tokens is sent to an output
+    let outputs = outputs
+        .into_iter()
+        .enumerate()
+        .map(|(index, output)| {
+            if index == 2 {
+                BasicOutputBuilder::from(output.as_basic())
+                    .add_native_token(NativeToken::new(minted_token_id, U256::from(20))?)
+                    .finish_output(protocol_parameters().token_supply())
+            } else {
+                Ok(output)
+            }
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let burn = Burn::new().add_native_token(token_id_1, 10);
+
+    let summary = TransactionSummary::new(&prepared_transaction_data(inputs, outputs, None)?, Some(&burn), Some(0))?;
+
+    assert_eq!(
+        summary.native_tokens_minted.get(&minted_token_id),
+        Some(&U256::from(50))
+    );
+    // The minted tokens that aren't in an output and the explicitly burned tokens
+    assert_eq!(summary.native_tokens_burned.len(), 2);
+    assert_eq!(
+        summary.native_tokens_burned.get(&minted_token_id),
+        Some(&U256::from(30))
+    );
+    assert_eq!(summary.native_tokens_burned.get(&token_id_1), Some(&U256::from(10)));
+
+    // Addresses are displayed bech32 encoded
+    let display = summary.display("rms").to_string();
+    assert!(display.contains(BECH32_ADDRESS_ED25519_0));
+    assert!(!display.contains("Ed25519Address"));
+    assert!(display.contains(&format!(
+        "Native tokens burned:\n  {minted_token_id}: 30\n  {token_id_1}: 10\n"
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn summary_of_expired_storage_deposit_return() -> Result<()> {
+    let address_1 = Address::try_from_bech32(BECH32_ADDRESS_ED25519_1)?;
+
+    // The output is expired, so it's unlocked by the return address and the storage deposit isn't returned
+    let inputs = build_inputs(vec![Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_0,
+        None,
+        None,
+        Some((BECH32_ADDRESS_ED25519_2, 500_000)),
+        None,
+        Some((BECH32_ADDRESS_ED25519_1, 100)),
+        None,
+    )]);
+    let outputs = build_outputs(vec![Basic(
+        1_000_000,
+        BECH32_ADDRESS_ED25519_1,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    )]);
+
+    let summary = TransactionSummary::new(&prepared_transaction_data(inputs, outputs, None)?, None, Some(200))?;
+
+    assert_eq!(summary.balance_changes.len(), 1);
+    assert_eq!(summary.balance_changes[0].address, address_1);
+    assert_eq!(summary.balance_changes[0].base_coin_spent, 1_000_000);
+    assert_eq!(summary.balance_changes[0].base_coin_received, 1_000_000);
+    assert!(summary.storage_deposits_returned.is_empty());
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    address::Bech32Address,
+    output::feature::{Irc27Attribute, Irc27Metadata, MetadataFeature},
+    Error,
+};
+
+const ROYALTY_ADDRESS: &str = "rms1qpllaj0pyveqfkwxmnngz2c488hfdtmfrj3wfkgxtk4gtyrax0jaxzt70zy";
+
+#[test]
+fn round_trip() {
+    let metadata = Irc27Metadata::new("image/png", "https://mywebsite.com/my-nft-files-1.png", "My NFT #0001")
+        .with_collection_name("My Collection of Art")
+        .add_royalty(Bech32Address::try_from_str(ROYALTY_ADDRESS).unwrap(), 0.025)
+        .with_issuer_name("My Artist Name")
+        .with_description("A little information about my NFT collection")
+        .add_attribute(Irc27Attribute::new("Background", "Purple"))
+        .add_attribute(Irc27Attribute::new("Level", 1).with_display_type("number"));
+
+    let feature = MetadataFeature::try_from(&metadata).unwrap();
+    let parsed = Irc27Metadata::try_from(&feature).unwrap();
+
+    assert_eq!(parsed, metadata);
+    assert_eq!(parsed.name(), "My NFT #0001");
+    assert_eq!(parsed.uri(), "https://mywebsite.com/my-nft-files-1.png");
+    assert_eq!(parsed.royalties().get(ROYALTY_ADDRESS), Some(&0.025));
+    assert_eq!(parsed.attributes()[1].display_type(), Some("number"));
+}
+
+#[test]
+fn parse_standard_json() {
+    let json = r#"{
+        "standard": "IRC27",
+        "version": "v1.0",
+        "type": "video/mp4",
+        "uri": "ipfs://wrongcVm9fx47YXNTkhpMEYSxCD3Bqh7PJYr7eo5Ywrong",
+        "name": "Shimmer OG NFT",
+        "attributes": [{ "trait_type": "Rarity", "value": "rare" }]
+    }"#;
+
+    let metadata = Irc27Metadata::from_bytes(json.as_bytes()).unwrap();
+
+    assert_eq!(metadata.media_type(), "video/mp4");
+    assert_eq!(metadata.collection_name(), None);
+    assert_eq!(metadata.attributes()[0].trait_type(), "Rarity");
+    // Fields without a value are omitted
+    assert!(!String::from_utf8(metadata.to_bytes().unwrap())
+        .unwrap()
+        .contains("royalties"));
+}
+
+#[test]
+fn invalid() {
+    assert!(matches!(
+        Irc27Metadata::new("png", "https://mywebsite.com/nft.png", "NFT").validate(),
+        Err(Error::InvalidIrc27Metadata(_))
+    ));
+    assert!(matches!(
+        Irc27Metadata::new("image/png", "", "NFT").validate(),
+        Err(Error::InvalidIrc27Metadata(_))
+    ));
+    assert!(matches!(
+        Irc27Metadata::new("image/png", "https://mywebsite.com/nft.png", "NFT")
+            .add_royalty(Bech32Address::try_from_str(ROYALTY_ADDRESS).unwrap(), 1.5)
+            .validate(),
+        Err(Error::InvalidIrc27Metadata(_))
+    ));
+    assert!(matches!(
+        Irc27Metadata::from_bytes(br#"{"standard":"IRC30","name":"NFT","symbol":"NFT","decimals":0}"#),
+        Err(Error::InvalidIrc27Metadata(_))
+    ));
+}
+
+#[test]
+fn exceeds_metadata_feature_length() {
+    let metadata = Irc27Metadata::new("image/png", "https://mywebsite.com/nft.png", "NFT")
+        .with_description("a".repeat(*MetadataFeature::LENGTH_RANGE.end() as usize));
+
+    assert!(matches!(
+        MetadataFeature::try_from(&metadata),
+        Err(Error::InvalidMetadataFeatureLength(_))
+    ));
+}
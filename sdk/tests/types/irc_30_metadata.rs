@@ -0,0 +1,43 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+use iota_sdk::types::block::{
+    output::feature::{Irc30Metadata, MetadataFeature},
+    Error,
+};
+
+#[test]
+fn round_trip() {
+    let metadata = Irc30Metadata::new("FooCoin", "FOO", 3)
+        .with_description("FooCoin is the utility and governance token of FooLand")
+        .with_url("https://foocoin.io")
+        .with_logo_url("https://ipfs.io/ipfs/QmR36VFfo1hH2RAwVs4zVJ5btkopGip5cW7ydY4jUQBrkR");
+
+    let feature = MetadataFeature::try_from(&metadata).unwrap();
+    let parsed = Irc30Metadata::try_from(&feature).unwrap();
+
+    assert_eq!(parsed, metadata);
+    assert_eq!(parsed.symbol(), "FOO");
+    assert_eq!(parsed.decimals(), 3);
+    assert_eq!(parsed.logo(), None);
+    assert_eq!(
+        String::from_utf8(feature.data().to_vec()).unwrap(),
+        r#"{"standard":"IRC30","name":"FooCoin","description":"FooCoin is the utility and governance token of FooLand","symbol":"FOO","decimals":3,"url":"https://foocoin.io","logoUrl":"https://ipfs.io/ipfs/QmR36VFfo1hH2RAwVs4zVJ5btkopGip5cW7ydY4jUQBrkR"}"#
+    );
+}
+
+#[test]
+fn invalid() {
+    assert!(matches!(
+        Irc30Metadata::new("FooCoin", "", 3).validate(),
+        Err(Error::InvalidIrc30Metadata(_))
+    ));
+    assert!(matches!(
+        Irc30Metadata::from_bytes(br#"{"standard":"IRC27","name":"FooCoin","symbol":"FOO","decimals":3}"#),
+        Err(Error::InvalidIrc30Metadata(_))
+    ));
+    assert!(matches!(
+        Irc30Metadata::from_bytes(b"not json"),
+        Err(Error::InvalidIrc30Metadata(_))
+    ));
+}
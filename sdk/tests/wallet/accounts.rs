@@ -2,6 +2,18 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::wallet::Result;
+#[cfg(feature = "test-utils")]
+use {
+    crate::wallet::common::fund_account,
+    iota_sdk::{
+        client::mock_node::MockNode,
+        types::block::output::{
+            unlock_condition::{GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition},
+            AliasId, AliasOutputBuilder,
+        },
+        wallet::{AddressWithAmount, Error},
+    },
+};
 #[cfg(feature = "stronghold")]
 use {
     iota_sdk::client::{
@@ -212,3 +224,132 @@ async fn account_creation_stronghold() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn watch_only_account() -> Result<()> {
+    let storage_path = "test-storage/watch_only_account";
+    let signer_storage_path = "test-storage/watch_only_account_signer";
+    setup(storage_path)?;
+    setup(signer_storage_path)?;
+
+    let node = MockNode::builder().finish()?;
+    let signer_wallet = make_wallet(signer_storage_path, None, Some(node.url().as_str())).await?;
+    let signer_account = signer_wallet.create_account().finish().await?;
+    let signer_address = signer_account.addresses().await?[0].address().clone();
+
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    // Watch-only addresses can't be combined with addresses
+    assert!(matches!(
+        wallet
+            .create_account()
+            .with_addresses(signer_account.addresses().await?)
+            .with_watch_only_addresses(vec![signer_address.clone()])
+            .finish()
+            .await,
+        Err(Error::WatchOnlyAccount(_))
+    ));
+    let watch_only_account = wallet
+        .create_account()
+        .with_watch_only_addresses(vec![signer_address])
+        .finish()
+        .await?;
+    assert!(watch_only_account.read().await.watch_only());
+    // Accounts from the secret manager can still be created after a watch-only account
+    let account = wallet.create_account().finish().await?;
+
+    let balance = fund_account(&node, &watch_only_account, 10_000_000).await?;
+    assert_eq!(balance.base_coin().available(), 10_000_000);
+
+    assert!(matches!(
+        watch_only_account.generate_addresses(1, None).await,
+        Err(Error::WatchOnlyAccount(_))
+    ));
+
+    let recipient = account.addresses().await?[0].address().to_string();
+    let prepared_transaction = watch_only_account
+        .prepare_send_amount(vec![AddressWithAmount::new(recipient.clone(), 1_000_000)], None)
+        .await?;
+    assert!(matches!(
+        watch_only_account.sign_transaction_essence(&prepared_transaction).await,
+        Err(Error::WatchOnlyAccount(_))
+    ));
+
+    // The transaction is signed by the offline signer with the seed of the watched address
+    let prepared_transaction = watch_only_account
+        .prepare_send_amount(vec![AddressWithAmount::new(recipient, 1_000_000)], None)
+        .await?;
+    let bundle = watch_only_account
+        .export_prepared_transaction(prepared_transaction)
+        .await?;
+    assert!(matches!(
+        watch_only_account.sign_transaction_bundle(bundle.clone()).await,
+        Err(Error::WatchOnlyAccount(_))
+    ));
+    let bundle = signer_account.sign_transaction_bundle(bundle).await?;
+    watch_only_account.import_signed_transaction(bundle).await?;
+    node.issue_milestone();
+
+    let balance = watch_only_account.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 9_000_000);
+    let balance = account.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 1_000_000);
+
+    tear_down(signer_storage_path)?;
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn watch_only_account_alias_governance_transition() -> Result<()> {
+    let storage_path = "test-storage/watch_only_account_alias_governance_transition";
+    let signer_storage_path = "test-storage/watch_only_account_alias_governance_transition_signer";
+    setup(storage_path)?;
+    setup(signer_storage_path)?;
+
+    let node = MockNode::builder().finish()?;
+    let rent_structure = *node.protocol_parameters().rent_structure();
+    let token_supply = node.protocol_parameters().token_supply();
+    let signer_wallet = make_wallet(signer_storage_path, None, Some(node.url().as_str())).await?;
+    let signer_account = signer_wallet.create_account().finish().await?;
+    let signer_address = signer_account.addresses().await?[0].address().clone();
+
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let watch_only_account = wallet
+        .create_account()
+        .with_watch_only_addresses(vec![signer_address.clone()])
+        .finish()
+        .await?;
+    let state_controller = *wallet.create_account().finish().await?.addresses().await?[0]
+        .address()
+        .inner();
+
+    // The watched address is only the governor of the alias
+    let alias_id = AliasId::new([1; AliasId::LENGTH]);
+    let alias_output = AliasOutputBuilder::new_with_minimum_storage_deposit(rent_structure, alias_id)
+        .with_state_index(1)
+        .add_unlock_condition(StateControllerAddressUnlockCondition::new(state_controller))
+        .add_unlock_condition(GovernorAddressUnlockCondition::new(*signer_address.inner()))
+        .finish_output(token_supply)?;
+    node.add_outputs(vec![alias_output.clone()])?;
+    let balance = fund_account(&node, &watch_only_account, 10_000_000).await?;
+    assert_eq!(balance.aliases(), &[alias_id]);
+
+    // A governance transition has to be signed with the chain of the governor
+    let output = AliasOutputBuilder::from(alias_output.as_alias())
+        .replace_unlock_condition(GovernorAddressUnlockCondition::new(state_controller))
+        .finish_output(token_supply)?;
+    let prepared_transaction = watch_only_account.prepare_transaction(vec![output], None).await?;
+    let bundle = watch_only_account
+        .export_prepared_transaction(prepared_transaction)
+        .await?;
+    let bundle = signer_account.sign_transaction_bundle(bundle).await?;
+    watch_only_account.import_signed_transaction(bundle).await?;
+    node.issue_milestone();
+
+    let balance = watch_only_account.sync(None).await?;
+    assert!(balance.aliases().is_empty());
+
+    tear_down(signer_storage_path)?;
+    tear_down(storage_path)
+}
@@ -9,6 +9,23 @@ use iota_sdk::{
     },
     wallet::Result,
 };
+#[cfg(feature = "test-utils")]
+use {
+    crate::wallet::common::fund_account,
+    iota_sdk::{
+        client::mock_node::MockNode,
+        utils::unix_timestamp_now,
+        wallet::{account::VestingSchedule, Error},
+    },
+};
+#[cfg(all(feature = "events", feature = "test-utils"))]
+use {
+    futures::{FutureExt, StreamExt},
+    iota_sdk::{
+        types::block::output::unlock_condition::{StorageDepositReturnUnlockCondition, TimelockUnlockCondition},
+        wallet::events::types::{EventFilter, WalletEvent, WalletEventType},
+    },
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -142,3 +159,162 @@ async fn balance_voting_power() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(all(feature = "events", feature = "test-utils"))]
+#[tokio::test]
+async fn balance_and_output_events() -> Result<()> {
+    let storage_path = "test-storage/balance_and_output_events";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish()?;
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    let address = *account_0.addresses().await?[0].address().as_ref();
+    let return_address = *account_1.addresses().await?[0].address().as_ref();
+    let token_supply = node.protocol_parameters().token_supply();
+    let now = account_0.client().get_time_checked().await?;
+
+    let mut events = wallet
+        .event_stream(EventFilter::new().with_account_indexes([0]).with_event_types([
+            WalletEventType::BalanceChanged,
+            WalletEventType::OutputClaimable,
+            WalletEventType::OutputExpiring,
+        ]))
+        .await;
+
+    let output_ids = node.add_outputs(vec![
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .add_unlock_condition(TimelockUnlockCondition::new(now - 100)?)
+            .finish_output(token_supply)?,
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(address))
+            .add_unlock_condition(StorageDepositReturnUnlockCondition::new(
+                return_address,
+                500_000,
+                token_supply,
+            )?)
+            .add_unlock_condition(ExpirationUnlockCondition::new(return_address, now + 3600)?)
+            .finish_output(token_supply)?,
+    ])?;
+    let balance = account_0.sync(None).await?;
+
+    // The first sync reports the change from the balance before it
+    let mut received = Vec::new();
+    while let Some(Some(event)) = events.next().now_or_never() {
+        received.push(event.event);
+    }
+    assert_eq!(received.len(), 3);
+    assert!(received.iter().any(|event| matches!(
+        event,
+        WalletEvent::BalanceChanged(event) if event.previous_balance.base_coin.total == "0"
+            && event.balance.base_coin.total == balance.base_coin().total().to_string()
+    )));
+    assert!(received
+        .iter()
+        .any(|event| matches!(event, WalletEvent::OutputClaimable(event) if event.output.output_id == output_ids[0])));
+    assert!(received.iter().any(|event| matches!(
+        event,
+        WalletEvent::OutputExpiring(event) if event.output.output_id == output_ids[1] && event.expiration_time == now + 3600
+    )));
+
+    // Events for outputs are only emitted once
+    fund_account(&node, &account_0, 1_000_000).await?;
+    // Calculating the balance doesn't emit events
+    account_0.balance().await?;
+
+    let Some(Some(event)) = events.next().now_or_never() else {
+        panic!("expected a balance changed event");
+    };
+    let WalletEvent::BalanceChanged(event) = event.event else {
+        panic!("expected a balance changed event");
+    };
+    assert_eq!(
+        event.balance.base_coin.total.parse::<u64>().unwrap(),
+        event.previous_balance.base_coin.total.parse::<u64>().unwrap() + 1_000_000
+    );
+    assert!(events.next().now_or_never().is_none());
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn vesting_schedule() -> Result<()> {
+    let storage_path = "test-storage/vesting_schedule";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish()?;
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    let recipient = account_1.addresses().await?[0].address().to_string();
+    fund_account(&node, &account_0, 100_000_000).await?;
+
+    // The first output is already unlocked and has a larger amount
+    let cliff = unix_timestamp_now().as_secs() as u32 - 60;
+    let period = 60 * 60;
+    let transactions = account_0
+        .send_vesting_schedule(
+            VestingSchedule::new(
+                recipient.clone(),
+                cliff,
+                period,
+                [2_000_000, 1_000_000, 1_000_000, 500_000],
+            ),
+            None,
+        )
+        .await?;
+    assert_eq!(transactions.len(), 1);
+    node.issue_milestone();
+    account_1.sync(None).await?;
+
+    let vesting_balance = account_1.vesting_balance(None).await?;
+    assert_eq!(vesting_balance.vested, 2_000_000);
+    assert_eq!(vesting_balance.locked, 2_500_000);
+    assert_eq!(
+        vesting_balance
+            .unlocks
+            .iter()
+            .map(|unlock| (unlock.timestamp, unlock.amount))
+            .collect::<Vec<_>>(),
+        [
+            (cliff + period, 1_000_000),
+            (cliff + 2 * period, 1_000_000),
+            (cliff + 3 * period, 500_000)
+        ]
+    );
+    let vesting_balance = account_1.vesting_balance(Some(cliff + 2 * period)).await?;
+    assert_eq!(vesting_balance.vested, 4_000_000);
+    assert_eq!(vesting_balance.locked, 500_000);
+
+    assert!(matches!(
+        account_0
+            .send_vesting_schedule(VestingSchedule::new(recipient.clone(), cliff, period, []), None)
+            .await,
+        Err(Error::InvalidVestingSchedule(_))
+    ));
+    // The outputs need to cover their storage deposit
+    assert!(matches!(
+        account_0
+            .send_vesting_schedule(VestingSchedule::new(recipient.clone(), cliff, period, [1; 4]), None)
+            .await,
+        Err(Error::Block(_))
+    ));
+    // The total amount needs to be available
+    assert!(matches!(
+        account_0
+            .send_vesting_schedule(
+                VestingSchedule::new(recipient, cliff, period, [50_000_000, 50_000_000]),
+                None
+            )
+            .await,
+        Err(Error::InsufficientFunds {
+            required: 100_000_000,
+            ..
+        })
+    ));
+
+    tear_down(storage_path)
+}
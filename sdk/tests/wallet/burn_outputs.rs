@@ -29,7 +29,7 @@ async fn mint_and_burn_nft() -> Result<()> {
         immutable_metadata: Some(b"some immutable nft metadata".to_vec()),
     }];
 
-    let transaction = account.mint_nfts(nft_options, None).await.unwrap();
+    let transaction = account.mint_nfts(nft_options, None).await.unwrap().remove(0);
     account
         .retry_transaction_until_included(&transaction.transaction_id, None, None)
         .await?;
@@ -11,6 +11,19 @@ use iota_sdk::{
         AddressNativeTokens, AddressWithAmount, NativeTokenOptions, Result, U256,
     },
 };
+#[cfg(feature = "test-utils")]
+use {
+    crate::wallet::common::fund_account,
+    iota_sdk::{
+        client::mock_node::MockNode,
+        utils::unix_timestamp_now,
+        wallet::{
+            account::{types::InclusionState, RefundablePaymentOptions, RefundablePaymentStatus, SyncOptions},
+            Error,
+        },
+    },
+    std::{collections::HashMap, time::Duration},
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -36,7 +49,8 @@ async fn claim_2_basic_micro_outputs() -> Result<()> {
                 ..Default::default()
             },
         )
-        .await?;
+        .await?
+        .remove(0);
 
     accounts[1]
         .retry_transaction_until_included(&tx.transaction_id, None, None)
@@ -90,7 +104,8 @@ async fn claim_1_of_2_basic_outputs() -> Result<()> {
                 ..Default::default()
             },
         )
-        .await?;
+        .await?
+        .remove(0);
 
     accounts[1]
         .retry_transaction_until_included(&tx.transaction_id, None, None)
@@ -536,3 +551,118 @@ async fn claim_2_nft_outputs_no_outputs_in_claim_account() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn send_with_refund_deadline() -> Result<()> {
+    let storage_path = "test-storage/send_with_refund_deadline";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish()?;
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    let recipient = account_1.addresses().await?[0].address().to_string();
+    fund_account(&node, &account_0, 100_000_000).await?;
+
+    let now = unix_timestamp_now().as_secs() as u32;
+    assert!(matches!(
+        account_0
+            .send_with_refund_deadline(
+                vec![RefundablePaymentOptions::new(recipient.clone(), 1_000_000, now - 1)],
+                None
+            )
+            .await,
+        Err(Error::InvalidRefundDeadline { .. })
+    ));
+    // The payment can only return to an address of the account
+    assert!(matches!(
+        account_0
+            .send_with_refund_deadline(
+                vec![RefundablePaymentOptions::new(recipient.clone(), 1_000_000, now + 60)
+                    .with_return_address(recipient.clone())],
+                None
+            )
+            .await,
+        Err(Error::AddressNotFoundInAccount(_))
+    ));
+
+    // Every issued milestone increases the milestone timestamp by at least a second
+    let deadline = now + 5;
+    let transaction = account_0
+        .send_with_refund_deadline(
+            vec![
+                RefundablePaymentOptions::new(recipient.clone(), 1_000_000, deadline),
+                RefundablePaymentOptions::new(recipient, 2_000_000, deadline),
+            ],
+            None,
+        )
+        .await?;
+    let refundable_payments = account_0.refundable_payments().await;
+    assert_eq!(refundable_payments.len(), 2);
+    assert!(refundable_payments.iter().all(|refundable_payment| {
+        refundable_payment.deadline == deadline
+            && refundable_payment.status == RefundablePaymentStatus::Pending
+            && refundable_payment.output_id.transaction_id() == &transaction.transaction_id
+    }));
+    let claimed_payment = refundable_payments
+        .iter()
+        .find(|refundable_payment| refundable_payment.amount == 1_000_000)
+        .unwrap()
+        .output_id;
+    let expired_payment = refundable_payments
+        .iter()
+        .find(|refundable_payment| refundable_payment.amount == 2_000_000)
+        .unwrap()
+        .output_id;
+    node.issue_milestone();
+
+    // The recipient only claims one of the payments before the deadline
+    account_1.sync(None).await?;
+    account_1.claim_outputs(vec![claimed_payment]).await?;
+    node.issue_milestone();
+
+    // Syncing after the deadline reclaims the expired payment
+    tokio::time::sleep(Duration::from_secs(6)).await;
+    account_0.sync(None).await?;
+    let statuses = account_0
+        .refundable_payments()
+        .await
+        .into_iter()
+        .map(|refundable_payment| (refundable_payment.output_id, refundable_payment.status))
+        .collect::<HashMap<_, _>>();
+    assert_eq!(statuses[&claimed_payment], RefundablePaymentStatus::Claimed);
+    let refund_transaction_id = match statuses[&expired_payment] {
+        RefundablePaymentStatus::Refunded { transaction_id } => transaction_id,
+        ref status => panic!("expected the payment to be refunded, got {status:?}"),
+    };
+    assert_eq!(account_0.pending_transactions().await?.len(), 1);
+
+    node.issue_milestone();
+    let balance = account_0
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await?;
+    assert_eq!(balance.base_coin().total(), 99_000_000);
+    assert_eq!(
+        account_0
+            .get_transaction(&refund_transaction_id)
+            .await?
+            .unwrap()
+            .inclusion_state,
+        InclusionState::Confirmed
+    );
+    // The refund stays with the confirmed transaction
+    assert!(account_0.refundable_payments().await.iter().any(|refundable_payment| {
+        refundable_payment.output_id == expired_payment
+            && refundable_payment.status
+                == RefundablePaymentStatus::Refunded {
+                    transaction_id: refund_transaction_id,
+                }
+            && refundable_payment.reclaim_error.is_none()
+    }));
+
+    tear_down(storage_path)
+}
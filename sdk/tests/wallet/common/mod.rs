@@ -5,6 +5,12 @@
 
 mod constants;
 
+#[cfg(feature = "test-utils")]
+use iota_sdk::{
+    client::mock_node::MockNode,
+    types::block::output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+    wallet::account::{types::AccountBalance, SyncOptions},
+};
 use iota_sdk::{
     client::{
         constants::SHIMMER_COIN_TYPE,
@@ -72,6 +78,24 @@ pub(crate) async fn create_accounts_with_funds(wallet: &Wallet, amount: usize) -
     Ok(new_accounts)
 }
 
+/// Add a basic output with `amount` for the first address of the account to the mock node and sync the account
+/// afterwards. Returns the balance of the account.
+#[cfg(feature = "test-utils")]
+#[allow(dead_code)]
+pub(crate) async fn fund_account(node: &MockNode, account: &Account, amount: u64) -> Result<AccountBalance> {
+    node.add_outputs(vec![BasicOutputBuilder::new_with_amount(amount)
+        .add_unlock_condition(AddressUnlockCondition::new(
+            *account.addresses().await?[0].address().inner(),
+        ))
+        .finish_output(node.protocol_parameters().token_supply())?])?;
+    account
+        .sync(Some(SyncOptions {
+            force_syncing: true,
+            ..Default::default()
+        }))
+        .await
+}
+
 #[allow(dead_code)]
 pub(crate) fn setup(path: &str) -> Result<()> {
     std::fs::remove_dir_all(path).ok();
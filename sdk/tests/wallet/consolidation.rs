@@ -2,6 +2,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use iota_sdk::wallet::{AddressWithAmount, Result};
+#[cfg(feature = "test-utils")]
+use iota_sdk::{
+    client::{
+        constants::SHIMMER_COIN_TYPE,
+        mock_node::MockNode,
+        secret::{GenerateAddressOptions, SecretManage, SecretManager},
+        Client,
+    },
+    types::block::output::{
+        unlock_condition::{
+            AddressUnlockCondition, GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition,
+            TimelockUnlockCondition,
+        },
+        AliasId, AliasOutputBuilder, BasicOutputBuilder, NativeToken, NftId, NftOutputBuilder, TokenId,
+    },
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -23,7 +39,8 @@ async fn consolidation() -> Result<()> {
             vec![AddressWithAmount::new(account_1.addresses().await?[0].address().to_string(), amount); 10],
             None,
         )
-        .await?;
+        .await?
+        .remove(0);
 
     account_0
         .retry_transaction_until_included(&tx.transaction_id, None, None)
@@ -46,3 +63,105 @@ async fn consolidation() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn sweep_from_secret_manager() -> Result<()> {
+    let storage_path = "test-storage/sweep_from_secret_manager";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish()?;
+    let token_supply = node.protocol_parameters().token_supply();
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let foreign_secret_manager = SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+    let foreign_addresses = foreign_secret_manager
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..2, None)
+        .await?;
+    let foreign_internal_address = foreign_secret_manager
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, Some(GenerateAddressOptions::internal()))
+        .await?[0];
+
+    let timelocked_output = BasicOutputBuilder::new_with_amount(1_000_000)
+        .add_unlock_condition(AddressUnlockCondition::new(foreign_addresses[0]))
+        .add_unlock_condition(TimelockUnlockCondition::new(u32::MAX)?)
+        .finish_output(token_supply)?;
+    node.add_outputs(vec![
+        BasicOutputBuilder::new_with_amount(5_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(foreign_addresses[0]))
+            .finish_output(token_supply)?,
+        BasicOutputBuilder::new_with_amount(2_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(foreign_addresses[1]))
+            .finish_output(token_supply)?,
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(foreign_internal_address))
+            .finish_output(token_supply)?,
+        NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+            .add_unlock_condition(AddressUnlockCondition::new(foreign_addresses[1]))
+            .finish_output(token_supply)?,
+        AliasOutputBuilder::new_with_amount(1_000_000, AliasId::null())
+            .add_unlock_condition(StateControllerAddressUnlockCondition::new(foreign_addresses[0]))
+            .add_unlock_condition(GovernorAddressUnlockCondition::new(foreign_addresses[0]))
+            .finish_output(token_supply)?,
+        timelocked_output,
+    ])?;
+
+    let sweep_result = account
+        .sweep_from_secret_manager(&foreign_secret_manager, 0..1, 5)
+        .await?;
+    assert_eq!(sweep_result.transactions.len(), 1);
+    assert_eq!(sweep_result.transactions[0].inputs.len(), 5);
+    assert_eq!(sweep_result.unswept_outputs.len(), 1);
+    node.issue_milestone();
+
+    let balance = account.sync(None).await?;
+    assert_eq!(balance.base_coin().total(), 10_000_000);
+    assert_eq!(balance.nfts().len(), 1);
+    assert_eq!(balance.aliases().len(), 1);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn sweep_native_tokens() -> Result<()> {
+    let storage_path = "test-storage/sweep_native_tokens";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish()?;
+    let token_supply = node.protocol_parameters().token_supply();
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let account = wallet.create_account().finish().await?;
+
+    let foreign_secret_manager = SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?;
+    let foreign_address = foreign_secret_manager
+        .generate_addresses(SHIMMER_COIN_TYPE, 0, 0..1, None)
+        .await?[0];
+
+    // More distinct native tokens than fit into the output with the merged basic outputs
+    let native_token = |i: u8| NativeToken::new(TokenId::new([i; TokenId::LENGTH]), 10u32.into());
+    node.add_outputs(vec![
+        BasicOutputBuilder::new_with_amount(10_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(foreign_address))
+            .with_native_tokens((0..64).map(native_token).collect::<std::result::Result<Vec<_>, _>>()?)
+            .finish_output(token_supply)?,
+        BasicOutputBuilder::new_with_amount(1_000_000)
+            .add_unlock_condition(AddressUnlockCondition::new(foreign_address))
+            .add_native_token(native_token(64)?)
+            .finish_output(token_supply)?,
+    ])?;
+
+    let sweep_result = account
+        .sweep_from_secret_manager(&foreign_secret_manager, 0..1, 1)
+        .await?;
+    assert_eq!(sweep_result.transactions.len(), 2);
+    assert!(sweep_result.unswept_outputs.is_empty());
+    node.issue_milestone();
+
+    let balance = account.sync(None).await?;
+    assert_eq!(balance.base_coin().total(), 11_000_000);
+    assert_eq!(balance.native_tokens().len(), 65);
+
+    tear_down(storage_path)
+}
@@ -1,8 +1,6 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use std::sync::{atomic::Ordering, Arc};
-
 #[cfg(feature = "events")]
 use iota_sdk::wallet::events::types::WalletEvent;
 use iota_sdk::{
@@ -229,11 +227,11 @@ async fn message_interface_emit_event() -> Result<()> {
 
     let wallet_handle = create_message_handler(Some(options)).await.unwrap();
 
-    let event_counter = Arc::new(std::sync::atomic::AtomicUsize::new(0));
-    let event_counter_clone = Arc::clone(&event_counter);
+    // The listener is invoked by a task, so the events are received from a channel
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
     wallet_handle
-        .listen(vec![], move |_name| {
-            event_counter_clone.fetch_add(1, Ordering::SeqCst);
+        .listen(vec![], move |event| {
+            sender.send(event.event.clone()).unwrap();
         })
         .await;
 
@@ -245,7 +243,7 @@ async fn message_interface_emit_event() -> Result<()> {
             .await;
         match response {
             Response::Ok(()) => {
-                assert_eq!(count, event_counter.load(Ordering::SeqCst))
+                assert_eq!(receiver.recv().await, Some(WalletEvent::ConsolidationRequired))
             }
             response_type => panic!("Unexpected response type: {response_type:?}"),
         }
@@ -261,8 +259,8 @@ async fn message_interface_emit_event() -> Result<()> {
         })
         .await;
 
-    // Event should not have fired, so we are still on 10 calls
-    assert_eq!(10, event_counter.load(Ordering::SeqCst));
+    // Event should not have fired, the removed listener dropped the sender instead
+    assert_eq!(receiver.recv().await, None);
 
     tear_down(storage_path)
 }
@@ -16,6 +16,8 @@ mod error;
 mod message_interface;
 mod native_tokens;
 mod output_preparation;
+#[cfg(feature = "storage")]
+mod storage;
 mod syncing;
 mod transactions;
 #[allow(clippy::module_inception)]
@@ -1,7 +1,10 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{account::SyncOptions, NativeTokenOptions, Result, U256};
+use iota_sdk::{
+    types::block::output::feature::Irc30Metadata,
+    wallet::{account::SyncOptions, NativeTokenOptions, Result, U256},
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -124,3 +127,31 @@ async fn native_token_foundry_metadata() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[test]
+fn native_token_options_irc_30_metadata() -> Result<()> {
+    let metadata = Irc30Metadata::new("FooCoin", "FOO", 3).with_description("The utility token of FooLand");
+    let options = NativeTokenOptions {
+        alias_id: None,
+        circulating_supply: U256::from(50),
+        maximum_supply: U256::from(100),
+        foundry_metadata: None,
+    }
+    .with_irc_30_metadata(&metadata)?;
+    assert_eq!(options.foundry_metadata, Some(metadata.to_bytes()?));
+
+    // Invalid metadata and metadata that doesn't fit into a metadata feature are rejected
+    assert!(
+        options
+            .clone()
+            .with_irc_30_metadata(&Irc30Metadata::new("FooCoin", "", 3))
+            .is_err()
+    );
+    assert!(
+        options
+            .with_irc_30_metadata(&metadata.with_description("a".repeat(8192)))
+            .is_err()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,294 @@
+// Copyright 2023 IOTA Stiftung
+// SPDX-License-Identifier: Apache-2.0
+
+#[cfg(feature = "sqlite")]
+use std::collections::HashMap;
+
+#[cfg(feature = "sqlite")]
+use iota_sdk::{
+    client::secret::{mnemonic::MnemonicSecretManager, SecretManager},
+    wallet::{
+        storage::adapter::{sqlite::SqliteStorageAdapter, StorageAdapter},
+        ClientOptions, Error, Result, Wallet,
+    },
+};
+#[cfg(all(feature = "events", feature = "sqlite", feature = "test-utils"))]
+use iota_sdk::{
+    client::{constants::SHIMMER_COIN_TYPE, Client},
+    wallet::events::types::WalletEvent,
+};
+#[cfg(all(feature = "sqlite", feature = "test-utils"))]
+use {
+    crate::wallet::common::{fund_account, make_wallet},
+    iota_sdk::{
+        client::mock_node::MockNode,
+        wallet::{
+            account::{types::InclusionState, PaymentQueueOptions, PaymentStatus},
+            AddressWithAmount,
+        },
+    },
+    std::time::Duration,
+};
+
+#[cfg(feature = "sqlite")]
+use crate::wallet::common::{setup, tear_down, DEFAULT_MNEMONIC};
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn sqlite_storage_adapter() -> Result<()> {
+    let storage_path = "test-storage/sqlite_storage_adapter";
+    setup(storage_path)?;
+
+    let db_path = format!("{storage_path}/wallet.sqlite");
+    let mut storage = SqliteStorageAdapter::new(&db_path)?;
+    assert_eq!(storage.id(), "Sqlite");
+    assert_eq!(storage.get("key").await?, None);
+
+    storage.set("key", "value".to_string()).await?;
+    assert_eq!(storage.get("key").await?, Some("value".to_string()));
+    storage.set("key", "updated".to_string()).await?;
+    assert_eq!(storage.get("key").await?, Some("updated".to_string()));
+
+    storage
+        .batch_set(HashMap::from([
+            ("first".to_string(), "1".to_string()),
+            ("second".to_string(), "2".to_string()),
+        ]))
+        .await?;
+    storage.remove("key").await?;
+    assert_eq!(storage.get("key").await?, None);
+    drop(storage);
+
+    // Records are persisted
+    let storage = SqliteStorageAdapter::new(&db_path)?;
+    assert_eq!(storage.get("first").await?, Some("1".to_string()));
+    assert_eq!(storage.get("second").await?, Some("2".to_string()));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn sqlite_storage_path_without_database() -> Result<()> {
+    let storage_path = "test-storage/sqlite_storage_path_without_database";
+    setup(storage_path)?;
+    std::fs::create_dir_all(storage_path)?;
+
+    // The directory exists, but the database doesn't, so the wallet can't be created without the coin type
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            DEFAULT_MNEMONIC,
+        )?))
+        .with_client_options(ClientOptions::new())
+        .with_storage_path(storage_path)
+        .finish()
+        .await;
+    assert!(matches!(wallet, Err(Error::MissingParameter("coin_type"))));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn migrate_storage_v1() -> Result<()> {
+    let storage_path = "test-storage/migrate_storage_v1";
+    setup(storage_path)?;
+
+    let db_path = format!("{storage_path}/wallet.sqlite");
+    let records: HashMap<String, serde_json::Value> =
+        serde_json::from_str(&std::fs::read_to_string("./tests/wallet/fixtures/storage_v1.json")?)?;
+    let mut storage = SqliteStorageAdapter::new(&db_path)?;
+    storage
+        .batch_set(
+            records
+                .into_iter()
+                .map(|(key, record)| (key, record.to_string()))
+                .collect(),
+        )
+        .await?;
+    drop(storage);
+
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            DEFAULT_MNEMONIC,
+        )?))
+        .with_storage_path(&db_path)
+        .finish()
+        .await?;
+
+    let account = wallet.get_account("Alice").await?;
+    assert_eq!(account.unspent_outputs(None).await?.len(), 1);
+    assert_eq!(account.pending_transactions().await?.len(), 1);
+    assert_eq!(account.incoming_transactions().await?.len(), 1);
+    drop(account);
+    drop(wallet);
+
+    // The migrated storage is opened without migrating it again
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::Mnemonic(MnemonicSecretManager::try_from_mnemonic(
+            DEFAULT_MNEMONIC,
+        )?))
+        .with_storage_path(&db_path)
+        .finish()
+        .await?;
+    assert_eq!(wallet.get_account("Alice").await?.transactions().await?.len(), 1);
+
+    tear_down(storage_path)
+}
+
+#[cfg(all(feature = "sqlite", feature = "test-utils"))]
+#[tokio::test]
+async fn historical_transactions_after_reload() -> Result<()> {
+    let storage_path = "test-storage/historical_transactions_after_reload";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish()?;
+    let wallet = make_wallet(storage_path, Some(DEFAULT_MNEMONIC), Some(node.url().as_str())).await?;
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    let address = account.addresses().await?[0].address().clone();
+    fund_account(&node, &account, 10_000_000).await?;
+
+    let transaction = account
+        .send_amount(vec![AddressWithAmount::new(address.to_string(), 1_000_000)], None)
+        .await?
+        .remove(0);
+    node.issue_milestone();
+    account.sync(None).await?;
+    drop(account);
+    drop(wallet);
+
+    // The confirmed transaction isn't loaded in memory anymore, but still returned from the storage
+    let wallet = make_wallet(storage_path, Some(DEFAULT_MNEMONIC), Some(node.url().as_str())).await?;
+    let account = wallet.get_account("Alice").await?;
+    assert!(account.read().await.transactions().is_empty());
+    assert_eq!(
+        account
+            .get_transaction(&transaction.transaction_id)
+            .await?
+            .unwrap()
+            .inclusion_state,
+        InclusionState::Confirmed
+    );
+    assert_eq!(account.transactions().await?.len(), 1);
+    #[cfg(feature = "message_interface")]
+    assert!(
+        iota_sdk::wallet::message_interface::dtos::AccountDetailsDto::from_account(&account)
+            .await?
+            .transactions
+            .contains_key(&transaction.transaction_id)
+    );
+
+    tear_down(storage_path)
+}
+
+#[cfg(all(feature = "sqlite", feature = "test-utils"))]
+#[tokio::test]
+async fn payment_queue_after_reload() -> Result<()> {
+    let storage_path = "test-storage/payment_queue_after_reload";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish()?;
+    let wallet = make_wallet(storage_path, Some(DEFAULT_MNEMONIC), Some(node.url().as_str())).await?;
+    let account = wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    let address = account.addresses().await?[0].address().clone();
+    fund_account(&node, &account, 10_000_000).await?;
+
+    account
+        .set_payment_queue_options(PaymentQueueOptions {
+            max_delay: Duration::from_secs(3600),
+            ..Default::default()
+        })
+        .await;
+    let sent_payment_id = account
+        .queue_payment(AddressWithAmount::new(address.to_string(), 1_000_000))
+        .await?;
+    let transaction_id = account.flush_payment_queue().await?[0].transaction_id;
+    let queued_payment_id = account
+        .queue_payment(AddressWithAmount::new(address.to_string(), 1_000_000))
+        .await?;
+    drop(account);
+    drop(wallet);
+
+    // The statuses and the queued payment are restored
+    let wallet = make_wallet(storage_path, Some(DEFAULT_MNEMONIC), Some(node.url().as_str())).await?;
+    let account = wallet.get_account("Alice").await?;
+    assert_eq!(
+        account.payment_status(sent_payment_id).await,
+        Some(PaymentStatus::Sent { transaction_id })
+    );
+    assert_eq!(
+        account.payment_status(queued_payment_id).await,
+        Some(PaymentStatus::Queued)
+    );
+    node.issue_milestone();
+    account.sync(None).await?;
+    assert_eq!(account.flush_payment_queue().await?.len(), 1);
+    assert!(matches!(
+        account.payment_status(queued_payment_id).await,
+        Some(PaymentStatus::Sent { .. })
+    ));
+    assert!(
+        account
+            .queue_payment(AddressWithAmount::new(address.to_string(), 1_000_000))
+            .await?
+            > queued_payment_id
+    );
+
+    tear_down(storage_path)
+}
+
+#[cfg(all(feature = "events", feature = "sqlite", feature = "test-utils"))]
+#[tokio::test]
+async fn event_log_after_reload() -> Result<()> {
+    let storage_path = "test-storage/event_log_after_reload";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish()?;
+    let mnemonic = Client::generate_mnemonic()?;
+    let wallet = Wallet::builder()
+        .with_secret_manager(SecretManager::try_from_mnemonic(&mnemonic)?)
+        .with_client_options(ClientOptions::new().with_node(node.url().as_str())?)
+        .with_coin_type(SHIMMER_COIN_TYPE)
+        .with_storage_path(storage_path)
+        .with_event_log(true)
+        .finish()
+        .await?;
+    let account = wallet.create_account().finish().await?;
+    fund_account(&node, &account, 10_000_000).await?;
+
+    let events = wallet.events_since(0).await?;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].sequence_number, 1);
+    assert_eq!(events[0].event.account_index, 0);
+    assert!(matches!(events[0].event.event, WalletEvent::NewOutput(_)));
+
+    drop(account);
+    drop(wallet);
+
+    // The event log stays enabled and continues with the stored sequence number
+    let wallet = make_wallet(storage_path, Some(&mnemonic), Some(node.url().as_str())).await?;
+    let account = wallet.get_account(0).await?;
+    fund_account(&node, &account, 1_000_000).await?;
+
+    let events = wallet.events_since(1).await?;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].sequence_number, 2);
+    assert_eq!(wallet.events_since(0).await?.len(), 2);
+    assert!(wallet.events_since(2).await?.is_empty());
+    assert!(wallet.events_since(u64::MAX).await?.is_empty());
+
+    // Processed events are removed, also after a reload
+    wallet.prune_events(1).await?;
+    let events = wallet.events_since(0).await?;
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].sequence_number, 2);
+    drop(account);
+    drop(wallet);
+
+    let wallet = make_wallet(storage_path, Some(&mnemonic), Some(node.url().as_str())).await?;
+    assert_eq!(wallet.events_since(0).await?.len(), 1);
+    wallet.prune_events(u64::MAX).await?;
+    assert!(wallet.events_since(0).await?.is_empty());
+
+    tear_down(storage_path)
+}
@@ -1,6 +1,8 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+#[cfg(feature = "mqtt")]
+use iota_sdk::wallet::AddressWithAmount;
 use iota_sdk::{
     types::block::output::{
         unlock_condition::{
@@ -147,3 +149,43 @@ async fn sync_only_most_basic_outputs() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[cfg(feature = "mqtt")]
+#[tokio::test]
+async fn mqtt_syncing() -> Result<()> {
+    let storage_path = "test-storage/mqtt_syncing";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    wallet.start_mqtt_syncing(None).await?;
+
+    let amount = 1_000_000;
+    account_0
+        .send_amount(
+            vec![AddressWithAmount::new(
+                account_1.addresses().await?[0].address().to_string(),
+                amount,
+            )],
+            None,
+        )
+        .await?;
+
+    // The output is received without syncing the account
+    let mut received = false;
+    for _ in 0..30 {
+        if account_1.balance().await?.base_coin().available() == amount {
+            received = true;
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    }
+    wallet.stop_mqtt_syncing().await?;
+    assert!(received);
+
+    tear_down(storage_path)
+}
@@ -1,7 +1,34 @@
 // Copyright 2023 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
-use iota_sdk::wallet::{account::TransactionOptions, AddressAndNftId, AddressWithAmount, NftOptions, Result};
+use iota_sdk::{
+    client::api::TransactionBundle,
+    types::block::{
+        output::{unlock_condition::AddressUnlockCondition, BasicOutputBuilder},
+        semantic::ConflictReason,
+    },
+    wallet::{account::TransactionOptions, AddressAndNftId, AddressWithAmount, NftOptions, Result},
+};
+#[cfg(feature = "test-utils")]
+use {
+    crate::wallet::common::fund_account,
+    iota_sdk::{
+        client::{mock_node::MockNode, secret::SecretManager, Client, Error as ClientError},
+        types::block::{
+            output::{
+                feature::{Irc27Attribute, Irc27Metadata, MetadataFeature},
+                unlock_condition::{GovernorAddressUnlockCondition, StateControllerAddressUnlockCondition},
+                AliasId, AliasOutput, AliasOutputBuilder, NftId, NftOutputBuilder, Output, OUTPUT_COUNT_MAX,
+            },
+            payload::transaction::TransactionEssence,
+        },
+        wallet::{
+            account::{types::InclusionState, Account, PaymentQueueOptions, PaymentStatus},
+            Error,
+        },
+    },
+    std::time::Duration,
+};
 
 use crate::wallet::common::{create_accounts_with_funds, make_wallet, setup, tear_down};
 
@@ -25,7 +52,8 @@ async fn send_amount() -> Result<()> {
             )],
             None,
         )
-        .await?;
+        .await?
+        .remove(0);
 
     account_0
         .retry_transaction_until_included(&tx.transaction_id, None, None)
@@ -61,7 +89,8 @@ async fn send_amount_127_outputs() -> Result<()> {
             ],
             None,
         )
-        .await?;
+        .await?
+        .remove(0);
 
     account_0
         .retry_transaction_until_included(&tx.transaction_id, None, None)
@@ -91,7 +120,8 @@ async fn send_amount_custom_input() -> Result<()> {
             vec![AddressWithAmount::new(account_1.addresses().await?[0].address().to_string(), amount); 10],
             None,
         )
-        .await?;
+        .await?
+        .remove(0);
 
     account_0
         .retry_transaction_until_included(&tx.transaction_id, None, None)
@@ -113,7 +143,8 @@ async fn send_amount_custom_input() -> Result<()> {
                 ..Default::default()
             }),
         )
-        .await?;
+        .await?
+        .remove(0);
 
     assert_eq!(tx.inputs.len(), 1);
     assert_eq!(tx.inputs.first().unwrap().metadata.output_id()?, custom_input.output_id);
@@ -139,7 +170,7 @@ async fn send_nft() -> Result<()> {
         immutable_metadata: Some(b"some immutable nft metadata".to_vec()),
     }];
 
-    let transaction = accounts[0].mint_nfts(nft_options, None).await.unwrap();
+    let transaction = accounts[0].mint_nfts(nft_options, None).await.unwrap().remove(0);
     accounts[0]
         .retry_transaction_until_included(&transaction.transaction_id, None, None)
         .await?;
@@ -166,3 +197,663 @@ async fn send_nft() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+#[ignore]
+#[tokio::test]
+async fn send_amount_with_transaction_bundle() -> Result<()> {
+    let storage_path = "test-storage/send_amount_with_transaction_bundle";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let amount = 1_000_000;
+    let prepared_transaction = account_0
+        .prepare_send_amount(
+            vec![AddressWithAmount::new(
+                account_1.addresses().await?[0].address().to_string(),
+                amount,
+            )],
+            None,
+        )
+        .await?;
+
+    // The bundle is signed by an offline signer with the same secret manager
+    let bytes = account_0
+        .export_prepared_transaction(prepared_transaction)
+        .await?
+        .to_bytes()?;
+    let mut bundle = TransactionBundle::from_bytes(&bytes)?;
+    bundle.sign(&*wallet.get_secret_manager().read().await, None).await?;
+
+    let tx = account_0
+        .import_signed_transaction(TransactionBundle::from_json(&bundle.to_json()?)?)
+        .await?;
+
+    account_0
+        .retry_transaction_until_included(&tx.transaction_id, None, None)
+        .await?;
+
+    let balance = account_1.sync(None).await.unwrap();
+    assert_eq!(balance.base_coin().available(), amount);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn import_signed_transaction_with_invalid_signature() -> Result<()> {
+    let storage_path = "test-storage/import_signed_transaction_with_invalid_signature";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish()?;
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let account = wallet.create_account().finish().await?;
+    let address = account.addresses().await?[0].address().clone();
+    fund_account(&node, &account, 10_000_000).await?;
+
+    let prepared_transaction = account
+        .prepare_send_amount(vec![AddressWithAmount::new(address.to_string(), 1_000_000)], None)
+        .await?;
+    let mut bundle = account.export_prepared_transaction(prepared_transaction).await?;
+    // Signed with another seed, so the signatures don't match the addresses of the inputs
+    bundle
+        .sign(&SecretManager::try_from_mnemonic(&Client::generate_mnemonic()?)?, None)
+        .await?;
+
+    let error = account.import_signed_transaction(bundle).await.unwrap_err();
+    assert!(matches!(
+        error,
+        Error::Client(error) if matches!(*error, ClientError::TransactionSemantic(ConflictReason::InvalidSignature))
+    ));
+    assert!(account.transactions().await?.is_empty());
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn send_amount_with_mock_node() -> Result<()> {
+    let storage_path = "test-storage/send_amount_with_mock_node";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish()?;
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+
+    let balance = fund_account(&node, &account_0, 10_000_000).await?;
+    assert_eq!(balance.base_coin().available(), 10_000_000);
+
+    let transaction = account_0
+        .send_amount(
+            vec![AddressWithAmount::new(
+                account_1.addresses().await?[0].address().to_string(),
+                1_000_000,
+            )],
+            None,
+        )
+        .await?
+        .remove(0);
+    node.issue_milestone();
+
+    let balance = account_0.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 9_000_000);
+    assert_eq!(
+        account_0
+            .get_transaction(&transaction.transaction_id)
+            .await?
+            .unwrap()
+            .inclusion_state,
+        InclusionState::Confirmed
+    );
+
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 1_000_000);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn payment_queue() -> Result<()> {
+    let storage_path = "test-storage/payment_queue";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish()?;
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    let recipient = account_1.addresses().await?[0].address().to_string();
+    fund_account(&node, &account_0, 10_000_000).await?;
+
+    account_0
+        .set_payment_queue_options(PaymentQueueOptions {
+            max_payments: 3,
+            max_delay: Duration::from_secs(3600),
+            transaction_options: None,
+        })
+        .await;
+
+    // Payments are sent together once the max payments are queued
+    let mut payment_ids = Vec::new();
+    for _ in 0..3 {
+        payment_ids.push(
+            account_0
+                .queue_payment(AddressWithAmount::new(recipient.clone(), 1_000_000))
+                .await?,
+        );
+        if payment_ids.len() < 3 {
+            assert_eq!(
+                account_0.payment_status(payment_ids[0]).await,
+                Some(PaymentStatus::Queued)
+            );
+        }
+    }
+    let Some(PaymentStatus::Sent { transaction_id }) = account_0.payment_status(payment_ids[0]).await else {
+        panic!("payment wasn't sent");
+    };
+    for payment_id in &payment_ids {
+        assert_eq!(
+            account_0.payment_status(*payment_id).await,
+            Some(PaymentStatus::Sent { transaction_id })
+        );
+    }
+    let transaction = account_0.get_transaction(&transaction_id).await?.unwrap();
+    let TransactionEssence::Regular(essence) = transaction.payload.essence();
+    // Three payments and the remainder
+    assert_eq!(essence.outputs().len(), 4);
+    node.issue_milestone();
+    account_0.sync(None).await?;
+
+    // Payments are sent after the max delay
+    account_0
+        .set_payment_queue_options(PaymentQueueOptions {
+            max_delay: Duration::from_millis(100),
+            ..Default::default()
+        })
+        .await;
+    let payment_id = account_0
+        .queue_payment(AddressWithAmount::new(recipient.clone(), 1_000_000))
+        .await?;
+    assert_eq!(account_0.payment_status(payment_id).await, Some(PaymentStatus::Queued));
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert!(matches!(
+        account_0.payment_status(payment_id).await,
+        Some(PaymentStatus::Sent { .. })
+    ));
+    node.issue_milestone();
+    account_0.sync(None).await?;
+
+    // Payments that can't be sent are marked as failed
+    account_0
+        .set_payment_queue_options(PaymentQueueOptions {
+            max_delay: Duration::from_secs(3600),
+            ..Default::default()
+        })
+        .await;
+    let payment_id = account_0
+        .queue_payment(AddressWithAmount::new(recipient, 100_000_000))
+        .await?;
+    assert!(account_0.flush_payment_queue().await?.is_empty());
+    assert!(matches!(
+        account_0.payment_status(payment_id).await,
+        Some(PaymentStatus::Failed { .. })
+    ));
+
+    node.issue_milestone();
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 4_000_000);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn send_amount_multiple_transactions() -> Result<()> {
+    let storage_path = "test-storage/send_amount_multiple_transactions";
+    setup(storage_path)?;
+
+    let node = MockNode::builder()
+        .with_milestone_interval(Duration::from_millis(200))
+        .finish()?;
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    fund_account(&node, &account_0, 1_000_000_000).await?;
+
+    let recipient = account_1.addresses().await?[0].address().to_string();
+    // More outputs than fit into a single transaction, the second one has to wait for the remainder of the first one
+    let transactions = account_0
+        .send_amount(vec![AddressWithAmount::new(recipient, 1_000_000); 200], None)
+        .await?;
+    assert_eq!(transactions.len(), 2);
+    let output_counts = transactions
+        .iter()
+        .map(|transaction| {
+            let TransactionEssence::Regular(essence) = transaction.payload.essence();
+            essence.outputs().len()
+        })
+        .collect::<Vec<_>>();
+    // The payments and the remainder
+    assert_eq!(output_counts, [128, 74]);
+
+    account_0
+        .retry_transaction_until_included(&transactions[1].transaction_id, None, None)
+        .await?;
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 200_000_000);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn send_amount_partially_sent() -> Result<()> {
+    let storage_path = "test-storage/send_amount_partially_sent";
+    setup(storage_path)?;
+
+    let node = MockNode::builder()
+        .with_milestone_interval(Duration::from_millis(200))
+        .finish()?;
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    fund_account(&node, &account_0, 150_000_000).await?;
+
+    let recipient = account_1.addresses().await?[0].address().to_string();
+    // The funds are only enough for the first transaction
+    let Err(Error::PartiallySent {
+        transactions,
+        failed_chunk,
+        error,
+    }) = account_0
+        .send_amount(vec![AddressWithAmount::new(recipient, 1_000_000); 200], None)
+        .await
+    else {
+        panic!("expected a partially sent error");
+    };
+    assert_eq!(failed_chunk, 1);
+    assert_eq!(transactions.len(), 1);
+    assert!(matches!(
+        *error,
+        Error::Client(error) if matches!(*error, ClientError::InputSelection(_))
+    ));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn send_amount_consolidating_inputs() -> Result<()> {
+    let storage_path = "test-storage/send_amount_consolidating_inputs";
+    setup(storage_path)?;
+
+    let node = MockNode::builder()
+        .with_milestone_interval(Duration::from_millis(200))
+        .finish()?;
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    let address = *account_0.addresses().await?[0].address().inner();
+    let token_supply = node.protocol_parameters().token_supply();
+    node.add_outputs(
+        (0..130)
+            .map(|_| {
+                BasicOutputBuilder::new_with_amount(1_000_000)
+                    .add_unlock_condition(AddressUnlockCondition::new(address))
+                    .finish_output(token_supply)
+            })
+            .collect::<std::result::Result<Vec<_>, _>>()?,
+    )?;
+    account_0.sync(None).await?;
+
+    // The amount needs more inputs than fit into a single transaction, so they're consolidated first
+    let recipient = account_1.addresses().await?[0].address().to_string();
+    let transactions = account_0
+        .send_amount(vec![AddressWithAmount::new(recipient, 129_000_000)], None)
+        .await?;
+    assert_eq!(transactions.len(), 1);
+    assert!(transactions[0].inputs.len() < 128);
+
+    account_0
+        .retry_transaction_until_included(&transactions[0].transaction_id, None, None)
+        .await?;
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 129_000_000);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn send_chunked_oversized_metadata() -> Result<()> {
+    let storage_path = "test-storage/send_chunked_oversized_metadata";
+    setup(storage_path)?;
+
+    let node = MockNode::builder()
+        .with_milestone_interval(Duration::from_millis(200))
+        .finish()?;
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    let token_supply = node.protocol_parameters().token_supply();
+    fund_account(&node, &account_0, 100_000_000).await?;
+
+    // Few outputs, but together with their metadata they exceed the max block size
+    let recipient = *account_1.addresses().await?[0].address().as_ref();
+    let outputs = (0..10)
+        .map(|_| {
+            BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(recipient))
+                .add_feature(MetadataFeature::new(vec![0; 4000])?)
+                .finish_output(token_supply)
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let transactions = account_0.send_chunked(outputs, None).await?;
+    let output_counts = transactions
+        .iter()
+        .map(|transaction| {
+            let TransactionEssence::Regular(essence) = transaction.payload.essence();
+            essence.outputs().len()
+        })
+        .collect::<Vec<_>>();
+    // The payments and the remainder
+    assert_eq!(output_counts, [6, 6]);
+
+    account_0
+        .retry_transaction_until_included(&transactions[1].transaction_id, None, None)
+        .await?;
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 10_000_000);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn send_chunked_after_oversized_chunk() -> Result<()> {
+    let storage_path = "test-storage/send_chunked_after_oversized_chunk";
+    setup(storage_path)?;
+
+    let node = MockNode::builder()
+        .with_milestone_interval(Duration::from_millis(200))
+        .finish()?;
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    let token_supply = node.protocol_parameters().token_supply();
+    fund_account(&node, &account_0, 200_000_000).await?;
+
+    // Only the first outputs exceed the max block size together, the following ones are small
+    let recipient = *account_1.addresses().await?[0].address().as_ref();
+    let outputs = (0..110)
+        .map(|index| {
+            let builder = BasicOutputBuilder::new_with_amount(1_000_000)
+                .add_unlock_condition(AddressUnlockCondition::new(recipient));
+            if index < 10 {
+                builder
+                    .add_feature(MetadataFeature::new(vec![0; 4000])?)
+                    .finish_output(token_supply)
+            } else {
+                builder.finish_output(token_supply)
+            }
+        })
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let transactions = account_0.send_chunked(outputs, None).await?;
+    // The small outputs aren't split into chunks as small as the ones of the large outputs
+    assert!(transactions.len() <= 4);
+    let TransactionEssence::Regular(essence) = transactions.last().unwrap().payload.essence();
+    assert!(essence.outputs().len() > 10);
+
+    account_0
+        .retry_transaction_until_included(&transactions.last().unwrap().transaction_id, None, None)
+        .await?;
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.base_coin().available(), 110_000_000);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn update_and_transfer_alias() -> Result<()> {
+    let storage_path = "test-storage/update_and_transfer_alias";
+    setup(storage_path)?;
+
+    let node = MockNode::builder().finish()?;
+    let rent_structure = *node.protocol_parameters().rent_structure();
+    let token_supply = node.protocol_parameters().token_supply();
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let account_0 = wallet.create_account().finish().await?;
+    let account_1 = wallet.create_account().finish().await?;
+    let address_0 = *account_0.addresses().await?[0].address().inner();
+    let bech32_address_1 = account_1.addresses().await?[0].address().to_string();
+
+    // An alias that already created a foundry
+    let alias_id = AliasId::new([1; AliasId::LENGTH]);
+    let alias_output = AliasOutputBuilder::new_with_minimum_storage_deposit(rent_structure, alias_id)
+        .with_state_index(1)
+        .with_foundry_counter(1)
+        .add_unlock_condition(StateControllerAddressUnlockCondition::new(address_0))
+        .add_unlock_condition(GovernorAddressUnlockCondition::new(address_0))
+        .finish_output(token_supply)?;
+    let initial_amount = alias_output.amount();
+    node.add_outputs(vec![alias_output])?;
+    fund_account(&node, &account_0, 10_000_000).await?;
+
+    // The state metadata requires a higher storage deposit
+    let state_metadata = vec![1; 500];
+    account_0
+        .update_alias_state(alias_id, state_metadata.clone(), None)
+        .await?;
+    node.issue_milestone();
+    account_0.sync(None).await?;
+    let alias_output = unspent_alias_output(&account_0, alias_id).await?;
+    assert_eq!(alias_output.state_index(), 2);
+    assert_eq!(alias_output.state_metadata(), state_metadata.as_slice());
+    assert_eq!(alias_output.foundry_counter(), 1);
+    assert!(alias_output.amount() > initial_amount);
+
+    account_0
+        .set_alias_state_controller(alias_id, &bech32_address_1, None)
+        .await?;
+    node.issue_milestone();
+    // The account is still the governor
+    account_0.sync(None).await?;
+    let alias_output = unspent_alias_output(&account_0, alias_id).await?;
+    assert_eq!(
+        alias_output.state_controller_address().to_bech32("rms"),
+        bech32_address_1
+    );
+    assert_eq!(alias_output.state_index(), 2);
+
+    account_0.transfer_alias(alias_id, &bech32_address_1, None).await?;
+    node.issue_milestone();
+    let balance = account_0.sync(None).await?;
+    assert!(balance.aliases().is_empty());
+    let balance = account_1.sync(None).await?;
+    assert_eq!(balance.aliases(), &[alias_id]);
+
+    // The new controller can update the state
+    account_1.update_alias_state(alias_id, Vec::new(), None).await?;
+    node.issue_milestone();
+    account_1.sync(None).await?;
+    let alias_output = unspent_alias_output(&account_1, alias_id).await?;
+    assert_eq!(alias_output.governor_address().to_bech32("rms"), bech32_address_1);
+    assert_eq!(alias_output.state_index(), 3);
+    assert_eq!(alias_output.foundry_counter(), 1);
+
+    assert!(matches!(
+        account_0.transfer_alias(alias_id, &bech32_address_1, None).await,
+        Err(Error::AliasNotFoundInUnspentOutputs)
+    ));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "test-utils")]
+async fn unspent_alias_output(account: &Account, alias_id: AliasId) -> Result<AliasOutput> {
+    Ok(account
+        .unspent_outputs(None)
+        .await?
+        .into_iter()
+        .find_map(|output_data| match output_data.output {
+            Output::Alias(alias_output) if alias_output.alias_id() == &alias_id => Some(alias_output),
+            _ => None,
+        })
+        .expect("alias output not found"))
+}
+
+#[cfg(feature = "test-utils")]
+#[tokio::test]
+async fn nft_collection() -> Result<()> {
+    let storage_path = "test-storage/nft_collection";
+    setup(storage_path)?;
+
+    let node = MockNode::builder()
+        .with_milestone_interval(Duration::from_millis(200))
+        .finish()?;
+    let token_supply = node.protocol_parameters().token_supply();
+    let wallet = make_wallet(storage_path, None, Some(node.url().as_str())).await?;
+    let account = wallet.create_account().finish().await?;
+    let address = *account.addresses().await?[0].address().inner();
+
+    let output_ids = node.add_outputs(vec![NftOutputBuilder::new_with_amount(1_000_000, NftId::null())
+        .add_unlock_condition(AddressUnlockCondition::new(address))
+        .finish_output(token_supply)?])?;
+    let collection_nft_id = NftId::from(&output_ids[0]);
+    fund_account(&node, &account, 1_000_000_000).await?;
+
+    // Metadata of about 300 bytes
+    let nft_options = |index: usize| -> Result<NftOptions> {
+        let metadata = Irc27Metadata::new(
+            "image/png",
+            format!("https://example.com/collection/{index}.png"),
+            format!("Collection NFT #{index}"),
+        )
+        .with_collection_name("Example collection")
+        .with_issuer_name("Example issuer")
+        .with_description("An NFT of the example collection, minted to test the chunking of collections")
+        .add_attribute(Irc27Attribute::new("background", "blue"))
+        .add_attribute(Irc27Attribute::new("rarity", index as u64));
+        NftOptions::default().with_irc_27_metadata(&metadata)
+    };
+    assert!(nft_options(0)?.immutable_metadata.unwrap().len() >= 300);
+
+    // More NFTs than fit into a single transaction with the collection NFT, by output count and by size
+    let transactions = account
+        .mint_collection_nfts(
+            collection_nft_id,
+            (0..130).map(nft_options).collect::<Result<_>>()?,
+            None,
+        )
+        .await?;
+    let mut minted_counts = Vec::new();
+    for transaction in &transactions {
+        let TransactionEssence::Regular(essence) = transaction.payload.essence();
+        assert!(essence.outputs().len() <= OUTPUT_COUNT_MAX as usize);
+        // The collection NFT is spent to issue the NFTs and created again
+        assert!(essence.outputs().iter().any(
+            |output| matches!(output, Output::Nft(nft_output) if nft_output.nft_id() == &collection_nft_id)
+        ));
+        minted_counts.push(
+            essence
+                .outputs()
+                .iter()
+                .filter(|output| matches!(output, Output::Nft(nft_output) if nft_output.nft_id().is_null()))
+                .count(),
+        );
+    }
+    assert_eq!(minted_counts.iter().sum::<usize>(), 130);
+    // No more transactions than needed with the most NFTs that fit into one of them
+    let max_minted_count = *minted_counts.iter().max().unwrap();
+    assert_eq!(transactions.len(), 130usize.div_ceil(max_minted_count));
+    account
+        .retry_transaction_until_included(&transactions.last().unwrap().transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+
+    let collections = account.nfts_by_collection().await;
+    assert_eq!(collections.len(), 1);
+    let collection_nft_ids = &collections[&collection_nft_id];
+    assert_eq!(collection_nft_ids.len(), 130);
+
+    // An NFT issued by an NFT of the collection is part of the collection too
+    let transactions = account
+        .mint_collection_nfts(collection_nft_ids[0], vec![nft_options(130)?], None)
+        .await?;
+    account
+        .retry_transaction_until_included(&transactions[0].transaction_id, None, None)
+        .await?;
+    account.sync(None).await?;
+    let collections = account.nfts_by_collection().await;
+    assert_eq!(collections.len(), 2);
+    let sub_collection_nft_id = collections[&collection_nft_ids[0]][0];
+
+    assert!(
+        account
+            .verify_nft_collection(collection_nft_ids[1], collection_nft_id)
+            .await?
+    );
+    assert!(
+        account
+            .verify_nft_collection(sub_collection_nft_id, collection_nft_id)
+            .await?
+    );
+    assert!(
+        !account
+            .verify_nft_collection(collection_nft_ids[1], collection_nft_ids[0])
+            .await?
+    );
+    assert!(
+        !account
+            .verify_nft_collection(collection_nft_id, collection_nft_id)
+            .await?
+    );
+
+    assert!(matches!(
+        account
+            .mint_collection_nfts(NftId::null(), vec![nft_options(0)?], None)
+            .await,
+        Err(Error::NftNotFoundInUnspentOutputs)
+    ));
+
+    tear_down(storage_path)
+}
+
+#[ignore]
+#[tokio::test]
+async fn simulate_transaction() -> Result<()> {
+    let storage_path = "test-storage/simulate_transaction";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let account_0 = &create_accounts_with_funds(&wallet, 1).await?[0];
+    let account_1 = wallet.create_account().finish().await?;
+
+    let balance = account_0.balance().await?;
+    let amount = 1_000_000;
+    let outputs = vec![BasicOutputBuilder::new_with_amount(amount)
+        .add_unlock_condition(AddressUnlockCondition::new(
+            *account_1.addresses().await?[0].address().inner(),
+        ))
+        .finish_output(account_0.client().get_token_supply().await?)?];
+
+    let simulation = account_0.simulate_transaction(outputs, None).await?;
+    assert_eq!(simulation.conflict, ConflictReason::None);
+    assert_eq!(
+        simulation.balance.base_coin().total(),
+        balance.base_coin().total() - amount
+    );
+
+    // Nothing got locked or sent
+    let balance_after = account_0.balance().await?;
+    assert_eq!(balance_after.base_coin().available(), balance.base_coin().available());
+    assert!(account_0.pending_transactions().await?.is_empty());
+
+    tear_down(storage_path)
+}
@@ -1,15 +1,27 @@
 // Copyright 2022 IOTA Stiftung
 // SPDX-License-Identifier: Apache-2.0
 
+use std::{
+    ops::Range,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+};
+
+use crypto::keys::slip10::Chain;
 #[cfg(feature = "storage")]
 use iota_sdk::client::node_manager::node::{Node, NodeDto, Url};
 #[cfg(feature = "stronghold")]
 use iota_sdk::client::secret::stronghold::StrongholdSecretManager;
+#[cfg(feature = "sqlite")]
+use iota_sdk::{client::secret::remote::RemoteSecretManager, wallet::Error};
 use iota_sdk::{
     client::{
-        constants::IOTA_COIN_TYPE,
-        secret::{mnemonic::MnemonicSecretManager, SecretManager},
+        constants::{IOTA_COIN_TYPE, SHIMMER_COIN_TYPE},
+        secret::{mnemonic::MnemonicSecretManager, GenerateAddressOptions, SecretManage, SecretManager},
     },
+    types::block::{address::Address, signature::Ed25519Signature},
     wallet::{ClientOptions, Result, Wallet},
 };
 
@@ -242,3 +254,187 @@ async fn update_node_auth() -> Result<()> {
 
     tear_down(storage_path)
 }
+
+// A secret manager implemented outside of the crate, that counts the generated addresses
+struct CountingSecretManager {
+    secret_manager: MnemonicSecretManager,
+    generated_addresses: Arc<AtomicUsize>,
+}
+
+#[async_trait::async_trait]
+impl SecretManage for CountingSecretManager {
+    type Error = iota_sdk::client::Error;
+
+    async fn generate_addresses(
+        &self,
+        coin_type: u32,
+        account_index: u32,
+        address_indexes: Range<u32>,
+        options: Option<GenerateAddressOptions>,
+    ) -> std::result::Result<Vec<Address>, Self::Error> {
+        self.generated_addresses
+            .fetch_add(address_indexes.len(), Ordering::Relaxed);
+        self.secret_manager
+            .generate_addresses(coin_type, account_index, address_indexes, options)
+            .await
+    }
+
+    async fn sign_ed25519(&self, msg: &[u8], chain: &Chain) -> std::result::Result<Ed25519Signature, Self::Error> {
+        self.secret_manager.sign_ed25519(msg, chain).await
+    }
+}
+
+#[tokio::test]
+async fn custom_secret_manager() -> Result<()> {
+    let storage_path = "test-storage/custom_secret_manager";
+    setup(storage_path)?;
+
+    let generated_addresses = Arc::new(AtomicUsize::new(0));
+    let secret_manager = CountingSecretManager {
+        secret_manager: MnemonicSecretManager::try_from_mnemonic(DEFAULT_MNEMONIC)?,
+        generated_addresses: generated_addresses.clone(),
+    };
+
+    #[allow(unused_mut)]
+    let mut wallet_builder = Wallet::builder()
+        .with_secret_manager(SecretManager::Custom(Box::new(secret_manager)))
+        .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+        .with_coin_type(SHIMMER_COIN_TYPE);
+    #[cfg(feature = "storage")]
+    {
+        wallet_builder = wallet_builder.with_storage_path(storage_path);
+    }
+    let wallet = wallet_builder.finish().await?;
+    let account = wallet.create_account().finish().await?;
+
+    assert_eq!(
+        &account.addresses().await?[0].address().as_ref().to_bech32("smr"),
+        // Address generated with bip32 path: [44, 4219, 0, 0, 0]
+        "smr1qq724zgvdujt3jdcd3xzsuqq7wl9pwq3dvsa5zvx49rj9tme8cat65xq7jz"
+    );
+
+    // The addresses were generated by the custom secret manager
+    assert!(generated_addresses.load(Ordering::Relaxed) > 0);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn custom_secret_manager_reopen() -> Result<()> {
+    let storage_path = "test-storage/custom_secret_manager_reopen";
+    setup(storage_path)?;
+    let db_path = format!("{storage_path}/wallet.sqlite");
+
+    let generated_addresses = Arc::new(AtomicUsize::new(0));
+    let build_wallet = |secret_manager: Option<SecretManager>| async {
+        Wallet::builder()
+            .with_secret_manager(secret_manager)
+            .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+            .with_coin_type(SHIMMER_COIN_TYPE)
+            .with_storage_path(&db_path)
+            .finish()
+            .await
+    };
+    let custom_secret_manager = || -> Result<SecretManager> {
+        Ok(SecretManager::Custom(Box::new(CountingSecretManager {
+            secret_manager: MnemonicSecretManager::try_from_mnemonic(DEFAULT_MNEMONIC)?,
+            generated_addresses: generated_addresses.clone(),
+        })))
+    };
+
+    let wallet = build_wallet(Some(custom_secret_manager()?)).await?;
+    wallet.create_account().with_alias("Alice".to_string()).finish().await?;
+    drop(wallet);
+
+    // The custom secret manager isn't stored, so it has to be provided again
+    assert!(matches!(
+        build_wallet(None).await,
+        Err(Error::MissingParameter("secret_manager"))
+    ));
+
+    let wallet = build_wallet(Some(custom_secret_manager()?)).await?;
+    let account = wallet.get_account("Alice").await?;
+    let generated_before = generated_addresses.load(Ordering::Relaxed);
+    let address = account.generate_addresses(1, None).await?;
+    assert_eq!(
+        address[0].address().as_ref().to_bech32("smr"),
+        // Address generated with bip32 path: [44, 4219, 0, 0, 1]
+        "smr1qpneufezq9ejdhhaz4y4ma6wpcac78ytll7nux0k8k0dvmdljcp8zdxmlsz"
+    );
+    assert_eq!(generated_addresses.load(Ordering::Relaxed), generated_before + 1);
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "sqlite")]
+#[tokio::test]
+async fn remote_secret_manager_bearer_token_not_stored() -> Result<()> {
+    let storage_path = "test-storage/remote_secret_manager_bearer_token_not_stored";
+    setup(storage_path)?;
+    let db_path = format!("{storage_path}/wallet.sqlite");
+
+    let build_wallet = |secret_manager: Option<SecretManager>| async {
+        Wallet::builder()
+            .with_secret_manager(secret_manager)
+            .with_client_options(ClientOptions::new().with_node(NODE_LOCAL)?)
+            .with_coin_type(SHIMMER_COIN_TYPE)
+            .with_storage_path(&db_path)
+            .finish()
+            .await
+    };
+    let remote_secret_manager = || RemoteSecretManager::new("http://localhost:1".parse().unwrap());
+
+    // A remote secret manager without a bearer token is restored from the storage
+    drop(build_wallet(Some(SecretManager::Remote(remote_secret_manager()))).await?);
+    let wallet = build_wallet(None).await?;
+    assert!(matches!(
+        *wallet.get_secret_manager().read().await,
+        SecretManager::Remote(_)
+    ));
+    drop(wallet);
+
+    // With a bearer token it has to be provided again
+    drop(
+        build_wallet(Some(SecretManager::Remote(
+            remote_secret_manager().with_bearer_token("some_token"),
+        )))
+        .await?,
+    );
+    assert!(matches!(
+        build_wallet(None).await,
+        Err(Error::MissingParameter("secret_manager"))
+    ));
+
+    tear_down(storage_path)
+}
+
+#[cfg(feature = "events")]
+#[tokio::test]
+async fn wallet_event_stream() -> Result<()> {
+    use futures::StreamExt;
+    use iota_sdk::wallet::events::types::{EventFilter, TransactionProgressEvent, WalletEvent, WalletEventType};
+
+    let storage_path = "test-storage/wallet_event_stream";
+    setup(storage_path)?;
+
+    let wallet = make_wallet(storage_path, None, None).await?;
+
+    let mut events = wallet
+        .event_stream(EventFilter::new().with_event_types([WalletEventType::ConsolidationRequired]))
+        .await;
+
+    wallet
+        .emit_test_event(WalletEvent::TransactionProgress(
+            TransactionProgressEvent::SelectingInputs,
+        ))
+        .await?;
+    wallet.emit_test_event(WalletEvent::ConsolidationRequired).await?;
+
+    let event = events.next().await.unwrap();
+    assert_eq!(event.account_index, 0);
+    assert_eq!(event.event, WalletEvent::ConsolidationRequired);
+    assert_eq!(events.missed_events(), 0);
+
+    tear_down(storage_path)
+}